@@ -0,0 +1,388 @@
+use accesskit::Role;
+use bevy::{
+    a11y::AccessibilityNode,
+    ecs::system::SystemId,
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::{FocusedInput, InputFocus, InputFocusVisible},
+    prelude::*,
+};
+
+use bevy_core_widgets::{InteractionDisabled, ValueChange};
+
+/// A slider selecting the range between two thumbs, `low` and `high`.
+///
+/// The thumbs are descendants with a [`CoreRangeSliderThumb`], each of them is focusable and
+/// controlled with the keyboard while focused. Like the other core widgets the range is not
+/// changed here: a change runs `on_change`, or triggers `ValueChange<(f32, f32)>` on the slider.
+#[derive(Component, Debug)]
+#[require(
+    AccessibilityNode(accesskit::Node::new(Role::Group)),
+    CoreRangeSliderDrag
+)]
+pub struct CoreRangeSlider {
+    pub min: f32,
+    pub max: f32,
+    pub low: f32,
+    pub high: f32,
+    /// Smallest distance kept between `low` and `high`.
+    pub min_gap: f32,
+    /// A thumb moved into the other one pushes it along, instead of stopping at it.
+    pub push: bool,
+    /// Values snap to multiples of `step` above `min`.
+    pub step: Option<f32>,
+    /// Change of an arrow key.
+    pub increment: f32,
+    /// Change of PageUp and PageDown.
+    pub page_increment: f32,
+    /// Length of a thumb along the track in logical pixels, the thumbs travel the rest.
+    pub thumb_size: f32,
+    /// The minimum is at the bottom and the maximum at the top.
+    pub vertical: bool,
+    /// Run instead of triggering `ValueChange`.
+    pub on_change: Option<SystemId<In<(Entity, (f32, f32))>>>,
+}
+
+impl Default for CoreRangeSlider {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 100.0,
+            low: 0.0,
+            high: 100.0,
+            min_gap: 0.0,
+            push: false,
+            step: None,
+            increment: 1.0,
+            page_increment: 10.0,
+            thumb_size: 12.0,
+            vertical: false,
+            on_change: None,
+        }
+    }
+}
+
+impl CoreRangeSlider {
+    pub fn value(&self, thumb: RangeThumb) -> f32 {
+        match thumb {
+            RangeThumb::Low => self.low,
+            RangeThumb::High => self.high,
+        }
+    }
+
+    /// Position of `value` along the track, from 0.0 at `min` to 1.0 at `max`.
+    pub fn fraction(&self, value: f32) -> f32 {
+        let span = self.max - self.min;
+        if span > 0.0 {
+            ((value - self.min) / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// The range after moving `thumb` to `value`. The value is clamped and snapped to the step,
+    /// then kept `min_gap` away from the other thumb by stopping at it or pushing it.
+    pub fn moved(&self, thumb: RangeThumb, value: f32) -> (f32, f32) {
+        let gap = self.min_gap.max(0.0).min((self.max - self.min).max(0.0));
+        let value = self.snap(value);
+        match (thumb, self.push) {
+            (RangeThumb::Low, true) => {
+                let low = value.min(self.max - gap);
+                (low, self.high.max(low + gap))
+            }
+            (RangeThumb::Low, false) => (value.min(self.high - gap).max(self.min), self.high),
+            (RangeThumb::High, true) => {
+                let high = value.max(self.min + gap);
+                (self.low.min(high - gap), high)
+            }
+            (RangeThumb::High, false) => (self.low, value.max(self.low + gap).min(self.max)),
+        }
+    }
+
+    fn snap(&self, value: f32) -> f32 {
+        snap_to_step(value, self.min, self.max, self.step)
+    }
+}
+
+/// Clamps `value` to `min..=max` and rounds it to multiples of `step` above `min`. When `step`
+/// doesn't divide the range, values past the last step snap to the nearer of it and `max`, so
+/// `max` stays reachable.
+pub fn snap_to_step(value: f32, min: f32, max: f32, step: Option<f32>) -> f32 {
+    let value = value.max(min).min(max);
+    let Some(step) = step.filter(|step| *step > 0.0) else {
+        return value;
+    };
+    let last_step = min + ((max - min) / step + 0.001).floor() * step;
+    if value <= last_step {
+        (min + ((value - min) / step).round() * step).min(max)
+    } else if max - value <= value - last_step {
+        max
+    } else {
+        last_step
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum RangeThumb {
+    Low,
+    High,
+}
+
+/// One of the two thumbs of a [`CoreRangeSlider`].
+#[derive(Component, Debug, Clone, Copy)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::Slider)))]
+pub struct CoreRangeSliderThumb(pub RangeThumb);
+
+/// Pointer state of a [`CoreRangeSlider`].
+///
+/// A press picks the thumb under the pointer, or the nearest one, which then follows the
+/// pointer until it is released.
+#[derive(Component, Debug, Default, Clone)]
+pub struct CoreRangeSliderDrag {
+    pub active: Option<RangeThumb>,
+    origin: f32,
+}
+
+fn trigger_range_change(
+    entity: Entity,
+    slider: &CoreRangeSlider,
+    range: (f32, f32),
+    commands: &mut Commands,
+) {
+    if range == (slider.low, slider.high) {
+        return;
+    }
+    if let Some(on_change) = slider.on_change {
+        commands.run_system_with(on_change, (entity, range));
+    } else {
+        commands.trigger_targets(ValueChange(range), entity);
+    }
+}
+
+fn find_thumb(
+    entity: Entity,
+    thumb: RangeThumb,
+    q_children: &Query<&Children>,
+    q_thumb: &Query<&CoreRangeSliderThumb>,
+) -> Option<Entity> {
+    q_children
+        .iter_descendants(entity)
+        .find(|descendant| q_thumb.get(*descendant).is_ok_and(|t| t.0 == thumb))
+}
+
+// The pointer position as a fraction of the track the thumbs travel.
+fn pointer_fraction(
+    slider: &CoreRangeSlider,
+    node: &ComputedNode,
+    transform: &GlobalTransform,
+    position: Vec2,
+) -> f32 {
+    let scale = node.inverse_scale_factor();
+    let size = node.size() * scale;
+    let corner = transform.translation().truncate() * scale - size * 0.5;
+    let offset = position - corner - Vec2::splat(slider.thumb_size * 0.5);
+    let travel = (size - Vec2::splat(slider.thumb_size)).max(Vec2::ONE);
+    if slider.vertical {
+        1.0 - (offset.y / travel.y).clamp(0.0, 1.0)
+    } else {
+        (offset.x / travel.x).clamp(0.0, 1.0)
+    }
+}
+
+fn range_slider_on_pressed(
+    mut trigger: Trigger<Pointer<Pressed>>,
+    mut q_slider: Query<(
+        &CoreRangeSlider,
+        &mut CoreRangeSliderDrag,
+        &ComputedNode,
+        &GlobalTransform,
+        Has<InteractionDisabled>,
+    )>,
+    q_children: Query<&Children>,
+    q_thumb: Query<&CoreRangeSliderThumb>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((slider, mut drag, node, transform, disabled)) = q_slider.get_mut(entity) else {
+        return;
+    };
+    trigger.propagate(false);
+    if disabled || trigger.event().button != PointerButton::Primary {
+        return;
+    }
+
+    let fraction = pointer_fraction(
+        slider,
+        node,
+        transform,
+        trigger.event().pointer_location.position,
+    );
+    let value = slider.min + fraction * (slider.max - slider.min);
+
+    let (thumb, range) = match q_thumb.get(trigger.event().target) {
+        // A pressed thumb is grabbed where it is
+        Ok(thumb) => (thumb.0, (slider.low, slider.high)),
+        // A press on the track moves the nearest thumb there
+        Err(_) => {
+            let thumb = if value < slider.low
+                || (value <= slider.high && value - slider.low < slider.high - value)
+            {
+                RangeThumb::Low
+            } else {
+                RangeThumb::High
+            };
+            (thumb, slider.moved(thumb, value))
+        }
+    };
+
+    drag.active = Some(thumb);
+    drag.origin = match thumb {
+        RangeThumb::Low => range.0,
+        RangeThumb::High => range.1,
+    };
+    if let Some(thumb_entity) = find_thumb(entity, thumb, &q_children, &q_thumb) {
+        focus.0 = Some(thumb_entity);
+        focus_visible.0 = false;
+    }
+    trigger_range_change(entity, slider, range, &mut commands);
+}
+
+fn range_slider_on_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    q_slider: Query<(
+        &CoreRangeSlider,
+        &CoreRangeSliderDrag,
+        &ComputedNode,
+        Has<InteractionDisabled>,
+    )>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((slider, drag, node, disabled)) = q_slider.get(entity) else {
+        return;
+    };
+    trigger.propagate(false);
+    let Some(thumb) = drag.active else {
+        return;
+    };
+    if disabled {
+        return;
+    }
+
+    let scale = node.inverse_scale_factor();
+    let distance = trigger.event().distance;
+    let (distance, length) = if slider.vertical {
+        (-distance.y, node.size().y * scale)
+    } else {
+        (distance.x, node.size().x * scale)
+    };
+    let travel = (length - slider.thumb_size).max(1.0);
+    let value = drag.origin + distance * (slider.max - slider.min) / travel;
+    let range = slider.moved(thumb, value);
+    trigger_range_change(entity, slider, range, &mut commands);
+}
+
+fn range_slider_on_released(
+    trigger: Trigger<Pointer<Released>>,
+    mut q_slider: Query<&mut CoreRangeSliderDrag>,
+) {
+    if let Ok(mut drag) = q_slider.get_mut(trigger.target()) {
+        drag.active = None;
+    }
+}
+
+// Drag events keep targeting the slider after the pointer leaves it, so a release anywhere
+// ends the drag.
+fn range_slider_on_drag_end(
+    trigger: Trigger<Pointer<DragEnd>>,
+    mut q_slider: Query<&mut CoreRangeSliderDrag>,
+) {
+    if let Ok(mut drag) = q_slider.get_mut(trigger.target()) {
+        drag.active = None;
+    }
+}
+
+fn range_slider_on_pointer_cancel(
+    trigger: Trigger<Pointer<Cancel>>,
+    mut q_slider: Query<&mut CoreRangeSliderDrag>,
+) {
+    if let Ok(mut drag) = q_slider.get_mut(trigger.target()) {
+        drag.active = None;
+    }
+}
+
+// Keyboard input bubbles from the focused thumb up to the slider.
+fn range_slider_on_key_input(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    q_slider: Query<(&CoreRangeSlider, Has<InteractionDisabled>)>,
+    q_thumb: Query<&CoreRangeSliderThumb>,
+    focus: Res<InputFocus>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((slider, disabled)) = q_slider.get(entity) else {
+        return;
+    };
+    let Some(thumb) = focus.0.and_then(|focused| q_thumb.get(focused).ok()) else {
+        return;
+    };
+    let event = &trigger.event().input;
+    if disabled || event.state != ButtonState::Pressed {
+        return;
+    }
+
+    let value = slider.value(thumb.0);
+    let value = match event.key_code {
+        KeyCode::ArrowLeft | KeyCode::ArrowDown => value - slider.increment,
+        KeyCode::ArrowRight | KeyCode::ArrowUp => value + slider.increment,
+        KeyCode::PageDown => value - slider.page_increment,
+        KeyCode::PageUp => value + slider.page_increment,
+        KeyCode::Home => slider.min,
+        KeyCode::End => slider.max,
+        _ => return,
+    };
+
+    trigger.propagate(false);
+    let range = slider.moved(thumb.0, value);
+    trigger_range_change(entity, slider, range, &mut commands);
+}
+
+// Expose each thumb as a slider of its own value.
+fn update_range_slider_accessibility(
+    q_slider: Query<(Entity, &CoreRangeSlider), Changed<CoreRangeSlider>>,
+    q_children: Query<&Children>,
+    mut q_thumb: Query<(&CoreRangeSliderThumb, &mut AccessibilityNode)>,
+) {
+    for (entity, slider) in q_slider.iter() {
+        for descendant in q_children.iter_descendants(entity) {
+            let Ok((thumb, mut accessibility)) = q_thumb.get_mut(descendant) else {
+                continue;
+            };
+            accessibility.set_numeric_value(slider.value(thumb.0).into());
+            accessibility.set_min_numeric_value(slider.min.into());
+            accessibility.set_max_numeric_value(slider.max.into());
+            accessibility.set_numeric_value_step(slider.increment.into());
+            accessibility.set_numeric_value_jump(slider.page_increment.into());
+            accessibility.set_orientation(if slider.vertical {
+                accesskit::Orientation::Vertical
+            } else {
+                accesskit::Orientation::Horizontal
+            });
+        }
+    }
+}
+
+pub struct CoreRangeSliderPlugin;
+
+impl Plugin for CoreRangeSliderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(range_slider_on_pressed)
+            .add_observer(range_slider_on_drag)
+            .add_observer(range_slider_on_released)
+            .add_observer(range_slider_on_drag_end)
+            .add_observer(range_slider_on_pointer_cancel)
+            .add_observer(range_slider_on_key_input)
+            .add_systems(PostUpdate, update_range_slider_accessibility);
+    }
+}
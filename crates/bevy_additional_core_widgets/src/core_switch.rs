@@ -10,11 +10,54 @@ use bevy::{
 use bevy_core_widgets::{Checked, InteractionDisabled, ValueChange};
 
 #[derive(Component, Debug)]
-#[require(AccessibilityNode(accesskit::Node::new(Role::Switch)), Checked)]
+#[require(
+    AccessibilityNode(accesskit::Node::new(Role::Switch)),
+    Checked,
+    CoreSwitchDrag
+)]
 pub struct CoreSwitch {
+    /// Run instead of triggering `ValueChange`.
     pub on_change: Option<SystemId<In<(Entity, bool)>>>,
 }
 
+/// Horizontal pointer movement, in logical pixels, before a press on a switch becomes a
+/// knob drag. Shorter movements still count as a click.
+pub const SWITCH_DRAG_THRESHOLD: f32 = 4.0;
+
+/// Knob drag state of a [`CoreSwitch`].
+///
+/// While `dragging`, `position` follows the pointer from 0.0 (off) to 1.0 (on). Releasing
+/// the pointer past the midpoint flips the switch, anywhere else it keeps its state. A
+/// canceled pointer drops the drag without a change.
+#[derive(Component, Debug, Clone)]
+pub struct CoreSwitchDrag {
+    pub dragging: bool,
+    pub position: f32,
+    /// Distance in logical pixels the knob travels between off and on.
+    pub travel: f32,
+    tracking: bool,
+    start: f32,
+}
+
+impl Default for CoreSwitchDrag {
+    fn default() -> Self {
+        Self {
+            dragging: false,
+            position: 0.0,
+            travel: 20.0,
+            tracking: false,
+            start: 0.0,
+        }
+    }
+}
+
+impl CoreSwitchDrag {
+    fn reset(&mut self) {
+        self.tracking = false;
+        self.dragging = false;
+    }
+}
+
 fn switch_on_key_input(
     mut trigger: Trigger<FocusedInput<KeyboardInput>>,
     q_state: Query<(&CoreSwitch, &Checked, Has<InteractionDisabled>)>,
@@ -43,18 +86,24 @@ fn switch_on_key_input(
 
 fn switch_on_pointer_click(
     mut trigger: Trigger<Pointer<Click>>,
-    q_state: Query<(&CoreSwitch, &Checked, Has<InteractionDisabled>)>,
+    q_state: Query<(
+        &CoreSwitch,
+        &Checked,
+        &CoreSwitchDrag,
+        Has<InteractionDisabled>,
+    )>,
     mut focus: ResMut<InputFocus>,
     mut focus_visible: ResMut<InputFocusVisible>,
     mut commands: Commands,
 ) {
-    if let Ok((switch, checked, disabled)) = q_state.get(trigger.target()) {
+    if let Ok((switch, checked, drag, disabled)) = q_state.get(trigger.target()) {
         let entity = trigger.target();
         focus.0 = Some(entity);
         focus_visible.0 = false;
         trigger.propagate(false);
 
-        if !disabled {
+        // A knob drag decides the state when it ends, which is after the click
+        if !disabled && !drag.dragging {
             let is_on = checked.0;
             let new_checked = !is_on;
 
@@ -67,11 +116,96 @@ fn switch_on_pointer_click(
     }
 }
 
+fn switch_on_drag_start(
+    mut trigger: Trigger<Pointer<DragStart>>,
+    mut q_state: Query<(&Checked, &mut CoreSwitchDrag, Has<InteractionDisabled>), With<CoreSwitch>>,
+) {
+    if let Ok((checked, mut drag, disabled)) = q_state.get_mut(trigger.target()) {
+        trigger.propagate(false);
+        if disabled || trigger.event().button != PointerButton::Primary {
+            return;
+        }
+        drag.tracking = true;
+        drag.dragging = false;
+        drag.start = if checked.0 { 1.0 } else { 0.0 };
+        drag.position = drag.start;
+    }
+}
+
+fn switch_on_drag(
+    mut trigger: Trigger<Pointer<Drag>>,
+    mut q_state: Query<(&mut CoreSwitchDrag, Has<InteractionDisabled>), With<CoreSwitch>>,
+) {
+    if let Ok((mut drag, disabled)) = q_state.get_mut(trigger.target()) {
+        trigger.propagate(false);
+        if !drag.tracking {
+            return;
+        }
+        if disabled {
+            drag.reset();
+            return;
+        }
+        let distance = trigger.event().distance.x;
+        if !drag.dragging && distance.abs() >= SWITCH_DRAG_THRESHOLD {
+            drag.dragging = true;
+        }
+        if drag.dragging {
+            drag.position = (drag.start + distance / drag.travel.max(1.0)).clamp(0.0, 1.0);
+        }
+    }
+}
+
+// Drag events keep targeting the switch after the pointer leaves it, so a release anywhere
+// ends the drag.
+fn switch_on_drag_end(
+    mut trigger: Trigger<Pointer<DragEnd>>,
+    mut q_state: Query<(
+        &CoreSwitch,
+        &Checked,
+        &mut CoreSwitchDrag,
+        Has<InteractionDisabled>,
+    )>,
+    mut commands: Commands,
+) {
+    if let Ok((switch, checked, mut drag, disabled)) = q_state.get_mut(trigger.target()) {
+        trigger.propagate(false);
+        let was_dragging = drag.dragging;
+        drag.reset();
+        if disabled || !was_dragging {
+            return;
+        }
+
+        let new_checked = drag.position >= 0.5;
+        if new_checked != checked.0 {
+            let entity = trigger.target();
+            if let Some(on_change) = switch.on_change {
+                commands.run_system_with(on_change, (entity, new_checked));
+            } else {
+                commands.trigger_targets(ValueChange(new_checked), entity);
+            }
+        }
+    }
+}
+
+fn switch_on_pointer_cancel(
+    mut trigger: Trigger<Pointer<Cancel>>,
+    mut q_state: Query<&mut CoreSwitchDrag, With<CoreSwitch>>,
+) {
+    if let Ok(mut drag) = q_state.get_mut(trigger.target()) {
+        trigger.propagate(false);
+        drag.reset();
+    }
+}
+
 pub struct CoreSwitchPlugin;
 
 impl Plugin for CoreSwitchPlugin {
     fn build(&self, app: &mut App) {
         app.add_observer(switch_on_key_input)
-            .add_observer(switch_on_pointer_click);
+            .add_observer(switch_on_pointer_click)
+            .add_observer(switch_on_drag_start)
+            .add_observer(switch_on_drag)
+            .add_observer(switch_on_drag_end)
+            .add_observer(switch_on_pointer_cancel);
     }
 }
@@ -0,0 +1,94 @@
+use accesskit::{Role, Toggled};
+use bevy::{
+    a11y::AccessibilityNode,
+    ecs::system::SystemId,
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::{FocusedInput, InputFocus, InputFocusVisible},
+    prelude::*,
+};
+
+use bevy_core_widgets::{Checked, InteractionDisabled, ValueChange};
+
+/// A button which stays pressed until it is activated again. `Checked` holds the pressed
+/// state, which is announced as the button's `Toggled` state.
+#[derive(Component, Debug)]
+#[require(AccessibilityNode(accesskit::Node::new(Role::Button)), Checked)]
+pub struct CoreToggleButton {
+    /// Run instead of triggering `ValueChange`.
+    pub on_change: Option<SystemId<In<(Entity, bool)>>>,
+}
+
+fn toggle_button_on_key_input(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    q_state: Query<(&CoreToggleButton, &Checked, Has<InteractionDisabled>)>,
+    mut commands: Commands,
+) {
+    if let Ok((toggle_button, checked, disabled)) = q_state.get(trigger.target()) {
+        let event = &trigger.event().input;
+        if !disabled
+            && event.state == ButtonState::Pressed
+            && !event.repeat
+            && (event.key_code == KeyCode::Enter || event.key_code == KeyCode::Space)
+        {
+            trigger.propagate(false);
+            let pressed = !checked.0;
+            let entity = trigger.target();
+
+            if let Some(on_change) = toggle_button.on_change {
+                commands.run_system_with(on_change, (entity, pressed));
+            } else {
+                commands.trigger_targets(ValueChange(pressed), entity);
+            }
+        }
+    }
+}
+
+fn toggle_button_on_pointer_click(
+    mut trigger: Trigger<Pointer<Click>>,
+    q_state: Query<(&CoreToggleButton, &Checked, Has<InteractionDisabled>)>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+    mut commands: Commands,
+) {
+    if let Ok((toggle_button, checked, disabled)) = q_state.get(trigger.target()) {
+        let entity = trigger.target();
+        focus.0 = Some(entity);
+        focus_visible.0 = false;
+        trigger.propagate(false);
+
+        if !disabled {
+            let pressed = !checked.0;
+
+            if let Some(on_change) = toggle_button.on_change {
+                commands.run_system_with(on_change, (entity, pressed));
+            } else {
+                commands.trigger_targets(ValueChange(pressed), entity);
+            }
+        }
+    }
+}
+
+fn update_toggle_button_accessibility(
+    mut query: Query<
+        (&Checked, &mut AccessibilityNode),
+        (With<CoreToggleButton>, Changed<Checked>),
+    >,
+) {
+    for (checked, mut accessibility) in query.iter_mut() {
+        accessibility.set_toggled(if checked.0 {
+            Toggled::True
+        } else {
+            Toggled::False
+        });
+    }
+}
+
+pub struct CoreToggleButtonPlugin;
+
+impl Plugin for CoreToggleButtonPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(toggle_button_on_key_input)
+            .add_observer(toggle_button_on_pointer_click)
+            .add_systems(PostUpdate, update_toggle_button_accessibility);
+    }
+}
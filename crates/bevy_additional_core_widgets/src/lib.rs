@@ -1,13 +1,24 @@
 use bevy::app::{App, Plugin};
+mod core_range_slider;
 mod core_switch;
+mod core_toggle_button;
 
-pub use core_switch::{CoreSwitch, CoreSwitchPlugin};
+pub use core_range_slider::{
+    CoreRangeSlider, CoreRangeSliderDrag, CoreRangeSliderPlugin, CoreRangeSliderThumb, RangeThumb,
+    snap_to_step,
+};
+pub use core_switch::{CoreSwitch, CoreSwitchDrag, CoreSwitchPlugin, SWITCH_DRAG_THRESHOLD};
+pub use core_toggle_button::{CoreToggleButton, CoreToggleButtonPlugin};
 
 pub struct AdditionalCoreWidgetsPlugin;
 
 impl Plugin for AdditionalCoreWidgetsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((CoreSwitchPlugin,));
+        app.add_plugins((
+            CoreSwitchPlugin,
+            CoreToggleButtonPlugin,
+            CoreRangeSliderPlugin,
+        ));
     }
 }
 
@@ -9,9 +9,13 @@ use bevy_additional_core_widgets::AdditionalCoreWidgetsPlugin;
 use bevy_core_widgets::CoreWidgetsPlugin;
 
 use ui::{
-    button::StyledButtonPlugin, checkbox::StyledCheckboxPlugin, input::StyledInputPlugin,
-    progress::StyledProgessPlugin, radio_group::StyledRadioGroupPlugin, slider::StyledSliderPlugin,
-    switch::StyledSwitchPlugin, text::StyledTextPlugin, toggle::StyledTogglePlugin,
+    button::StyledButtonPlugin, button_group::StyledButtonGroupPlugin,
+    checkbox::StyledCheckboxPlugin, checkbox_group::StyledCheckboxGroupPlugin,
+    focus_ring::StyledFocusRingPlugin, icon::StyledIconPlugin, input::StyledInputPlugin,
+    label::StyledLabelPlugin, panel::StyledPanelPlugin, progress::StyledProgessPlugin,
+    radio_group::StyledRadioGroupPlugin, range_slider::StyledRangeSliderPlugin,
+    slider::StyledSliderPlugin, split_button::StyledSplitButtonPlugin, switch::StyledSwitchPlugin,
+    text::StyledTextPlugin, toggle::StyledTogglePlugin, toggle_group::StyledToggleGroupPlugin,
 };
 
 pub struct StyledWidgetsPlugin;
@@ -31,6 +35,17 @@ impl Plugin for StyledWidgetsPlugin {
             StyledCheckboxPlugin,
             StyledSliderPlugin,
             StyledRadioGroupPlugin,
+            StyledPanelPlugin,
+            StyledIconPlugin,
+            (
+                StyledButtonGroupPlugin,
+                StyledSplitButtonPlugin,
+                StyledFocusRingPlugin,
+                StyledCheckboxGroupPlugin,
+                StyledLabelPlugin,
+                StyledToggleGroupPlugin,
+                StyledRangeSliderPlugin,
+            ),
         ));
     }
 }
@@ -39,13 +54,25 @@ impl Plugin for StyledWidgetsPlugin {
 pub mod prelude {
     pub use crate::StyledWidgetsPlugin;
     pub use crate::themes::*;
+    pub use crate::ui::binding::*;
     pub use crate::ui::button::*;
+    pub use crate::ui::button_group::*;
     pub use crate::ui::checkbox::*;
+    pub use crate::ui::checkbox_group::*;
+    pub use crate::ui::disabled::*;
+    pub use crate::ui::focus_ring::*;
+    pub use crate::ui::events::*;
+    pub use crate::ui::icon::*;
     pub use crate::ui::input::*;
+    pub use crate::ui::label::*;
+    pub use crate::ui::panel::*;
     pub use crate::ui::progress::*;
     pub use crate::ui::radio_group::*;
+    pub use crate::ui::range_slider::*;
     pub use crate::ui::slider::*;
+    pub use crate::ui::split_button::*;
     pub use crate::ui::switch::*;
     pub use crate::ui::text::*;
     pub use crate::ui::toggle::*;
+    pub use crate::ui::toggle_group::*;
 }
@@ -91,9 +91,9 @@ impl ButtonVariantStyles {
             },
             link: ButtonStyle {
                 normal_background: Color::NONE, // Link buttons typically have no background
-                hovered_background: colors.accent.with_alpha(0.7),
-                pressed_background: colors.accent.with_alpha(0.5),
-                text_color: colors.accent_foreground,
+                hovered_background: Color::NONE, // Hover is shown with an underline instead
+                pressed_background: Color::NONE,
+                text_color: colors.primary,
                 border_color: Color::NONE,
                 shadow_color: Color::NONE,
                 shadow_offset: Vec2::ZERO,
@@ -115,6 +115,7 @@ pub struct ButtonSizeProperties {
     pub border_radius: f32,
     pub padding_horizontal: f32,
     pub padding_vertical: f32,
+    pub gap: f32,
 }
 
 // Collection of size variants
@@ -138,6 +139,7 @@ pub fn button_sizes() -> ButtonSizeStyles {
             min_height: 28.0,
             border_width: 1.0,
             border_radius: 4.0,
+            gap: 4.0,
         },
         small: ButtonSizeProperties {
             padding_horizontal: 12.0,
@@ -148,6 +150,7 @@ pub fn button_sizes() -> ButtonSizeStyles {
             min_height: 32.0,
             border_width: 1.0,
             border_radius: 5.0,
+            gap: 6.0,
         },
         medium: ButtonSizeProperties {
             padding_horizontal: 16.0,
@@ -158,6 +161,7 @@ pub fn button_sizes() -> ButtonSizeStyles {
             min_height: 36.0,
             border_width: 1.5,
             border_radius: 6.0,
+            gap: 8.0,
         },
         large: ButtonSizeProperties {
             padding_horizontal: 24.0,
@@ -168,6 +172,7 @@ pub fn button_sizes() -> ButtonSizeStyles {
             min_height: 40.0,
             border_width: 2.0,
             border_radius: 6.0,
+            gap: 8.0,
         },
         xlarge: ButtonSizeProperties {
             padding_horizontal: 28.0,
@@ -178,6 +183,7 @@ pub fn button_sizes() -> ButtonSizeStyles {
             min_height: 52.0,
             border_width: 2.0,
             border_radius: 8.0,
+            gap: 10.0,
         },
     }
 }
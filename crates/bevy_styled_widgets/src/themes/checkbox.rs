@@ -11,6 +11,10 @@ pub struct CheckboxStyle {
     pub hovered_background: Color,
     pub disabled_unchecked_background: Color,
     pub disabled_checked_background: Color,
+    pub indeterminate_background: Color,
+    pub indeterminate_text_color: Color,
+    pub hovered_indeterminate_background: Color,
+    pub disabled_indeterminate_background: Color,
     pub disabled_text_color: Color,
     pub border_color: Color,
     pub disabled_border_color: Color,
@@ -26,6 +30,7 @@ pub struct CheckboxStyleAlphas {
     pub disabled_border_alpha: f32,
     pub disabled_text_alpha: f32,
     pub hovered_alpha: f32,
+    pub hovered_indeterminate_alpha: f32,
 }
 
 #[derive(Debug, Clone)]
@@ -41,6 +46,7 @@ impl CheckboxVariantStyles {
             disabled_border_alpha: 0.5,
             disabled_text_alpha: 0.5,
             hovered_alpha: 0.5,
+            hovered_indeterminate_alpha: 0.8,
         };
 
         Self {
@@ -53,6 +59,14 @@ impl CheckboxVariantStyles {
                 disabled_checked_background: colors
                     .primary
                     .with_alpha(alphas.disabled_checked_alpha),
+                indeterminate_background: colors.primary,
+                indeterminate_text_color: colors.primary_foreground,
+                hovered_indeterminate_background: colors
+                    .primary
+                    .with_alpha(alphas.hovered_indeterminate_alpha),
+                disabled_indeterminate_background: colors
+                    .primary
+                    .with_alpha(alphas.disabled_checked_alpha),
                 disabled_text_color: colors
                     .primary_foreground
                     .with_alpha(alphas.disabled_text_alpha),
@@ -71,6 +85,14 @@ impl CheckboxVariantStyles {
                 disabled_checked_background: colors
                     .primary
                     .with_alpha(alphas.disabled_checked_alpha),
+                indeterminate_background: colors.primary,
+                indeterminate_text_color: colors.primary_foreground,
+                hovered_indeterminate_background: colors
+                    .primary
+                    .with_alpha(alphas.hovered_indeterminate_alpha),
+                disabled_indeterminate_background: colors
+                    .primary
+                    .with_alpha(alphas.disabled_checked_alpha),
                 disabled_text_color: colors
                     .primary_foreground
                     .with_alpha(alphas.disabled_text_alpha),
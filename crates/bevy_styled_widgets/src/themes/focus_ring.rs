@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use super::ThemeColors;
+
+#[derive(Debug, Clone, Default)]
+pub struct FocusRingStyle {
+    pub color: Color,
+}
+
+impl FocusRingStyle {
+    pub fn from_colors(colors: ThemeColors) -> Self {
+        Self { color: colors.ring }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FocusRingSizeProperties {
+    pub width: f32,
+    pub offset: f32,
+}
+
+// Collection of size variants
+#[derive(Debug, Clone)]
+pub struct FocusRingSizeStyles {
+    pub xsmall: FocusRingSizeProperties,
+    pub small: FocusRingSizeProperties,
+    pub medium: FocusRingSizeProperties,
+    pub large: FocusRingSizeProperties,
+    pub xlarge: FocusRingSizeProperties,
+}
+
+pub fn focus_ring_sizes() -> FocusRingSizeStyles {
+    FocusRingSizeStyles {
+        xsmall: FocusRingSizeProperties {
+            width: 1.5,
+            offset: 1.0,
+        },
+        small: FocusRingSizeProperties {
+            width: 2.0,
+            offset: 1.5,
+        },
+        medium: FocusRingSizeProperties {
+            width: 2.0,
+            offset: 2.0,
+        },
+        large: FocusRingSizeProperties {
+            width: 3.0,
+            offset: 2.0,
+        },
+        xlarge: FocusRingSizeProperties {
+            width: 3.0,
+            offset: 3.0,
+        },
+    }
+}
@@ -8,6 +8,7 @@ mod manager;
 pub use manager::*;
 
 pub mod checkbox;
+pub mod focus_ring;
 pub mod progress;
 pub mod radio;
 pub mod slider;
@@ -4,13 +4,128 @@ use super::ThemeColors;
 
 #[derive(Debug, Clone)]
 pub struct PanelStyle {
+    // Colors
     pub background_color: Color,
+    pub text_color: Color,
+    pub muted_text_color: Color,
+    pub border_color: Color,
 }
 
 impl PanelStyle {
     pub fn from_colors(colors: ThemeColors) -> Self {
         Self {
             background_color: colors.background,
+            text_color: colors.foreground,
+            muted_text_color: colors.muted_foreground,
+            border_color: colors.border,
         }
     }
 }
+
+// Style collection for all panel variants
+#[derive(Debug, Clone)]
+pub struct PanelVariantStyles {
+    pub background: PanelStyle,
+    pub card: PanelStyle,
+    pub popover: PanelStyle,
+    pub sidebar: PanelStyle,
+    pub muted: PanelStyle,
+}
+
+impl PanelVariantStyles {
+    pub fn from_colors(colors: ThemeColors) -> Self {
+        Self {
+            background: PanelStyle::from_colors(colors.clone()),
+            card: PanelStyle {
+                background_color: colors.card,
+                text_color: colors.card_foreground,
+                muted_text_color: colors.muted_foreground,
+                border_color: colors.border,
+            },
+            popover: PanelStyle {
+                background_color: colors.popover,
+                text_color: colors.popover_foreground,
+                muted_text_color: colors.muted_foreground,
+                border_color: colors.border,
+            },
+            sidebar: PanelStyle {
+                background_color: colors.sidebar,
+                text_color: colors.sidebar_foreground,
+                muted_text_color: colors.muted_foreground,
+                border_color: colors.sidebar_border,
+            },
+            muted: PanelStyle {
+                background_color: colors.muted,
+                text_color: colors.foreground,
+                muted_text_color: colors.muted_foreground,
+                border_color: colors.border,
+            },
+        }
+    }
+}
+
+// Panel size properties
+#[derive(Debug, Clone)]
+pub struct PanelSizeProperties {
+    pub padding: f32,
+    pub row_gap: f32,
+    pub border_width: f32,
+    pub border_radius: f32,
+    pub header_font_size: f32,
+    pub footer_font_size: f32,
+}
+
+// Collection of size variants for Panel
+#[derive(Debug, Clone)]
+pub struct PanelSizeStyles {
+    pub xsmall: PanelSizeProperties,
+    pub small: PanelSizeProperties,
+    pub medium: PanelSizeProperties,
+    pub large: PanelSizeProperties,
+    pub xlarge: PanelSizeProperties,
+}
+
+pub fn panel_sizes() -> PanelSizeStyles {
+    PanelSizeStyles {
+        xsmall: PanelSizeProperties {
+            padding: 6.0,
+            row_gap: 4.0,
+            border_width: 1.0,
+            border_radius: 4.0,
+            header_font_size: 12.0,
+            footer_font_size: 10.0,
+        },
+        small: PanelSizeProperties {
+            padding: 10.0,
+            row_gap: 6.0,
+            border_width: 1.0,
+            border_radius: 6.0,
+            header_font_size: 14.0,
+            footer_font_size: 12.0,
+        },
+        medium: PanelSizeProperties {
+            padding: 16.0,
+            row_gap: 10.0,
+            border_width: 1.0,
+            border_radius: 8.0,
+            header_font_size: 16.0,
+            footer_font_size: 13.0,
+        },
+        large: PanelSizeProperties {
+            padding: 24.0,
+            row_gap: 14.0,
+            border_width: 1.5,
+            border_radius: 10.0,
+            header_font_size: 18.0,
+            footer_font_size: 14.0,
+        },
+        xlarge: PanelSizeProperties {
+            padding: 32.0,
+            row_gap: 18.0,
+            border_width: 2.0,
+            border_radius: 12.0,
+            header_font_size: 20.0,
+            footer_font_size: 16.0,
+        },
+    }
+}
@@ -15,6 +15,20 @@ pub struct RadioButtonStyle {
     pub disabled_inner_unchecked_background: Color,
     pub disabled_inner_checked_background: Color,
     pub caption_color: Color,
+    pub checked_caption_color: Color,
+    pub description_color: Color,
+    // Container colors, the whole radio is drawn as a card or segment
+    pub background: Color,
+    pub hovered_background: Color,
+    pub checked_background: Color,
+    pub disabled_background: Color,
+    pub disabled_checked_background: Color,
+    pub border: Color,
+    pub hovered_border: Color,
+    pub checked_border: Color,
+    pub disabled_border: Color,
+    // Segmented radios show no indicator circle
+    pub show_indicator: bool,
     // Transitions
     pub transition_duration: f32,
 }
@@ -29,6 +43,8 @@ pub struct RadioButtonStyleAlphas {
 #[derive(Debug, Clone)]
 pub struct RadioButtonVariantStyles {
     pub default: RadioButtonStyle,
+    pub card: RadioButtonStyle,
+    pub segmented: RadioButtonStyle,
 }
 
 impl RadioButtonVariantStyles {
@@ -39,22 +55,67 @@ impl RadioButtonVariantStyles {
             hovered_alpha: 0.5,
         };
 
+        let default = RadioButtonStyle {
+            outer_border: colors.primary,
+            disabled_outer_border: colors.primary.with_alpha(alphas.disabled_outer_alpha),
+            hovered_outer_border: colors.primary.with_alpha(alphas.hovered_alpha),
+            hovered_inner_checked_background: colors.primary.with_alpha(alphas.hovered_alpha),
+            hovered_inner_unchecked_background: Color::NONE,
+            inner_checked_background: colors.primary,
+            inner_unchecked_background: Color::NONE,
+            disabled_inner_unchecked_background: Color::NONE,
+            disabled_inner_checked_background: colors
+                .primary
+                .with_alpha(alphas.disabled_inner_checked_alpha),
+            caption_color: colors.foreground,
+            checked_caption_color: colors.foreground,
+            description_color: colors.muted_foreground,
+            background: Color::NONE,
+            hovered_background: Color::NONE,
+            checked_background: Color::NONE,
+            disabled_background: Color::NONE,
+            disabled_checked_background: Color::NONE,
+            border: Color::NONE,
+            hovered_border: Color::NONE,
+            checked_border: Color::NONE,
+            disabled_border: Color::NONE,
+            show_indicator: true,
+            transition_duration: 0.2,
+        };
+
         Self {
-            default: RadioButtonStyle {
-                outer_border: colors.primary,
-                disabled_outer_border: colors.primary.with_alpha(alphas.disabled_outer_alpha),
-                hovered_outer_border: colors.primary.with_alpha(alphas.hovered_alpha),
-                hovered_inner_checked_background: colors.primary.with_alpha(alphas.hovered_alpha),
-                hovered_inner_unchecked_background: Color::NONE,
-                inner_checked_background: colors.primary,
-                inner_unchecked_background: Color::NONE,
-                disabled_inner_unchecked_background: Color::NONE,
-                disabled_inner_checked_background: colors
+            card: RadioButtonStyle {
+                caption_color: colors.card_foreground,
+                checked_caption_color: colors.card_foreground,
+                background: colors.card,
+                hovered_background: colors.accent,
+                checked_background: colors.primary.with_alpha(0.05),
+                disabled_background: colors.card.with_alpha(alphas.disabled_outer_alpha),
+                disabled_checked_background: colors.primary.with_alpha(0.05),
+                border: colors.border,
+                hovered_border: colors.primary.with_alpha(alphas.hovered_alpha),
+                checked_border: colors.primary,
+                disabled_border: colors.border.with_alpha(alphas.disabled_outer_alpha),
+                ..default.clone()
+            },
+            segmented: RadioButtonStyle {
+                caption_color: colors.foreground,
+                checked_caption_color: colors.primary_foreground,
+                background: colors.background,
+                hovered_background: colors.accent,
+                checked_background: colors.primary,
+                disabled_background: colors.background.with_alpha(alphas.disabled_outer_alpha),
+                disabled_checked_background: colors
                     .primary
                     .with_alpha(alphas.disabled_inner_checked_alpha),
-                caption_color: colors.foreground,
-                transition_duration: 0.2,
+                border: colors.border,
+                hovered_border: colors.border,
+                checked_border: colors.primary,
+                disabled_border: colors.border.with_alpha(alphas.disabled_outer_alpha),
+                show_indicator: false,
+                ..default.clone()
             },
+            default,
         }
     }
 }
@@ -74,6 +135,12 @@ pub struct RadioButtonSizeProperties {
     pub padding_horizontal: f32,
     pub padding_vertical: f32,
     pub column_gap: f32,
+    pub description_font_size: f32,
+    pub icon_size: f32,
+    // Card and segmented variants
+    pub card_padding: f32,
+    pub container_border_width: f32,
+    pub container_corner_radius: f32,
 }
 
 // Collection of size variants for Radio button
@@ -102,6 +169,11 @@ pub fn radio_button_sizes() -> RadioButtonSizeStyles {
             padding_horizontal: 8.0,
             padding_vertical: 4.0,
             column_gap: 3.0,
+            description_font_size: 12.0,
+            icon_size: 14.0,
+            card_padding: 8.0,
+            container_border_width: 1.0,
+            container_corner_radius: 4.0,
         },
         small: RadioButtonSizeProperties {
             outer_border_width: 1.0,
@@ -117,6 +189,11 @@ pub fn radio_button_sizes() -> RadioButtonSizeStyles {
             padding_horizontal: 8.0,
             padding_vertical: 4.0,
             column_gap: 3.0,
+            description_font_size: 12.0,
+            icon_size: 16.0,
+            card_padding: 10.0,
+            container_border_width: 1.0,
+            container_corner_radius: 5.0,
         },
         medium: RadioButtonSizeProperties {
             outer_border_width: 1.0,
@@ -132,6 +209,11 @@ pub fn radio_button_sizes() -> RadioButtonSizeStyles {
             padding_horizontal: 10.0,
             padding_vertical: 6.0,
             column_gap: 4.0,
+            description_font_size: 13.0,
+            icon_size: 18.0,
+            card_padding: 12.0,
+            container_border_width: 1.0,
+            container_corner_radius: 6.0,
         },
         large: RadioButtonSizeProperties {
             outer_border_width: 1.0,
@@ -147,6 +229,11 @@ pub fn radio_button_sizes() -> RadioButtonSizeStyles {
             padding_horizontal: 12.0,
             padding_vertical: 8.0,
             column_gap: 5.0,
+            description_font_size: 14.0,
+            icon_size: 22.0,
+            card_padding: 16.0,
+            container_border_width: 1.0,
+            container_corner_radius: 8.0,
         },
         xlarge: RadioButtonSizeProperties {
             outer_border_width: 1.0,
@@ -162,6 +249,11 @@ pub fn radio_button_sizes() -> RadioButtonSizeStyles {
             padding_horizontal: 16.0,
             padding_vertical: 10.0,
             column_gap: 5.0,
+            description_font_size: 16.0,
+            icon_size: 28.0,
+            card_padding: 20.0,
+            container_border_width: 2.0,
+            container_corner_radius: 10.0,
         },
     }
 }
@@ -6,12 +6,108 @@ use super::ThemeColors;
 pub struct SliderStyle {
     pub track_color: Color,
     pub thumb_color: Color,
+    /// Part of the track inside the selected range, or up to the thumb.
+    pub fill_color: Color,
+    pub tick_color: Color,
+    pub tick_label_color: Color,
+    pub bubble_background: Color,
+    pub bubble_text_color: Color,
 }
 impl SliderStyle {
     pub fn from_colors(colors: ThemeColors) -> Self {
         Self {
             track_color: colors.primary.with_alpha(0.5),
             thumb_color: colors.primary,
+            fill_color: colors.primary,
+            tick_color: colors.muted_foreground,
+            tick_label_color: colors.muted_foreground,
+            bubble_background: colors.primary,
+            bubble_text_color: colors.primary_foreground,
         }
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct SliderSizeProperties {
+    pub track_thickness: f32,
+    pub track_corner_radius: f32,
+    pub thumb_size: f32,
+    pub thumb_corner_radius: f32,
+    pub tick_length: f32,
+    pub tick_label_font_size: f32,
+    /// Width of a tick label, the label is centered on its tick.
+    pub tick_label_width: f32,
+    pub bubble_font_size: f32,
+    /// Width of the value bubble's container, the bubble is centered on the thumb.
+    pub bubble_width: f32,
+}
+
+// Collection of size variants for Slider
+#[derive(Debug, Clone)]
+pub struct SliderSizeStyles {
+    pub xsmall: SliderSizeProperties,
+    pub small: SliderSizeProperties,
+    pub medium: SliderSizeProperties,
+    pub large: SliderSizeProperties,
+    pub xlarge: SliderSizeProperties,
+}
+
+pub fn slider_sizes() -> SliderSizeStyles {
+    SliderSizeStyles {
+        xsmall: SliderSizeProperties {
+            track_thickness: 2.0,
+            track_corner_radius: 1.0,
+            thumb_size: 8.0,
+            thumb_corner_radius: 4.0,
+            tick_length: 4.0,
+            tick_label_font_size: 8.0,
+            tick_label_width: 32.0,
+            bubble_font_size: 9.0,
+            bubble_width: 48.0,
+        },
+        small: SliderSizeProperties {
+            track_thickness: 4.0,
+            track_corner_radius: 2.0,
+            thumb_size: 10.0,
+            thumb_corner_radius: 5.0,
+            tick_length: 5.0,
+            tick_label_font_size: 9.0,
+            tick_label_width: 36.0,
+            bubble_font_size: 10.0,
+            bubble_width: 56.0,
+        },
+        medium: SliderSizeProperties {
+            track_thickness: 6.0,
+            track_corner_radius: 3.0,
+            thumb_size: 12.0,
+            thumb_corner_radius: 6.0,
+            tick_length: 6.0,
+            tick_label_font_size: 10.0,
+            tick_label_width: 40.0,
+            bubble_font_size: 12.0,
+            bubble_width: 64.0,
+        },
+        large: SliderSizeProperties {
+            track_thickness: 8.0,
+            track_corner_radius: 4.0,
+            thumb_size: 16.0,
+            thumb_corner_radius: 8.0,
+            tick_length: 7.0,
+            tick_label_font_size: 11.0,
+            tick_label_width: 44.0,
+            bubble_font_size: 13.0,
+            bubble_width: 72.0,
+        },
+        xlarge: SliderSizeProperties {
+            track_thickness: 10.0,
+            track_corner_radius: 5.0,
+            thumb_size: 20.0,
+            thumb_corner_radius: 10.0,
+            tick_length: 8.0,
+            tick_label_font_size: 12.0,
+            tick_label_width: 48.0,
+            bubble_font_size: 14.0,
+            bubble_width: 80.0,
+        },
+    }
+}
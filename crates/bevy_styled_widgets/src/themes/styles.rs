@@ -6,10 +6,11 @@ use super::{
     ThemeModeConfigs,
     button::{ButtonSizeStyles, ButtonVariantStyles, button_sizes},
     checkbox::{CheckboxSizeStyles, CheckboxVariantStyles, checkbox_sizes},
+    focus_ring::{FocusRingSizeStyles, FocusRingStyle, focus_ring_sizes},
     input::InputStyle,
-    panel::PanelStyle,
+    panel::{PanelSizeStyles, PanelStyle, PanelVariantStyles, panel_sizes},
     progress::ProgressStyle,
-    slider::SliderStyle,
+    slider::{SliderSizeStyles, SliderStyle, slider_sizes},
     radio::{RadioButtonSizeStyles, RadioButtonVariantStyles, radio_button_sizes},
     switch::{SwitchSizeStyles, SwitchVariantStyles, switch_sizes},
     text::TextStyle,
@@ -22,6 +23,8 @@ pub struct ThemeStyles {
     pub button_sizes: ButtonSizeStyles,
     pub text: TextStyle,
     pub panel: PanelStyle,
+    pub panels: PanelVariantStyles,
+    pub panel_sizes: PanelSizeStyles,
     pub icons: HashMap<String, String>,
     pub switches: SwitchVariantStyles,
     pub switch_sizes: SwitchSizeStyles,
@@ -32,8 +35,11 @@ pub struct ThemeStyles {
     pub checkbox_sizes: CheckboxSizeStyles,
     pub progress: ProgressStyle,
     pub slider: SliderStyle,
+    pub slider_sizes: SliderSizeStyles,
     pub radio_buttons: RadioButtonVariantStyles,
     pub radio_button_sizes: RadioButtonSizeStyles,
+    pub focus_ring: FocusRingStyle,
+    pub focus_ring_sizes: FocusRingSizeStyles,
 }
 
 impl ThemeStyles {
@@ -43,6 +49,8 @@ impl ThemeStyles {
             button_sizes: button_sizes(),
             text: TextStyle::from_colors(configs.colors.clone()),
             panel: PanelStyle::from_colors(configs.colors.clone()),
+            panels: PanelVariantStyles::from_colors(configs.colors.clone()),
+            panel_sizes: panel_sizes(),
             switches: SwitchVariantStyles::from_colors(configs.colors.clone()),
             switch_sizes: switch_sizes(),
             icons: configs.icons.clone(),
@@ -53,8 +61,11 @@ impl ThemeStyles {
             checkbox_sizes: checkbox_sizes(),
             progress: ProgressStyle::from_colors(configs.colors.clone()),
             slider: SliderStyle::from_colors(configs.colors.clone()),
+            slider_sizes: slider_sizes(),
             radio_buttons: RadioButtonVariantStyles::from_colors(configs.colors.clone()),
             radio_button_sizes: radio_button_sizes(),
+            focus_ring: FocusRingStyle::from_colors(configs.colors.clone()),
+            focus_ring_sizes: focus_ring_sizes(),
         }
     }
 }
@@ -93,6 +93,8 @@ pub struct SwitchSizeProperties {
     pub label_font_size: f32,
     pub label_offset_on: f32,
     pub label_offset: f32,
+    pub knob_icon_size: f32,
+    pub track_icon_size: f32,
 }
 
 // Collection of size variants for Switch
@@ -120,6 +122,8 @@ pub fn switch_sizes() -> SwitchSizeStyles {
             label_font_size: 6.0,
             label_offset_on: 1.0,
             label_offset: 12.0,
+            knob_icon_size: 6.0,
+            track_icon_size: 6.0,
         },
         small: SwitchSizeProperties {
             track_border_width: 0.0,
@@ -134,6 +138,8 @@ pub fn switch_sizes() -> SwitchSizeStyles {
             label_font_size: 8.0,
             label_offset_on: 2.0,
             label_offset: 17.0,
+            knob_icon_size: 8.0,
+            track_icon_size: 8.0,
         },
         medium: SwitchSizeProperties {
             track_border_width: 0.0,
@@ -148,6 +154,8 @@ pub fn switch_sizes() -> SwitchSizeStyles {
             label_font_size: 9.0,
             label_offset_on: 3.0,
             label_offset: 22.0,
+            knob_icon_size: 10.0,
+            track_icon_size: 10.0,
         },
         large: SwitchSizeProperties {
             track_border_width: 0.0,
@@ -162,6 +170,8 @@ pub fn switch_sizes() -> SwitchSizeStyles {
             label_font_size: 10.0,
             label_offset_on: 3.0,
             label_offset: 26.0,
+            knob_icon_size: 12.0,
+            track_icon_size: 12.0,
         },
         xlarge: SwitchSizeProperties {
             track_border_width: 0.0,
@@ -176,6 +186,8 @@ pub fn switch_sizes() -> SwitchSizeStyles {
             label_font_size: 12.0,
             label_offset_on: 3.0,
             label_offset: 30.0,
+            knob_icon_size: 14.0,
+            track_icon_size: 14.0,
         },
     }
 }
@@ -0,0 +1,46 @@
+use bevy::{ecs::component::Mutable, prelude::*};
+
+/// A widget whose value can be bound to app data with [`Bind`].
+pub trait BindableWidget: Component<Mutability = Mutable> {
+    type Value: Clone + PartialEq + Send + Sync + 'static;
+
+    fn value(&self) -> Self::Value;
+
+    fn set_value(&mut self, value: Self::Value);
+}
+
+/// Binds the value of the widget on the same entity to a field of `S`.
+///
+/// `S` is a resource registered with [`BindResourcePlugin`](super::BindResourcePlugin),
+/// or a component registered with [`BindComponentPlugin`](super::BindComponentPlugin).
+///
+/// ```ignore
+/// commands.spawn((
+///     StyledSlider::builder().max(100.0).build(),
+///     Bind::resource(|settings: &mut MySettings| &mut settings.volume),
+/// ));
+/// ```
+#[derive(Component)]
+pub struct Bind<S: 'static, V: 'static> {
+    /// Entity holding the bound component, `None` for resources.
+    pub target: Option<Entity>,
+    pub accessor: fn(&mut S) -> &mut V,
+}
+
+impl<S: Resource, V: 'static> Bind<S, V> {
+    pub fn resource(accessor: fn(&mut S) -> &mut V) -> Self {
+        Self {
+            target: None,
+            accessor,
+        }
+    }
+}
+
+impl<S: Component<Mutability = Mutable>, V: 'static> Bind<S, V> {
+    pub fn component(target: Entity, accessor: fn(&mut S) -> &mut V) -> Self {
+        Self {
+            target: Some(target),
+            accessor,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+
+use bevy::{ecs::component::Mutable, prelude::*};
+
+use super::systems::{sync_component_bindings, sync_resource_bindings};
+use crate::ui::{
+    checkbox::{StyledCheckbox, sync_checkbox_state},
+    input::{StyledInput, update_input_colors},
+    progress::{StyledProgress, update_progress_value},
+    radio_group::{RadioGroup, RadioGroupValue, sync_radio_group_value},
+    slider::{StyledSlider, sync_slider_state},
+    switch::{StyledSwitch, sync_switch_state},
+    toggle::{StyledToggle, sync_toggle_state},
+};
+
+/// Enables [`Bind`](super::Bind) for fields of the resource `S`.
+///
+/// Bindings run before the widgets push their state to the core widgets, so a value taken
+/// from `S` is shown in the same frame.
+pub struct BindResourcePlugin<S> {
+    radio_groups: Vec<fn(&mut App)>,
+    marker: PhantomData<S>,
+}
+
+impl<S> Default for BindResourcePlugin<S> {
+    fn default() -> Self {
+        Self {
+            radio_groups: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S: Resource> BindResourcePlugin<S> {
+    /// Also binds the value of [`RadioGroup<T>`] groups to `Option<T>` fields of `S`.
+    pub fn radio_group<T: RadioGroupValue>(mut self) -> Self {
+        self.radio_groups.push(|app| {
+            app.add_systems(
+                Update,
+                sync_resource_bindings::<S, RadioGroup<T>>.before(sync_radio_group_value::<T>),
+            );
+        });
+        self
+    }
+}
+
+impl<S: Resource> Plugin for BindResourcePlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                sync_resource_bindings::<S, StyledCheckbox>.before(sync_checkbox_state),
+                sync_resource_bindings::<S, StyledSwitch>.before(sync_switch_state),
+                sync_resource_bindings::<S, StyledToggle>.before(sync_toggle_state),
+                sync_resource_bindings::<S, StyledSlider>.before(sync_slider_state),
+                sync_resource_bindings::<S, StyledProgress>.before(update_progress_value),
+                sync_resource_bindings::<S, StyledInput>.before(update_input_colors),
+            ),
+        );
+        for add_radio_group in self.radio_groups.iter() {
+            add_radio_group(app);
+        }
+    }
+}
+
+/// Enables [`Bind`](super::Bind) for fields of the component `S`.
+pub struct BindComponentPlugin<S> {
+    radio_groups: Vec<fn(&mut App)>,
+    marker: PhantomData<S>,
+}
+
+impl<S> Default for BindComponentPlugin<S> {
+    fn default() -> Self {
+        Self {
+            radio_groups: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S: Component<Mutability = Mutable>> BindComponentPlugin<S> {
+    /// Also binds the value of [`RadioGroup<T>`] groups to `Option<T>` fields of `S`.
+    pub fn radio_group<T: RadioGroupValue>(mut self) -> Self {
+        self.radio_groups.push(|app| {
+            app.add_systems(
+                Update,
+                sync_component_bindings::<S, RadioGroup<T>>.before(sync_radio_group_value::<T>),
+            );
+        });
+        self
+    }
+}
+
+impl<S: Component<Mutability = Mutable>> Plugin for BindComponentPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                sync_component_bindings::<S, StyledCheckbox>.before(sync_checkbox_state),
+                sync_component_bindings::<S, StyledSwitch>.before(sync_switch_state),
+                sync_component_bindings::<S, StyledToggle>.before(sync_toggle_state),
+                sync_component_bindings::<S, StyledSlider>.before(sync_slider_state),
+                sync_component_bindings::<S, StyledProgress>.before(update_progress_value),
+                sync_component_bindings::<S, StyledInput>.before(update_input_colors),
+            ),
+        );
+        for add_radio_group in self.radio_groups.iter() {
+            add_radio_group(app);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use bevy::{ecs::component::Mutable, prelude::*};
+
+use super::components::{Bind, BindableWidget};
+
+// Sync widgets with resource fields. A changed widget writes the resource, otherwise a
+// changed resource updates the widget. Values are compared first so neither side is
+// marked changed by a no-op write, which keeps the two from feeding back into each other.
+pub fn sync_resource_bindings<S: Resource, W: BindableWidget>(
+    data: Option<ResMut<S>>,
+    mut query: Query<(&mut W, Ref<Bind<S, W::Value>>)>,
+) {
+    let Some(mut data) = data else {
+        return;
+    };
+
+    for (mut widget, bind) in query.iter_mut() {
+        let widget_value = widget.value();
+        let data_value = (bind.accessor)(data.bypass_change_detection()).clone();
+        if widget_value == data_value {
+            continue;
+        }
+
+        // The data wins when the binding is new
+        if widget.is_changed() && !bind.is_added() {
+            *(bind.accessor)(&mut data) = widget_value;
+        } else if data.is_changed() || bind.is_added() {
+            widget.set_value(data_value);
+        }
+    }
+}
+
+// Sync widgets with fields of components on other entities, see `sync_resource_bindings`.
+pub fn sync_component_bindings<S: Component<Mutability = Mutable>, W: BindableWidget>(
+    mut q_data: Query<&mut S, Without<W>>,
+    mut query: Query<(&mut W, Ref<Bind<S, W::Value>>)>,
+) {
+    for (mut widget, bind) in query.iter_mut() {
+        let Some(target) = bind.target else {
+            continue;
+        };
+        let Ok(mut data) = q_data.get_mut(target) else {
+            continue;
+        };
+
+        let widget_value = widget.value();
+        let data_value = (bind.accessor)(data.bypass_change_detection()).clone();
+        if widget_value == data_value {
+            continue;
+        }
+
+        if widget.is_changed() && !bind.is_added() {
+            *(bind.accessor)(&mut data) = widget_value;
+        } else if data.is_changed() || bind.is_added() {
+            widget.set_value(data_value);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::components::BindableWidget;
+use crate::ui::{
+    checkbox::StyledCheckbox,
+    input::StyledInput,
+    progress::StyledProgress,
+    radio_group::{RadioGroup, RadioGroupValue},
+    slider::StyledSlider,
+    switch::StyledSwitch,
+    toggle::StyledToggle,
+};
+
+impl BindableWidget for StyledCheckbox {
+    type Value = bool;
+
+    fn value(&self) -> bool {
+        self.checked
+    }
+
+    fn set_value(&mut self, value: bool) {
+        self.checked = value;
+        self.indeterminate = false;
+    }
+}
+
+impl BindableWidget for StyledSwitch {
+    type Value = bool;
+
+    fn value(&self) -> bool {
+        self.state
+    }
+
+    fn set_value(&mut self, value: bool) {
+        self.state = value;
+    }
+}
+
+impl BindableWidget for StyledToggle {
+    type Value = bool;
+
+    fn value(&self) -> bool {
+        self.active
+    }
+
+    fn set_value(&mut self, value: bool) {
+        self.active = value;
+    }
+}
+
+impl BindableWidget for StyledSlider {
+    type Value = f32;
+
+    fn value(&self) -> f32 {
+        self.value
+    }
+
+    fn set_value(&mut self, value: f32) {
+        self.value = value;
+    }
+}
+
+impl BindableWidget for StyledProgress {
+    type Value = f32;
+
+    fn value(&self) -> f32 {
+        self.value
+    }
+
+    fn set_value(&mut self, value: f32) {
+        self.value = value;
+    }
+}
+
+impl BindableWidget for StyledInput {
+    type Value = String;
+
+    fn value(&self) -> String {
+        self.value.clone().unwrap_or_default()
+    }
+
+    fn set_value(&mut self, value: String) {
+        self.value = Some(value);
+    }
+}
+
+impl<T: RadioGroupValue> BindableWidget for RadioGroup<T> {
+    type Value = Option<T>;
+
+    fn value(&self) -> Option<T> {
+        self.value.clone()
+    }
+
+    fn set_value(&mut self, value: Option<T>) {
+        self.value = value;
+    }
+}
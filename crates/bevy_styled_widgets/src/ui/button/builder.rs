@@ -5,23 +5,36 @@ use bevy::{
 use bevy_core_widgets::{CoreButton, hover::Hovering};
 
 use super::{
-    ButtonSize, StyledButtonText,
-    components::{AccessibleName, ButtonVariant, StyledButton},
+    ButtonSize, StyledButtonIcon, StyledButtonText,
+    components::{
+        AccessibleName, ButtonLoadingPlacement, ButtonVariant, StyledButton, StyledButtonSpinner,
+        StyledButtonSpinnerDot, StyledButtonSpinnerRotor,
+    },
+};
+use crate::{
+    themes::ThemeManager,
+    ui::icon::{IconId, StyledIcon},
 };
 
 #[derive(Default)]
 pub struct ButtonBuilder {
     variant: ButtonVariant,
     on_click: Option<SystemId>,
+    href: Option<String>,
+    on_navigate: Option<SystemId<In<String>>>,
     background_color: Option<Color>,
     border_color: Option<Color>,
     hover_background_color: Option<Color>,
     hover_border_color: Option<Color>,
     text_color: Option<Color>,
     text: Option<String>,
-    icon: Option<String>,
+    leading_icon: Option<IconId>,
+    trailing_icon: Option<IconId>,
+    label: Option<String>,
     size: Option<ButtonSize>,
     disabled: bool,
+    loading: bool,
+    loading_placement: ButtonLoadingPlacement,
     font: Option<Handle<Font>>,
 }
 
@@ -36,13 +49,50 @@ impl ButtonBuilder {
         self
     }
 
+    /// Sets the link target delivered to `on_navigate` by [`ButtonVariant::Link`] buttons.
+    pub fn href<S: Into<String>>(mut self, href: S) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    pub fn on_navigate(mut self, system_id: SystemId<In<String>>) -> Self {
+        self.on_navigate = Some(system_id);
+        self
+    }
+
     pub fn text<S: Into<String>>(mut self, text: S) -> Self {
         self.text = Some(text.into());
         self
     }
 
-    pub fn icon<S: Into<String>>(mut self, icon: S) -> Self {
-        self.icon = Some(icon.into());
+    /// Sets the leading icon, shown before the text.
+    pub fn icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.leading_icon = Some(icon.into());
+        self
+    }
+
+    pub fn leading_icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.leading_icon = Some(icon.into());
+        self
+    }
+
+    pub fn trailing_icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.trailing_icon = Some(icon.into());
+        self
+    }
+
+    /// Builds an icon-only button. The label is required since there is no visible text.
+    pub fn icon_only<I: Into<IconId>, S: Into<String>>(mut self, icon: I, label: S) -> Self {
+        self.leading_icon = Some(icon.into());
+        self.trailing_icon = None;
+        self.text = None;
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the accessible label, which defaults to the button text.
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
         self
     }
 
@@ -76,6 +126,16 @@ impl ButtonBuilder {
         self
     }
 
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    pub fn loading_placement(mut self, placement: ButtonLoadingPlacement) -> Self {
+        self.loading_placement = placement;
+        self
+    }
+
     pub fn size(mut self, size: ButtonSize) -> Self {
         self.size = Some(size);
         self
@@ -87,6 +147,26 @@ impl ButtonBuilder {
     }
 
     pub fn build(self) -> impl Bundle {
+        let accessible_name = match (&self.label, &self.text) {
+            (Some(label), _) => label.clone(),
+            (None, Some(text)) => text.clone(),
+            (None, None) => {
+                if self.leading_icon.is_some() || self.trailing_icon.is_some() {
+                    warn!("Icon-only buttons need an accessible label, use `icon_only` or `label`");
+                }
+                "Button".to_string()
+            }
+        };
+
+        let button_size_styles = ThemeManager::default().styles.button_sizes;
+        let spinner_size = match self.size.unwrap_or_default() {
+            ButtonSize::XSmall => button_size_styles.xsmall.icon_size,
+            ButtonSize::Small => button_size_styles.small.icon_size,
+            ButtonSize::Medium => button_size_styles.medium.icon_size,
+            ButtonSize::Large => button_size_styles.large.icon_size,
+            ButtonSize::XLarge => button_size_styles.xlarge.icon_size,
+        };
+
         (
             Node {
                 display: Display::Flex,
@@ -103,28 +183,78 @@ impl ButtonBuilder {
             CursorIcon::System(SystemCursorIcon::Pointer),
             StyledButton {
                 text: self.text.clone(),
-                icon: self.icon.clone(),
+                leading_icon: self.leading_icon.clone(),
+                trailing_icon: self.trailing_icon.clone(),
+                label: self.label.clone(),
                 font: self.font,
                 variant: self.variant,
                 size: self.size,
                 on_click: self.on_click,
+                href: self.href.clone(),
+                on_navigate: self.on_navigate,
                 background_color: self.background_color,
                 border_color: self.border_color,
                 hover_background_color: self.hover_background_color,
                 hover_border_color: self.hover_border_color,
                 text_color: self.text_color,
                 disabled: self.disabled,
+                loading: self.loading,
+                loading_placement: self.loading_placement,
             },
             CoreButton {
-                on_click: self.on_click,
+                on_click: if self.loading { None } else { self.on_click },
             },
-            AccessibleName(self.text.clone().unwrap_or_else(|| "Button".to_string())),
+            AccessibleName(accessible_name),
             TabIndex(0),
-            Children::spawn(Spawn((
-                Text::new(""),
-                TextFont::default(),
-                StyledButtonText,
-            ))),
+            Children::spawn((
+                // Spinner, laid out by `update_button` from the loading placement
+                Spawn((
+                    Node {
+                        display: Display::None,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    StyledButtonSpinner,
+                    children![(
+                        Node {
+                            width: Val::Px(spinner_size),
+                            height: Val::Px(spinner_size),
+                            justify_content: JustifyContent::Center,
+                            ..default()
+                        },
+                        StyledButtonSpinnerRotor,
+                        children![(
+                            Node {
+                                width: Val::Px(spinner_size / 4.0),
+                                height: Val::Px(spinner_size / 4.0),
+                                ..default()
+                            },
+                            BorderRadius::MAX,
+                            BackgroundColor::default(),
+                            StyledButtonSpinnerDot,
+                        )],
+                    )],
+                )),
+                Spawn((
+                    StyledIcon::builder()
+                        .icon(self.leading_icon.unwrap_or_default())
+                        .build(),
+                    StyledButtonIcon::Leading,
+                )),
+                Spawn((
+                    Text::new(""),
+                    TextFont::default(),
+                    BorderColor::default(),
+                    StyledButtonText,
+                )),
+                Spawn((
+                    StyledIcon::builder()
+                        .icon(self.trailing_icon.unwrap_or_default())
+                        .build(),
+                    StyledButtonIcon::Trailing,
+                )),
+            )),
         )
     }
 }
@@ -1,6 +1,9 @@
 use bevy::a11y::AccessibilityNode;
 use bevy::ecs::{component::HookContext, system::SystemId, world::DeferredWorld};
 use bevy::prelude::*;
+use bevy::tasks::Task;
+
+use crate::ui::icon::IconId;
 
 #[derive(Component, Reflect)]
 #[reflect(from_reflect = false)]
@@ -10,16 +13,25 @@ pub struct StyledButton {
     #[reflect(ignore)]
     pub size: Option<ButtonSize>,
     pub text: Option<String>,
-    pub icon: Option<String>,
+    pub leading_icon: Option<IconId>,
+    pub trailing_icon: Option<IconId>,
+    pub label: Option<String>,
     pub font: Option<Handle<Font>>,
     #[reflect(ignore)]
     pub on_click: Option<SystemId>,
+    pub href: Option<String>,
+    #[reflect(ignore)]
+    pub on_navigate: Option<SystemId<In<String>>>,
     pub background_color: Option<Color>,
     pub border_color: Option<Color>,
     pub hover_background_color: Option<Color>,
     pub hover_border_color: Option<Color>,
     pub text_color: Option<Color>,
     pub disabled: bool,
+    /// Shows a spinner and ignores clicks while set.
+    pub loading: bool,
+    #[reflect(ignore)]
+    pub loading_placement: ButtonLoadingPlacement,
 }
 
 impl StyledButton {
@@ -30,6 +42,38 @@ impl StyledButton {
 #[derive(Component)]
 pub struct StyledButtonText;
 
+/// Placement of an icon child relative to the button text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub enum StyledButtonIcon {
+    Leading,
+    Trailing,
+}
+
+/// Where the spinner of a loading button is shown.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ButtonLoadingPlacement {
+    /// The spinner is drawn over the hidden content, keeping the button width.
+    #[default]
+    Replace,
+    /// The spinner takes the place of the leading icon, next to the text.
+    Leading,
+}
+
+#[derive(Component)]
+pub struct StyledButtonSpinner;
+
+#[derive(Component)]
+pub struct StyledButtonSpinnerRotor;
+
+#[derive(Component)]
+pub struct StyledButtonSpinnerDot;
+
+/// Keeps the button loading until the task completes, then clears the loading state.
+///
+/// Insert it on a button entity to opt in.
+#[derive(Component)]
+pub struct ButtonLoadingTask(pub Task<()>);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
 pub enum ButtonVariant {
     Primary,
@@ -37,6 +81,7 @@ pub enum ButtonVariant {
     Destructive,
     Outline,
     Ghost,
+    Link,
 }
 
 impl Default for ButtonVariant {
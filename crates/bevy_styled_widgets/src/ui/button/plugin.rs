@@ -1,9 +1,28 @@
-use super::systems::update_button;
+use super::{
+    StyledButton,
+    systems::{
+        animate_button_spinners, on_link_click, on_link_key_input, poll_button_loading_tasks,
+        sync_button_state, update_button,
+    },
+};
+use crate::ui::disabled::sync_disabled;
 use bevy::prelude::*;
 
 pub struct StyledButtonPlugin;
 impl Plugin for StyledButtonPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, update_button);
+        app.add_observer(on_link_click)
+            .add_observer(on_link_key_input);
+        app.add_systems(
+            Update,
+            (
+                poll_button_loading_tasks,
+                sync_button_state,
+                sync_disabled::<StyledButton>,
+                update_button,
+                animate_button_spinners,
+            )
+                .chain(),
+        );
     }
 }
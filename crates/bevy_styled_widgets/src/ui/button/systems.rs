@@ -1,19 +1,65 @@
-use bevy::prelude::*;
-use bevy_core_widgets::{ButtonPressed, InteractionDisabled, hover::Hovering};
+use std::f32::consts::TAU;
 
-use crate::themes::ThemeManager;
+use bevy::{
+    a11y::AccessibilityNode,
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::FocusedInput,
+    prelude::*,
+};
+use bevy_core_widgets::{ButtonPressed, CoreButton, InteractionDisabled, hover::Hovering};
+
+use crate::{themes::ThemeManager, ui::icon::StyledIcon};
 
 use super::{
     ButtonSize,
-    components::{ButtonVariant, StyledButton, StyledButtonText},
+    components::{
+        AccessibleName, ButtonLoadingPlacement, ButtonLoadingTask, ButtonVariant, StyledButton,
+        StyledButtonIcon, StyledButtonSpinner, StyledButtonSpinnerDot, StyledButtonSpinnerRotor,
+        StyledButtonText,
+    },
 };
 
+// Turns per second of the loading spinner
+const SPINNER_SPEED: f32 = 1.2;
+
 // Update the button's background color.
 #[allow(clippy::type_complexity)]
 pub fn update_button(
     theme_manager: Res<ThemeManager>,
-    children: Query<&mut Children>,
-    mut text_query: Query<(&mut Text, &mut TextColor, &mut TextFont), With<StyledButtonText>>,
+    children: Query<&Children>,
+    mut text_query: Query<
+        (
+            &mut Text,
+            &mut TextColor,
+            &mut TextFont,
+            &mut Node,
+            &mut BorderColor,
+            &mut Visibility,
+        ),
+        (With<StyledButtonText>, Without<StyledButton>),
+    >,
+    mut icon_query: Query<
+        (
+            &StyledButtonIcon,
+            &mut StyledIcon,
+            &mut Node,
+            &mut Visibility,
+        ),
+        (Without<StyledButtonText>, Without<StyledButton>),
+    >,
+    mut spinner_query: Query<
+        &mut Node,
+        (
+            With<StyledButtonSpinner>,
+            Without<StyledButtonText>,
+            Without<StyledButtonIcon>,
+            Without<StyledButton>,
+        ),
+    >,
+    mut dot_query: Query<
+        &mut BackgroundColor,
+        (With<StyledButtonSpinnerDot>, Without<StyledButton>),
+    >,
     mut query: Query<(
         Entity,
         &mut Node,
@@ -41,13 +87,31 @@ pub fn update_button(
         // Get styles from theme manager
         let button_styles = theme_manager.styles.buttons.clone();
         let button_size_styles = theme_manager.styles.button_sizes.clone();
-        let theme_icons = theme_manager.styles.icons.clone();
+
+        //content is hidden but keeps its size while the spinner replaces it
+        let replace_content =
+            button.loading && button.loading_placement == ButtonLoadingPlacement::Replace;
+        let content_visibility = if replace_content {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
 
         // Update text
         //Get button text
         if let Ok(children) = children.get(button_entity_id) {
             for child in children.iter() {
-                if let Ok((mut text, mut text_color, mut text_font)) = text_query.get_mut(child) {
+                if let Ok((
+                    mut text,
+                    mut text_color,
+                    mut text_font,
+                    mut text_node,
+                    mut underline_color,
+                    mut text_visibility,
+                )) = text_query.get_mut(child)
+                {
+                    text_visibility.set_if_neq(content_visibility);
+
                     let button_styles = theme_manager.styles.buttons.clone();
                     let button_size_styles = theme_manager.styles.button_sizes.clone();
                     let button_style = match button.variant {
@@ -56,10 +120,24 @@ pub fn update_button(
                         ButtonVariant::Destructive => button_styles.destructive,
                         ButtonVariant::Outline => button_styles.outline,
                         ButtonVariant::Ghost => button_styles.ghost,
+                        ButtonVariant::Link => button_styles.link,
                     };
-                    let color = button_style.text_color;
+                    let color = button.text_color.unwrap_or(button_style.text_color);
                     text_color.0 = color;
 
+                    //links are underlined on hover, the border is always reserved
+                    //so the layout doesn't shift
+                    if button.variant == ButtonVariant::Link {
+                        text_node.border = UiRect::bottom(Val::Px(1.0));
+                        underline_color.0 = if *is_hovering && !is_disabled && !button.loading {
+                            color
+                        } else {
+                            Color::NONE
+                        };
+                    } else {
+                        text_node.border = UiRect::ZERO;
+                    }
+
                     //update font size
                     let button_size_style = match button.size.unwrap_or_default() {
                         ButtonSize::XSmall => button_size_styles.xsmall,
@@ -75,20 +153,105 @@ pub fn update_button(
                         text.0 = text_str.clone();
                     }
 
-                    //update icon
-                    if let Some(icon) = button.icon.clone() {
-                        if let Some(theme_icon) = theme_icons.get(&icon) {
-                            text.0 = theme_icon.clone();
-                        } else {
-                            text.0 = icon;
-                        };
-                    }
+                    //hide the text node when there is no text, so it takes no gap
+                    text_node.display = if button.text.is_some() {
+                        Display::Flex
+                    } else {
+                        Display::None
+                    };
 
                     //update font
                     if let Some(font) = &button.font {
                         text_font.font = font.clone();
                     }
                 }
+
+                //update leading and trailing icons
+                if let Ok((placement, mut icon, mut icon_node, mut icon_visibility)) =
+                    icon_query.get_mut(child)
+                {
+                    icon_visibility.set_if_neq(content_visibility);
+
+                    let button_style = match button.variant {
+                        ButtonVariant::Primary => &button_styles.primary,
+                        ButtonVariant::Secondary => &button_styles.secondary,
+                        ButtonVariant::Destructive => &button_styles.destructive,
+                        ButtonVariant::Outline => &button_styles.outline,
+                        ButtonVariant::Ghost => &button_styles.ghost,
+                        ButtonVariant::Link => &button_styles.link,
+                    };
+                    let button_size_style = match button.size.unwrap_or_default() {
+                        ButtonSize::XSmall => &button_size_styles.xsmall,
+                        ButtonSize::Small => &button_size_styles.small,
+                        ButtonSize::Medium => &button_size_styles.medium,
+                        ButtonSize::Large => &button_size_styles.large,
+                        ButtonSize::XLarge => &button_size_styles.xlarge,
+                    };
+
+                    let icon_id = match placement {
+                        StyledButtonIcon::Leading => &button.leading_icon,
+                        StyledButtonIcon::Trailing => &button.trailing_icon,
+                    };
+
+                    //a leading spinner takes the place of the leading icon
+                    let replaced_by_spinner = button.loading
+                        && button.loading_placement == ButtonLoadingPlacement::Leading
+                        && *placement == StyledButtonIcon::Leading;
+                    icon_node.display = if icon_id.is_some() && !replaced_by_spinner {
+                        Display::Flex
+                    } else {
+                        Display::None
+                    };
+
+                    if let Some(icon_id) = icon_id {
+                        if icon.icon != *icon_id {
+                            icon.icon = icon_id.clone();
+                        }
+                    }
+                    if icon.size != Some(button_size_style.icon_size) {
+                        icon.size = Some(button_size_style.icon_size);
+                    }
+                    let icon_color = button.text_color.unwrap_or(button_style.text_color);
+                    if icon.color != Some(icon_color) {
+                        icon.color = Some(icon_color);
+                    }
+                }
+
+                //update the loading spinner
+                if let Ok(mut spinner_node) = spinner_query.get_mut(child) {
+                    spinner_node.display = if button.loading {
+                        Display::Flex
+                    } else {
+                        Display::None
+                    };
+                    if replace_content {
+                        spinner_node.position_type = PositionType::Absolute;
+                        spinner_node.left = Val::Px(0.0);
+                        spinner_node.right = Val::Px(0.0);
+                        spinner_node.top = Val::Px(0.0);
+                        spinner_node.bottom = Val::Px(0.0);
+                    } else {
+                        spinner_node.position_type = PositionType::Relative;
+                        spinner_node.left = Val::Auto;
+                        spinner_node.right = Val::Auto;
+                        spinner_node.top = Val::Auto;
+                        spinner_node.bottom = Val::Auto;
+                    }
+
+                    let button_style = match button.variant {
+                        ButtonVariant::Primary => &button_styles.primary,
+                        ButtonVariant::Secondary => &button_styles.secondary,
+                        ButtonVariant::Destructive => &button_styles.destructive,
+                        ButtonVariant::Outline => &button_styles.outline,
+                        ButtonVariant::Ghost => &button_styles.ghost,
+                        ButtonVariant::Link => &button_styles.link,
+                    };
+                    for descendant in children.iter_descendants(child) {
+                        if let Ok(mut dot_color) = dot_query.get_mut(descendant) {
+                            dot_color.0 = button.text_color.unwrap_or(button_style.text_color);
+                        }
+                    }
+                }
             }
         };
 
@@ -99,24 +262,32 @@ pub fn update_button(
             ButtonVariant::Destructive => button_styles.destructive,
             ButtonVariant::Outline => button_styles.outline,
             ButtonVariant::Ghost => button_styles.ghost,
+            ButtonVariant::Link => button_styles.link,
         };
 
-        match (is_disabled, is_pressed, is_hovering) {
+        let normal_background = button
+            .background_color
+            .unwrap_or(button_style.normal_background);
+        let normal_border = button.border_color.unwrap_or(button_style.border_color);
+        // Loading buttons ignore clicks, so they don't react to hover and press either
+        match (is_disabled || button.loading, is_pressed, is_hovering) {
             (true, _, _) => {
-                bg_color.0 = button_style.normal_background;
-                border_color.0 = button_style.border_color;
+                bg_color.0 = normal_background;
+                border_color.0 = normal_border;
             }
             (_, true, true) => {
                 bg_color.0 = button_style.pressed_background;
-                border_color.0 = button_style.border_color;
+                border_color.0 = button.hover_border_color.unwrap_or(normal_border);
             }
             (_, false, true) => {
-                bg_color.0 = button_style.hovered_background;
-                border_color.0 = button_style.border_color;
+                bg_color.0 = button
+                    .hover_background_color
+                    .unwrap_or(button_style.hovered_background);
+                border_color.0 = button.hover_border_color.unwrap_or(normal_border);
             }
             _ => {
-                bg_color.0 = button_style.normal_background;
-                border_color.0 = button_style.border_color;
+                bg_color.0 = normal_background;
+                border_color.0 = normal_border;
             }
         };
 
@@ -128,10 +299,21 @@ pub fn update_button(
             ButtonSize::Large => button_size_styles.large,
             ButtonSize::XLarge => button_size_styles.xlarge,
         };
-        button_node.padding = UiRect::axes(
-            Val::Px(button_size_style.padding_horizontal),
-            Val::Px(button_size_style.padding_vertical),
-        );
+        let is_icon_only = button.text.is_none()
+            && (button.leading_icon.is_some() || button.trailing_icon.is_some());
+        button_node.padding = if button.variant == ButtonVariant::Link {
+            // Links are text-only
+            UiRect::ZERO
+        } else if is_icon_only {
+            // Icon-only buttons are square
+            UiRect::all(Val::Px(button_size_style.padding_vertical))
+        } else {
+            UiRect::axes(
+                Val::Px(button_size_style.padding_horizontal),
+                Val::Px(button_size_style.padding_vertical),
+            )
+        };
+        button_node.column_gap = Val::Px(button_size_style.gap);
         button_node.border = UiRect::all(Val::Px(button_size_style.border_width));
         border_radius.top_left = Val::Px(button_size_style.border_radius);
         border_radius.top_right = Val::Px(button_size_style.border_radius);
@@ -139,3 +321,111 @@ pub fn update_button(
         border_radius.bottom_right = Val::Px(button_size_style.border_radius);
     }
 }
+
+// Keep the core button in sync with `StyledButton`, which is the source of truth.
+// Clicks are blocked and the button is marked busy while it is loading.
+#[allow(clippy::type_complexity)]
+pub fn sync_button_state(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &StyledButton,
+            &mut CoreButton,
+            &AccessibleName,
+            Option<&mut AccessibilityNode>,
+        ),
+        Changed<StyledButton>,
+    >,
+) {
+    for (entity, button, mut core_button, name, accessibility) in query.iter_mut() {
+        let on_click = if button.loading {
+            None
+        } else {
+            button.on_click
+        };
+        if core_button.on_click != on_click {
+            core_button.on_click = on_click;
+        }
+
+        let accessible_name = button
+            .label
+            .clone()
+            .or_else(|| button.text.clone())
+            .unwrap_or_else(|| "Button".to_string());
+        if name.0 != accessible_name {
+            commands
+                .entity(entity)
+                .insert(AccessibleName(accessible_name));
+        }
+
+        if let Some(mut accessibility) = accessibility {
+            if button.loading {
+                accessibility.set_busy();
+            } else {
+                accessibility.clear_busy();
+            }
+        }
+    }
+}
+
+// Keep buttons loading until their paired task completes.
+pub fn poll_button_loading_tasks(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut StyledButton, &ButtonLoadingTask)>,
+) {
+    for (entity, mut button, task) in query.iter_mut() {
+        if task.0.is_finished() {
+            button.loading = false;
+            commands.entity(entity).remove::<ButtonLoadingTask>();
+        } else if !button.loading {
+            button.loading = true;
+        }
+    }
+}
+
+pub fn animate_button_spinners(
+    time: Res<Time>,
+    mut query: Query<(&mut Transform, &InheritedVisibility), With<StyledButtonSpinnerRotor>>,
+) {
+    for (mut transform, visibility) in query.iter_mut() {
+        if visibility.get() {
+            transform.rotate_z(-TAU * SPINNER_SPEED * time.delta_secs());
+        }
+    }
+}
+
+pub fn on_link_click(
+    trigger: Trigger<Pointer<Click>>,
+    q_link: Query<&StyledButton, Without<InteractionDisabled>>,
+    mut commands: Commands,
+) {
+    if let Ok(button) = q_link.get(trigger.target()) {
+        navigate(button, &mut commands);
+    }
+}
+
+pub fn on_link_key_input(
+    trigger: Trigger<FocusedInput<KeyboardInput>>,
+    q_link: Query<&StyledButton, Without<InteractionDisabled>>,
+    mut commands: Commands,
+) {
+    let event = &trigger.event().input;
+    if event.state == ButtonState::Pressed
+        && !event.repeat
+        && (event.key_code == KeyCode::Enter || event.key_code == KeyCode::Space)
+    {
+        if let Ok(button) = q_link.get(trigger.target()) {
+            navigate(button, &mut commands);
+        }
+    }
+}
+
+fn navigate(button: &StyledButton, commands: &mut Commands) {
+    if button.variant != ButtonVariant::Link || button.loading {
+        return;
+    }
+    if let (Some(on_navigate), Some(href)) = (button.on_navigate, button.href.clone()) {
+        commands.run_system_with(on_navigate, href);
+    }
+}
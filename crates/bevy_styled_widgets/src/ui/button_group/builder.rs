@@ -0,0 +1,84 @@
+use accesskit::Role;
+use bevy::{a11y::AccessibilityNode, ecs::spawn::SpawnIter, prelude::*};
+
+use super::components::{ButtonGroupOrientation, StyledButtonGroup};
+use crate::ui::button::{ButtonBuilder, ButtonSize, ButtonVariant};
+
+#[derive(Default)]
+pub struct ButtonGroupBuilder {
+    orientation: ButtonGroupOrientation,
+    variant: Option<ButtonVariant>,
+    size: Option<ButtonSize>,
+    label: Option<String>,
+    buttons: Vec<ButtonBuilder>,
+}
+
+impl ButtonGroupBuilder {
+    pub fn orientation(mut self, orientation: ButtonGroupOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the variant of every button in the group.
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Sets the size of every button in the group.
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn button(mut self, button: ButtonBuilder) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let flex_direction = match self.orientation {
+            ButtonGroupOrientation::Horizontal => FlexDirection::Row,
+            ButtonGroupOrientation::Vertical => FlexDirection::Column,
+        };
+
+        let mut accessible = accesskit::Node::new(Role::Group);
+        if let Some(label) = &self.label {
+            accessible.set_label(label.as_str());
+        }
+
+        let buttons: Vec<_> = self
+            .buttons
+            .into_iter()
+            .map(|mut button| {
+                if let Some(variant) = self.variant {
+                    button = button.variant(variant);
+                }
+                if let Some(size) = self.size {
+                    button = button.size(size);
+                }
+                button.build()
+            })
+            .collect();
+
+        (
+            Node {
+                display: Display::Flex,
+                flex_direction,
+                align_items: AlignItems::Stretch,
+                ..default()
+            },
+            Name::new("ButtonGroup"),
+            AccessibilityNode(accessible),
+            StyledButtonGroup {
+                orientation: self.orientation,
+            },
+            Children::spawn(SpawnIter(buttons.into_iter())),
+        )
+    }
+}
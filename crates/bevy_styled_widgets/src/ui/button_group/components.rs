@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+#[derive(Component, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct StyledButtonGroup {
+    #[reflect(ignore)]
+    pub orientation: ButtonGroupOrientation,
+}
+
+impl StyledButtonGroup {
+    pub fn builder() -> super::builder::ButtonGroupBuilder {
+        super::builder::ButtonGroupBuilder::default()
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub enum ButtonGroupOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
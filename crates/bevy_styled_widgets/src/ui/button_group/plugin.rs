@@ -0,0 +1,19 @@
+use super::systems::{
+    on_button_group_key_input, update_button_group_styles, update_button_group_tab_index,
+};
+use crate::ui::button::update_button;
+use bevy::prelude::*;
+
+pub struct StyledButtonGroupPlugin;
+impl Plugin for StyledButtonGroupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_button_group_key_input);
+        app.add_systems(
+            Update,
+            (
+                update_button_group_styles.after(update_button),
+                update_button_group_tab_index,
+            ),
+        );
+    }
+}
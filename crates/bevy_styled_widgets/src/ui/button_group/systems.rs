@@ -0,0 +1,117 @@
+use bevy::{
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::{FocusedInput, InputFocus, InputFocusVisible, tab_navigation::TabIndex},
+    prelude::*,
+};
+use bevy_core_widgets::InteractionDisabled;
+
+use crate::ui::{
+    button::StyledButton,
+    focus::{move_roving_focus, update_roving_tab_index},
+};
+
+use super::components::{ButtonGroupOrientation, StyledButtonGroup};
+
+// Merge the borders of grouped buttons and keep the radius on the outer corners only.
+// Runs after `update_button`, which resets the border and radius every frame.
+pub fn update_button_group_styles(
+    q_group: Query<(&StyledButtonGroup, &Children)>,
+    mut q_member: Query<(&mut Node, &mut BorderRadius), With<StyledButton>>,
+) {
+    for (group, children) in q_group.iter() {
+        let members: Vec<Entity> = children
+            .iter()
+            .filter(|child| q_member.contains(*child))
+            .collect();
+        let last = members.len().saturating_sub(1);
+
+        for (index, member) in members.iter().enumerate() {
+            let Ok((mut node, mut border_radius)) = q_member.get_mut(*member) else {
+                continue;
+            };
+            let is_first = index == 0;
+            let is_last = index == last;
+
+            match group.orientation {
+                ButtonGroupOrientation::Horizontal => {
+                    if !is_first {
+                        border_radius.top_left = Val::ZERO;
+                        border_radius.bottom_left = Val::ZERO;
+                        node.border.left = Val::ZERO;
+                    }
+                    if !is_last {
+                        border_radius.top_right = Val::ZERO;
+                        border_radius.bottom_right = Val::ZERO;
+                    }
+                }
+                ButtonGroupOrientation::Vertical => {
+                    if !is_first {
+                        border_radius.top_left = Val::ZERO;
+                        border_radius.top_right = Val::ZERO;
+                        node.border.top = Val::ZERO;
+                    }
+                    if !is_last {
+                        border_radius.bottom_left = Val::ZERO;
+                        border_radius.bottom_right = Val::ZERO;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Only one member of a group is reachable with Tab, the focused one or the first enabled one.
+pub fn update_button_group_tab_index(
+    focus: Res<InputFocus>,
+    q_group: Query<&Children, With<StyledButtonGroup>>,
+    mut q_member: Query<(&mut TabIndex, Has<InteractionDisabled>), With<StyledButton>>,
+) {
+    for children in q_group.iter() {
+        let members: Vec<Entity> = children
+            .iter()
+            .filter(|child| q_member.contains(*child))
+            .collect();
+        // Keep the current tab stop while focus is outside the group
+        let current = members.iter().copied().find(|member| {
+            q_member
+                .get(*member)
+                .is_ok_and(|(tab_index, _)| tab_index.0 == 0)
+        });
+
+        update_roving_tab_index(&members, &focus, current, &mut q_member);
+    }
+}
+
+// Move focus between group members with the arrow keys, Home and End.
+pub fn on_button_group_key_input(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    q_group: Query<(&StyledButtonGroup, &Children)>,
+    q_member: Query<Has<InteractionDisabled>, With<StyledButton>>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+) {
+    let Ok((group, children)) = q_group.get(trigger.target()) else {
+        return;
+    };
+    let event = &trigger.event().input;
+    if event.state != ButtonState::Pressed {
+        return;
+    }
+
+    let members: Vec<Entity> = children
+        .iter()
+        .filter(|child| q_member.contains(*child))
+        .collect();
+    if move_roving_focus(
+        event.key_code,
+        group.orientation == ButtonGroupOrientation::Vertical,
+        &members,
+        |member| q_member.get(member).is_ok_and(|disabled| !disabled),
+        &mut focus,
+        &mut focus_visible,
+    )
+    .is_some()
+    {
+        trigger.propagate(false);
+    }
+}
@@ -8,7 +8,7 @@ use super::{
     CheckboxSize,
     components::{AccessibleName, CheckboxVariant, StyledCheckbox},
 };
-use crate::themes::ThemeManager;
+use crate::{themes::ThemeManager, ui::label::StyledLabel};
 
 #[derive(Component, Default)]
 pub struct RootComponent;
@@ -16,9 +16,13 @@ pub struct RootComponent;
 #[derive(Component)]
 pub struct CheckboxCheckmarkText;
 
+/// Glyph drawn in place of the check mark while the checkbox is indeterminate.
+pub const INDETERMINATE_MARK: &str = "−";
+
 #[derive(Default)]
 pub struct CheckboxBuilder {
     pub checked: bool,
+    pub indeterminate: bool,
     pub check_mark: Option<String>,
     pub on_change: Option<SystemId<In<bool>>>,
     pub variant: CheckboxVariant,
@@ -26,6 +30,7 @@ pub struct CheckboxBuilder {
     pub size: Option<CheckboxSize>,
     pub caption: Option<String>,
     pub description: Option<String>,
+    pub value: String,
 }
 
 impl CheckboxBuilder {
@@ -34,6 +39,11 @@ impl CheckboxBuilder {
         self
     }
 
+    pub fn indeterminate(mut self) -> Self {
+        self.indeterminate = true;
+        self
+    }
+
     pub fn check_mark<S: Into<String>>(mut self, check_mark: S) -> Self {
         self.check_mark = Some(check_mark.into());
         self
@@ -69,6 +79,11 @@ impl CheckboxBuilder {
         self
     }
 
+    pub fn value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = value.into();
+        self
+    }
+
     pub fn build(self) -> impl Bundle {
         let theme_manager = ThemeManager::default();
 
@@ -88,9 +103,16 @@ impl CheckboxBuilder {
         };
 
         let is_checked = self.checked;
+        let is_indeterminate = self.indeterminate;
         let is_disabled = self.disabled;
 
-        let background = if is_disabled {
+        let background = if is_indeterminate {
+            if is_disabled {
+                style.disabled_indeterminate_background
+            } else {
+                style.indeterminate_background
+            }
+        } else if is_disabled {
             if is_checked {
                 style.disabled_checked_background
             } else {
@@ -120,12 +142,6 @@ impl CheckboxBuilder {
         let caption = self.caption.clone().unwrap_or_else(|| "".to_string());
         let description = self.description.clone().unwrap_or_else(|| "".to_string());
 
-        let cursor_icon = if self.disabled {
-            SystemCursorIcon::NotAllowed
-        } else {
-            SystemCursorIcon::Pointer
-        };
-
         let child_nodes = Children::spawn((
             // Checkbox box (left)
             Spawn((
@@ -141,7 +157,9 @@ impl CheckboxBuilder {
                 BorderRadius::all(Val::Px(checkbox_size_style.corner_radius)),
                 BackgroundColor(background),
                 Children::spawn((Spawn((
-                    Text::new(if is_checked {
+                    Text::new(if is_indeterminate {
+                        INDETERMINATE_MARK.to_string()
+                    } else if is_checked {
                         text.clone()
                     } else {
                         "".to_string()
@@ -170,6 +188,7 @@ impl CheckboxBuilder {
                             ..default()
                         },
                         TextColor(style.caption_color),
+                        StyledLabel::caption(),
                     )),
                     // Description (under caption)
                     Spawn((
@@ -179,6 +198,7 @@ impl CheckboxBuilder {
                             ..default()
                         },
                         TextColor(style.description_color),
+                        StyledLabel::description(),
                     )),
                 )),
             )),
@@ -195,22 +215,27 @@ impl CheckboxBuilder {
             },
             Name::new("Checkbox"),
             Hovering::default(),
-            CursorIcon::System(cursor_icon),
+            CursorIcon::System(SystemCursorIcon::Pointer),
             StyledCheckbox {
                 checked: self.checked,
+                indeterminate: self.indeterminate,
                 on_change: self.on_change,
                 check_mark: self.check_mark.clone(),
                 variant: self.variant,
                 disabled: self.disabled,
                 size: self.size,
+                caption: self.caption.clone(),
+                description: self.description.clone(),
+                value: self.value,
             },
+            // `on_change` is run by the styled observer, so the core widget triggers `ValueChange`
             CoreCheckbox {
-                on_change: self.on_change,
+                on_change: None,
             },
             Checked(self.checked),
             RootComponent,
             AccessibleName(
-                self.check_mark
+                self.caption
                     .clone()
                     .unwrap_or_else(|| "Checkbox".to_string()),
             ),
@@ -6,6 +6,9 @@ use bevy::prelude::*;
 #[reflect(from_reflect = false)]
 pub struct StyledCheckbox {
     pub checked: bool,
+    /// Mixed state, drawn with a dash and reported as mixed to assistive technology. Takes
+    /// precedence over `checked` and is cleared when the user toggles the checkbox.
+    pub indeterminate: bool,
     #[reflect(ignore)]
     pub on_change: Option<SystemId<In<bool>>>,
     pub check_mark: Option<String>,
@@ -14,12 +17,49 @@ pub struct StyledCheckbox {
     #[reflect(ignore)]
     pub size: Option<CheckboxSize>,
     pub disabled: bool,
+    pub caption: Option<String>,
+    pub description: Option<String>,
+    /// Identifies the checkbox in a checkbox group's selection.
+    pub value: String,
 }
 
 impl StyledCheckbox {
     pub fn builder() -> super::builder::CheckboxBuilder {
         super::builder::CheckboxBuilder::default()
     }
+
+    pub fn state(&self) -> CheckboxState {
+        if self.indeterminate {
+            CheckboxState::Indeterminate
+        } else if self.checked {
+            CheckboxState::Checked
+        } else {
+            CheckboxState::Unchecked
+        }
+    }
+
+    pub fn set_state(&mut self, state: CheckboxState) {
+        self.checked = state == CheckboxState::Checked;
+        self.indeterminate = state == CheckboxState::Indeterminate;
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckboxState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl From<bool> for CheckboxState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            CheckboxState::Checked
+        } else {
+            CheckboxState::Unchecked
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
@@ -1,4 +1,10 @@
-use super::systems::{on_checkbox_changed, setup_checkbox_fonts, update_checkbox_visuals};
+use super::{
+    StyledCheckbox,
+    systems::{
+        on_checkbox_changed, setup_checkbox_fonts, sync_checkbox_state, update_checkbox_visuals,
+    },
+};
+use crate::ui::disabled::sync_disabled;
 use bevy::prelude::*;
 
 pub struct StyledCheckboxPlugin;
@@ -6,6 +12,14 @@ impl Plugin for StyledCheckboxPlugin {
     fn build(&self, app: &mut App) {
         app.add_observer(on_checkbox_changed);
         app.add_systems(Update, setup_checkbox_fonts);
-        app.add_systems(Update, update_checkbox_visuals);
+        app.add_systems(
+            Update,
+            (
+                sync_checkbox_state,
+                sync_disabled::<StyledCheckbox>,
+                update_checkbox_visuals,
+            )
+                .chain(),
+        );
     }
 }
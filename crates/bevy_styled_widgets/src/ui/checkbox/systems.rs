@@ -1,12 +1,16 @@
-use bevy::prelude::*;
+use accesskit::Toggled;
+use bevy::{a11y::AccessibilityNode, prelude::*};
 use bevy_core_widgets::{Checked, InteractionDisabled, ValueChange, hover::Hovering};
 
 use super::{
-    CheckboxCheckmarkText, CheckboxSize,
+    CheckboxCheckmarkText, CheckboxSize, INDETERMINATE_MARK,
     builder::RootComponent,
-    components::{CheckboxVariant, StyledCheckbox},
+    components::{AccessibleName, CheckboxState, CheckboxVariant, StyledCheckbox},
+};
+use crate::{
+    themes::ThemeManager,
+    ui::{checkbox_group::StyledCheckboxGroup, events::WidgetChanged},
 };
-use crate::themes::ThemeManager;
 
 #[allow(clippy::type_complexity)]
 pub fn update_checkbox_visuals(
@@ -23,10 +27,16 @@ pub fn update_checkbox_visuals(
         (With<RootComponent>,),
     >,
     mut q_background_color: Query<
-        (&mut BackgroundColor, &mut BorderColor, &mut Children),
+        (
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &mut Children,
+            &mut Node,
+            &mut BorderRadius,
+        ),
         Without<RootComponent>,
     >,
-    mut q_text: Query<(&mut Text, &mut TextColor)>,
+    mut q_text: Query<(&mut Text, &mut TextColor, &mut TextFont)>,
 ) {
     for (
         mut checkbox_node,
@@ -50,15 +60,33 @@ pub fn update_checkbox_visuals(
             continue;
         };
 
-        let Ok((mut bg_color, mut border_color, main_children)) =
+        //Update size styles
+        let checkbox_size_style = match checkbox.size.unwrap_or_default() {
+            CheckboxSize::XSmall => checkbox_size_styles.xsmall,
+            CheckboxSize::Small => checkbox_size_styles.small,
+            CheckboxSize::Medium => checkbox_size_styles.medium,
+            CheckboxSize::Large => checkbox_size_styles.large,
+            CheckboxSize::XLarge => checkbox_size_styles.xlarge,
+        };
+
+        let Ok((mut bg_color, mut border_color, main_children, mut box_node, mut box_radius)) =
             q_background_color.get_mut(*checkbox_id)
         else {
             continue;
         };
 
+        box_node.width = Val::Px(checkbox_size_style.width);
+        box_node.height = Val::Px(checkbox_size_style.height);
+        box_node.border = UiRect::all(Val::Px(checkbox_size_style.border_width));
+        *box_radius = BorderRadius::all(Val::Px(checkbox_size_style.corner_radius));
+
         let check_mark_id = main_children[0];
-        if let Ok((mut text, mut text_color)) = q_text.get_mut(check_mark_id) {
-            if *checked {
+        if let Ok((mut text, mut text_color, mut text_font)) = q_text.get_mut(check_mark_id) {
+            text_font.font_size = checkbox_size_style.check_mark_font_size;
+
+            if checkbox.indeterminate {
+                **text = INDETERMINATE_MARK.into();
+            } else if *checked {
                 **text = checkbox.check_mark.clone().unwrap_or("✔".into());
             } else {
                 **text = "".into();
@@ -66,6 +94,21 @@ pub fn update_checkbox_visuals(
 
             let (new_bg, new_text_color, new_border_color) =
                 match (*checked, is_disabled, is_hovering) {
+                    _ if checkbox.indeterminate && is_disabled => (
+                        checkbox_style.disabled_indeterminate_background,
+                        checkbox_style.disabled_text_color,
+                        checkbox_style.disabled_border_color,
+                    ),
+                    _ if checkbox.indeterminate && *is_hovering => (
+                        checkbox_style.hovered_indeterminate_background,
+                        checkbox_style.indeterminate_text_color,
+                        checkbox_style.border_color,
+                    ),
+                    _ if checkbox.indeterminate => (
+                        checkbox_style.indeterminate_background,
+                        checkbox_style.indeterminate_text_color,
+                        checkbox_style.border_color,
+                    ),
                     (true, true, _) => (
                         checkbox_style.disabled_checked_background,
                         checkbox_style.disabled_text_color,
@@ -98,14 +141,6 @@ pub fn update_checkbox_visuals(
                     ),
                 };
 
-            //Update size styles
-            let checkbox_size_style = match checkbox.size.unwrap_or_default() {
-                CheckboxSize::XSmall => checkbox_size_styles.xsmall,
-                CheckboxSize::Small => checkbox_size_styles.small,
-                CheckboxSize::Medium => checkbox_size_styles.medium,
-                CheckboxSize::Large => checkbox_size_styles.large,
-                CheckboxSize::XLarge => checkbox_size_styles.xlarge,
-            };
             checkbox_node.padding = UiRect::axes(
                 Val::Px(checkbox_size_style.padding_horizontal),
                 Val::Px(checkbox_size_style.padding_vertical),
@@ -118,22 +153,32 @@ pub fn update_checkbox_visuals(
 
         if children.len() >= 2 {
             let caption_container_id = children[1];
-            if let Ok((_, _, caption_desc_children)) =
+            if let Ok((_, _, caption_desc_children, _, _)) =
                 q_background_color.get_mut(caption_container_id)
             {
                 if caption_desc_children.len() >= 2 {
                     // Caption
-                    if let Ok((_caption_text, mut caption_color)) =
+                    if let Ok((mut caption_text, mut caption_color, mut caption_font)) =
                         q_text.get_mut(caption_desc_children[0])
                     {
+                        let caption = checkbox.caption.clone().unwrap_or_default();
+                        if caption_text.0 != caption {
+                            caption_text.0 = caption;
+                        }
                         caption_color.0 = checkbox_style.caption_color;
+                        caption_font.font_size = checkbox_size_style.caption_font_size;
                     }
 
                     // Description
-                    if let Ok((_desc_text, mut desc_color)) =
+                    if let Ok((mut desc_text, mut desc_color, mut desc_font)) =
                         q_text.get_mut(caption_desc_children[1])
                     {
+                        let description = checkbox.description.clone().unwrap_or_default();
+                        if desc_text.0 != description {
+                            desc_text.0 = description;
+                        }
                         desc_color.0 = checkbox_style.description_color;
+                        desc_font.font_size = checkbox_size_style.description_font_size;
                     }
                 }
             }
@@ -141,26 +186,101 @@ pub fn update_checkbox_visuals(
     }
 }
 
+// Keep the core checkbox in sync with `StyledCheckbox`, which is the source of truth.
+// `Checked` set directly, e.g. from an `on_change` callback, is copied back and ends the
+// mixed state.
+#[allow(clippy::type_complexity)]
+pub fn sync_checkbox_state(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut StyledCheckbox,
+        Ref<Checked>,
+        &AccessibleName,
+        Option<&mut AccessibilityNode>,
+    )>,
+) {
+    for (entity, mut checkbox, checked, name, accessibility) in query.iter_mut() {
+        // The core checkbox only knows true and false, report the mixed state on top
+        if let Some(mut accessibility) = accessibility {
+            let toggled = if checkbox.indeterminate {
+                Toggled::Mixed
+            } else if checked.0 {
+                Toggled::True
+            } else {
+                Toggled::False
+            };
+            if accessibility.toggled() != Some(toggled) {
+                accessibility.set_toggled(toggled);
+            }
+        }
+
+        if !checkbox.is_changed() {
+            if checked.is_changed() && checked.0 != checkbox.checked {
+                let checkbox = checkbox.bypass_change_detection();
+                checkbox.checked = checked.0;
+                checkbox.indeterminate = false;
+            }
+            continue;
+        }
+
+        if checked.0 != checkbox.checked {
+            commands.entity(entity).insert(Checked(checkbox.checked));
+        }
+
+        let accessible_name = checkbox
+            .caption
+            .clone()
+            .unwrap_or_else(|| "Checkbox".to_string());
+        if name.0 != accessible_name {
+            commands
+                .entity(entity)
+                .insert(AccessibleName(accessible_name));
+        }
+    }
+}
+
+// Emit `WidgetChanged` for every change. With an `on_change` callback the widget is
+// controlled and the callback decides the new state, otherwise it is applied here.
+// An indeterminate checkbox always becomes checked. Checkboxes in a `StyledCheckboxGroup`
+// are left to the group, which may reject the change.
 pub fn on_checkbox_changed(
     mut trigger: Trigger<ValueChange<bool>>,
-    query: Query<&StyledCheckbox>,
+    mut query: Query<&mut StyledCheckbox>,
+    q_parent: Query<&ChildOf>,
+    q_checkbox_group: Query<(), With<StyledCheckboxGroup>>,
     mut commands: Commands,
 ) {
     trigger.propagate(false);
 
-    let checked = trigger.event().0;
     let entity = trigger.target();
+    if q_parent
+        .iter_ancestors(entity)
+        .any(|ancestor| q_checkbox_group.contains(ancestor))
+    {
+        return;
+    }
 
-    commands.entity(entity).insert(Checked(checked));
+    let Ok(mut styled_checkbox) = query.get_mut(entity) else {
+        return;
+    };
+    let checked = styled_checkbox.indeterminate || trigger.event().0;
 
-    if let Ok(styled_checkbox) = query.get(entity) {
-        if styled_checkbox.disabled {
-            commands.entity(entity).insert(InteractionDisabled);
-        }
-        if let Some(system_id) = styled_checkbox.on_change {
-            // Defer the callback system using commands
-            commands.run_system_with(system_id, checked);
-        }
+    commands.trigger_targets(
+        WidgetChanged {
+            entity,
+            old: styled_checkbox.state(),
+            new: CheckboxState::from(checked),
+        },
+        entity,
+    );
+
+    if let Some(system_id) = styled_checkbox.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, checked);
+    } else if styled_checkbox.indeterminate || styled_checkbox.checked != checked {
+        styled_checkbox.checked = checked;
+        styled_checkbox.indeterminate = false;
     }
 }
 
@@ -0,0 +1,131 @@
+use accesskit::Role;
+use bevy::{
+    a11y::AccessibilityNode,
+    ecs::{
+        spawn::{SpawnIter, SpawnWith},
+        system::SystemId,
+    },
+    prelude::*,
+};
+
+use super::components::{CheckboxGroupSelectAll, StyledCheckboxGroup};
+use crate::ui::{checkbox::CheckboxBuilder, disabled::Fieldset};
+
+#[derive(Default)]
+pub struct CheckboxGroupBuilder {
+    checkboxes: Vec<CheckboxBuilder>,
+    select_all: Option<String>,
+    min: Option<usize>,
+    max: Option<usize>,
+    disabled: bool,
+    label: Option<String>,
+    on_change: Option<SystemId<In<Vec<String>>>>,
+}
+
+impl CheckboxGroupBuilder {
+    pub fn checkbox(mut self, checkbox: CheckboxBuilder) -> Self {
+        self.checkboxes.push(checkbox);
+        self
+    }
+
+    pub fn checkboxes(mut self, checkboxes: impl IntoIterator<Item = CheckboxBuilder>) -> Self {
+        self.checkboxes.extend(checkboxes);
+        self
+    }
+
+    /// Adds a parent checkbox above the group that selects or clears every checkbox.
+    pub fn select_all<S: Into<String>>(mut self, caption: S) -> Self {
+        self.select_all = Some(caption.into());
+        self
+    }
+
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn on_change(mut self, system_id: SystemId<In<Vec<String>>>) -> Self {
+        self.on_change = Some(system_id);
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let mut accessible = accesskit::Node::new(Role::Group);
+        if let Some(label) = &self.label {
+            accessible.set_label(label.as_str());
+        }
+
+        let selected = self
+            .checkboxes
+            .iter()
+            .filter(|checkbox| checkbox.checked)
+            .map(|checkbox| checkbox.value.clone())
+            .collect();
+        let checkboxes: Vec<_> = self
+            .checkboxes
+            .into_iter()
+            .map(|checkbox| checkbox.build())
+            .collect();
+        let select_all = self.select_all;
+
+        (
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Start,
+                ..default()
+            },
+            Name::new("CheckboxGroup"),
+            AccessibilityNode(accessible),
+            StyledCheckboxGroup {
+                selected,
+                min: self.min,
+                max: self.max,
+                disabled: self.disabled,
+                error: None,
+                on_change: self.on_change,
+            },
+            Fieldset {
+                disabled: self.disabled,
+            },
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                let Some(caption) = select_all else {
+                    for checkbox in checkboxes {
+                        parent.spawn(checkbox);
+                    }
+                    return;
+                };
+                parent.spawn((
+                    CheckboxBuilder::default().caption(caption).build(),
+                    CheckboxGroupSelectAll,
+                ));
+                // Checkboxes are indented below the select-all checkbox
+                parent.spawn((
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Start,
+                        padding: UiRect::left(Val::Px(24.0)),
+                        ..default()
+                    },
+                    Children::spawn(SpawnIter(checkboxes.into_iter())),
+                ));
+            })),
+        )
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+use bevy::{ecs::system::SystemId, prelude::*};
+
+#[derive(Component, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct StyledCheckboxGroup {
+    /// Values of the checked checkboxes, in hierarchy order.
+    pub selected: Vec<String>,
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+    pub disabled: bool,
+    /// Set when the last change was rejected by `min` or `max`, cleared by the next accepted one.
+    #[reflect(ignore)]
+    pub error: Option<CheckboxGroupError>,
+    #[reflect(ignore)]
+    pub on_change: Option<SystemId<In<Vec<String>>>>,
+}
+
+impl StyledCheckboxGroup {
+    pub fn builder() -> super::builder::CheckboxGroupBuilder {
+        super::builder::CheckboxGroupBuilder::default()
+    }
+
+    /// Checks a selection of `count` checkboxes against `min` and `max`.
+    pub fn validate(&self, count: usize) -> Result<(), CheckboxGroupError> {
+        if let Some(min) = self.min.filter(|min| count < *min) {
+            return Err(CheckboxGroupError::TooFew { min });
+        }
+        if let Some(max) = self.max.filter(|max| count > *max) {
+            return Err(CheckboxGroupError::TooMany { max });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckboxGroupError {
+    TooFew { min: usize },
+    TooMany { max: usize },
+}
+
+impl fmt::Display for CheckboxGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckboxGroupError::TooFew { min } => write!(f, "Select at least {min}"),
+            CheckboxGroupError::TooMany { max } => write!(f, "Select at most {max}"),
+        }
+    }
+}
+
+/// Marks the checkbox that selects or clears every checkbox of its group. It is
+/// indeterminate while only some of them are checked.
+#[derive(Component, Default)]
+pub struct CheckboxGroupSelectAll;
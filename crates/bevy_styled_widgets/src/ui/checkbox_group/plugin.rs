@@ -0,0 +1,14 @@
+use super::systems::{on_checkbox_group_member_changed, sync_checkbox_group_state};
+use crate::ui::checkbox::sync_checkbox_state;
+use bevy::prelude::*;
+
+pub struct StyledCheckboxGroupPlugin;
+impl Plugin for StyledCheckboxGroupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_checkbox_group_member_changed);
+        app.add_systems(
+            Update,
+            sync_checkbox_group_state.before(sync_checkbox_state),
+        );
+    }
+}
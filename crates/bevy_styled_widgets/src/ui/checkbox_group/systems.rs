@@ -0,0 +1,224 @@
+use bevy::prelude::*;
+use bevy_core_widgets::{InteractionDisabled, ValueChange};
+
+use super::components::{CheckboxGroupSelectAll, StyledCheckboxGroup};
+use crate::ui::{
+    checkbox::{CheckboxState, StyledCheckbox},
+    disabled::Fieldset,
+    events::WidgetChanged,
+};
+
+// Checkboxes belonging to `group`, at any depth below it but not inside a nested group.
+// The select-all checkbox is not a member.
+pub fn checkbox_group_members(
+    group: Entity,
+    q_children: &Query<&Children>,
+    q_parent: &Query<&ChildOf>,
+    q_checkbox_group: &Query<(), With<StyledCheckboxGroup>>,
+    is_member: impl Fn(Entity) -> bool,
+) -> Vec<Entity> {
+    q_children
+        .iter_descendants(group)
+        .filter(|descendant| is_member(*descendant))
+        .filter(|checkbox| {
+            q_parent
+                .iter_ancestors(*checkbox)
+                .find(|ancestor| q_checkbox_group.contains(*ancestor))
+                == Some(group)
+        })
+        .collect()
+}
+
+// Apply a user change of a member or the select-all checkbox, checked against `min` and
+// `max` first. `on_checkbox_changed` leaves grouped checkboxes to this observer, so a rejected
+// change only sets the group's error and neither the checkbox nor its listeners see it. An
+// accepted change triggers `WidgetChanged` on the checkbox, then on the group with the selected
+// values. With an `on_change` callback the group is controlled and the callback decides the
+// selection, otherwise it is applied here.
+#[allow(clippy::type_complexity)]
+pub fn on_checkbox_group_member_changed(
+    trigger: Trigger<ValueChange<bool>>,
+    mut q_group: Query<&mut StyledCheckboxGroup>,
+    q_checkbox: Query<
+        (
+            &StyledCheckbox,
+            Has<CheckboxGroupSelectAll>,
+            Has<InteractionDisabled>,
+        ),
+        Without<StyledCheckboxGroup>,
+    >,
+    q_children: Query<&Children>,
+    q_parent: Query<&ChildOf>,
+    q_checkbox_group: Query<(), With<StyledCheckboxGroup>>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((checkbox, is_select_all, _)) = q_checkbox.get(entity) else {
+        return;
+    };
+    let Some(group_entity) = q_parent
+        .iter_ancestors(entity)
+        .find(|ancestor| q_checkbox_group.contains(*ancestor))
+    else {
+        return;
+    };
+    let Ok(mut group) = q_group.get_mut(group_entity) else {
+        return;
+    };
+    // An indeterminate checkbox always becomes checked
+    let checked = checkbox.indeterminate || trigger.event().0;
+
+    let members = checkbox_group_members(
+        group_entity,
+        &q_children,
+        &q_parent,
+        &q_checkbox_group,
+        |entity| {
+            q_checkbox
+                .get(entity)
+                .is_ok_and(|(_, select_all, _)| !select_all)
+        },
+    );
+
+    // The proposed checked state of every member; disabled members keep theirs
+    let new: Vec<String> = members
+        .iter()
+        .filter_map(|member| q_checkbox.get(*member).ok().map(|item| (*member, item)))
+        .filter(|(member, (member_checkbox, _, is_disabled))| {
+            if *member == entity || (is_select_all && !is_disabled) {
+                checked
+            } else {
+                member_checkbox.checked
+            }
+        })
+        .map(|(_, (member_checkbox, _, _))| member_checkbox.value.clone())
+        .collect();
+
+    if let Err(error) = group.validate(new.len()) {
+        group.error = Some(error);
+        return;
+    }
+    if group.error.is_some() {
+        group.error = None;
+    }
+
+    commands.trigger_targets(
+        WidgetChanged {
+            entity,
+            old: checkbox.state(),
+            new: CheckboxState::from(checked),
+        },
+        entity,
+    );
+    if let Some(system_id) = checkbox.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, checked);
+    }
+
+    commands.trigger_targets(
+        WidgetChanged {
+            entity: group_entity,
+            old: group.selected.clone(),
+            new: new.clone(),
+        },
+        group_entity,
+    );
+
+    if let Some(system_id) = group.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, new);
+    } else if group.selected != new {
+        group.selected = new;
+    }
+}
+
+// Check the members listed in a changed `selected`, or copy the checked members back into
+// `selected` when they changed. Keeps the select-all checkbox checked, unchecked or
+// indeterminate to match the enabled members, which are the ones it can change, and mirrors
+// `disabled` to the group's `Fieldset`.
+#[allow(clippy::type_complexity)]
+pub fn sync_checkbox_group_state(
+    mut q_group: Query<(Entity, &mut StyledCheckboxGroup, &mut Fieldset)>,
+    mut q_checkbox: Query<
+        (
+            &mut StyledCheckbox,
+            Has<CheckboxGroupSelectAll>,
+            Has<InteractionDisabled>,
+        ),
+        Without<StyledCheckboxGroup>,
+    >,
+    q_children: Query<&Children>,
+    q_parent: Query<&ChildOf>,
+    q_checkbox_group: Query<(), With<StyledCheckboxGroup>>,
+) {
+    for (entity, mut group, mut fieldset) in q_group.iter_mut() {
+        if fieldset.disabled != group.disabled {
+            fieldset.disabled = group.disabled;
+        }
+
+        let members = checkbox_group_members(
+            entity,
+            &q_children,
+            &q_parent,
+            &q_checkbox_group,
+            |entity| {
+                q_checkbox
+                    .get(entity)
+                    .is_ok_and(|(_, select_all, _)| !select_all)
+            },
+        );
+
+        if group.is_changed() {
+            for member in members.iter() {
+                if let Ok((mut checkbox, _, _)) = q_checkbox.get_mut(*member) {
+                    let is_selected = group.selected.contains(&checkbox.value);
+                    if checkbox.checked != is_selected || checkbox.indeterminate {
+                        checkbox.checked = is_selected;
+                        checkbox.indeterminate = false;
+                    }
+                }
+            }
+        } else if members.iter().any(|member| {
+            q_checkbox
+                .get_mut(*member)
+                .is_ok_and(|(checkbox, _, _)| checkbox.is_changed())
+        }) {
+            let selected: Vec<String> = members
+                .iter()
+                .filter_map(|member| q_checkbox.get(*member).ok())
+                .filter(|(checkbox, _, _)| checkbox.checked)
+                .map(|(checkbox, _, _)| checkbox.value.clone())
+                .collect();
+            if group.selected != selected {
+                group.bypass_change_detection().selected = selected;
+            }
+        }
+
+        let enabled: Vec<bool> = members
+            .iter()
+            .filter_map(|member| q_checkbox.get(*member).ok())
+            .filter(|(_, _, is_disabled)| !is_disabled)
+            .map(|(checkbox, _, _)| group.selected.contains(&checkbox.value))
+            .collect();
+        let state = match enabled.iter().filter(|is_selected| **is_selected).count() {
+            0 => CheckboxState::Unchecked,
+            count if count >= enabled.len() => CheckboxState::Checked,
+            _ => CheckboxState::Indeterminate,
+        };
+        let select_all = q_children.iter_descendants(entity).find(|descendant| {
+            q_checkbox
+                .get(*descendant)
+                .is_ok_and(|(_, select_all, _)| select_all)
+                && q_parent
+                    .iter_ancestors(*descendant)
+                    .find(|ancestor| q_checkbox_group.contains(*ancestor))
+                    == Some(entity)
+        });
+        if let Some(Ok((mut checkbox, _, _))) = select_all.map(|entity| q_checkbox.get_mut(entity))
+        {
+            if checkbox.state() != state {
+                checkbox.set_state(state);
+            }
+        }
+    }
+}
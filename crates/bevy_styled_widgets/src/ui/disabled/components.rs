@@ -0,0 +1,17 @@
+use bevy::{prelude::*, winit::cursor::CursorIcon};
+
+/// A widget with its own `disabled` flag.
+pub trait DisableableWidget: Component {
+    fn disabled(&self) -> bool;
+}
+
+/// Disables every widget below this entity while `disabled` is set, regardless of
+/// the widgets' own flags.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+pub struct Fieldset {
+    pub disabled: bool,
+}
+
+/// The cursor a widget had before it was disabled, restored once it is enabled again.
+#[derive(Component, Debug, Clone)]
+pub struct EnabledCursor(pub CursorIcon);
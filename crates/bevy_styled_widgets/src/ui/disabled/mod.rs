@@ -0,0 +1,6 @@
+mod components;
+mod systems;
+mod widgets;
+
+pub use components::*;
+pub use systems::*;
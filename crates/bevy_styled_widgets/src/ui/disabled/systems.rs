@@ -0,0 +1,67 @@
+use bevy::{
+    a11y::AccessibilityNode, prelude::*, window::SystemCursorIcon, winit::cursor::CursorIcon,
+};
+use bevy_core_widgets::InteractionDisabled;
+
+use super::components::{DisableableWidget, EnabledCursor, Fieldset};
+
+// A widget is disabled by its own flag or by a disabled `Fieldset` above it. The result is
+// kept in `InteractionDisabled`, which the core widgets and the visuals systems read, and
+// mirrored to the accessibility node. Disabled widgets show the not-allowed cursor, the
+// cursor they had before is kept in `EnabledCursor` and restored when they are enabled.
+#[allow(clippy::type_complexity)]
+pub fn sync_disabled<W: DisableableWidget>(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &W,
+        Option<&mut CursorIcon>,
+        Option<&EnabledCursor>,
+        Option<&mut AccessibilityNode>,
+        Has<InteractionDisabled>,
+    )>,
+    q_parent: Query<&ChildOf>,
+    q_fieldset: Query<&Fieldset>,
+) {
+    for (entity, widget, cursor, enabled_cursor, accessibility, is_disabled) in query.iter_mut() {
+        let disabled = widget.disabled()
+            || q_parent.iter_ancestors(entity).any(|ancestor| {
+                q_fieldset
+                    .get(ancestor)
+                    .is_ok_and(|fieldset| fieldset.disabled)
+            });
+
+        if disabled && !is_disabled {
+            commands.entity(entity).insert(InteractionDisabled);
+        } else if !disabled && is_disabled {
+            commands.entity(entity).remove::<InteractionDisabled>();
+        }
+
+        if let Some(mut cursor) = cursor {
+            let not_allowed = CursorIcon::System(SystemCursorIcon::NotAllowed);
+            match (disabled, enabled_cursor) {
+                (true, None) if *cursor != not_allowed => {
+                    commands
+                        .entity(entity)
+                        .insert(EnabledCursor(cursor.clone()));
+                    *cursor = not_allowed;
+                }
+                (false, Some(enabled_cursor)) => {
+                    *cursor = enabled_cursor.0.clone();
+                    commands.entity(entity).remove::<EnabledCursor>();
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(mut accessibility) = accessibility {
+            if accessibility.is_disabled() != disabled {
+                if disabled {
+                    accessibility.set_disabled();
+                } else {
+                    accessibility.clear_disabled();
+                }
+            }
+        }
+    }
+}
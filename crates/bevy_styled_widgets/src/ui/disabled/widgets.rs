@@ -0,0 +1,54 @@
+use super::components::DisableableWidget;
+use crate::ui::{
+    button::StyledButton, checkbox::StyledCheckbox, input::StyledInput,
+    radio_group::StyledRadioButton, range_slider::StyledRangeSlider, slider::StyledSlider,
+    switch::StyledSwitch, toggle::StyledToggle,
+};
+
+impl DisableableWidget for StyledButton {
+    fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl DisableableWidget for StyledCheckbox {
+    fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl DisableableWidget for StyledSwitch {
+    fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl DisableableWidget for StyledToggle {
+    fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl DisableableWidget for StyledRadioButton {
+    fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl DisableableWidget for StyledSlider {
+    fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl DisableableWidget for StyledRangeSlider {
+    fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl DisableableWidget for StyledInput {
+    fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
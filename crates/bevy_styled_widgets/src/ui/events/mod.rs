@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+/// Triggered on a widget when the user changes its value, whether or not an
+/// `on_change` callback is set. The event bubbles up the hierarchy, so a parent
+/// can observe every widget of a form.
+#[derive(Clone, Debug)]
+pub struct WidgetChanged<T> {
+    pub entity: Entity,
+    pub old: T,
+    pub new: T,
+}
+
+impl<T: Send + Sync + 'static> Event for WidgetChanged<T> {
+    type Traversal = &'static ChildOf;
+    const AUTO_PROPAGATE: bool = true;
+}
+
+/// Triggered on a selection group, e.g. a radio group, when the user selects an item.
+/// `old` is `None` when nothing was selected before.
+#[derive(Clone, Debug)]
+pub struct WidgetSelected<T> {
+    pub entity: Entity,
+    pub selected: Entity,
+    pub old: Option<T>,
+    pub new: T,
+}
+
+impl<T: Send + Sync + 'static> Event for WidgetSelected<T> {
+    type Traversal = &'static ChildOf;
+    const AUTO_PROPAGATE: bool = true;
+}
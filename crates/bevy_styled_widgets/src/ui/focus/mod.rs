@@ -0,0 +1,3 @@
+mod roving;
+
+pub use roving::*;
@@ -0,0 +1,80 @@
+use bevy::{
+    ecs::query::QueryFilter,
+    input_focus::{InputFocus, InputFocusVisible, tab_navigation::TabIndex},
+    prelude::*,
+};
+use bevy_core_widgets::InteractionDisabled;
+
+// Helpers for groups whose members share a single tab stop and move focus between each
+// other with the arrow keys, Home and End.
+
+// Only one member of a group is reachable with Tab: the focused one, else `preferred`, else
+// the first enabled one. The others get a tab index of -1.
+pub fn update_roving_tab_index<F: QueryFilter>(
+    members: &[Entity],
+    focus: &InputFocus,
+    preferred: Option<Entity>,
+    q_member: &mut Query<(&mut TabIndex, Has<InteractionDisabled>), F>,
+) {
+    let is_enabled = |member: Entity| q_member.get(member).is_ok_and(|(_, disabled)| !disabled);
+
+    let focused = focus.0.filter(|entity| members.contains(entity));
+    let preferred = preferred.filter(|member| is_enabled(*member));
+    let first_enabled = members.iter().copied().find(|member| is_enabled(*member));
+
+    let Some(active) = focused.or(preferred).or(first_enabled) else {
+        return;
+    };
+
+    for member in members.iter() {
+        if let Ok((mut tab_index, _)) = q_member.get_mut(*member) {
+            let index = if *member == active { 0 } else { -1 };
+            if tab_index.0 != index {
+                tab_index.0 = index;
+            }
+        }
+    }
+}
+
+// Move focus to the next or previous enabled member with the arrow keys of the group's axis,
+// wrapping around, or to the first or last one with Home and End. Only keys pressed on a
+// member are handled, the newly focused member is returned.
+pub fn move_roving_focus(
+    key_code: KeyCode,
+    vertical: bool,
+    members: &[Entity],
+    is_enabled: impl Fn(Entity) -> bool,
+    focus: &mut InputFocus,
+    focus_visible: &mut InputFocusVisible,
+) -> Option<Entity> {
+    let (previous_key, next_key) = if vertical {
+        (KeyCode::ArrowUp, KeyCode::ArrowDown)
+    } else {
+        (KeyCode::ArrowLeft, KeyCode::ArrowRight)
+    };
+
+    let focused = focus.0.filter(|entity| members.contains(entity))?;
+    let enabled: Vec<Entity> = members
+        .iter()
+        .copied()
+        .filter(|member| is_enabled(*member))
+        .collect();
+    if enabled.is_empty() {
+        return None;
+    }
+    let current = enabled.iter().position(|member| *member == focused);
+
+    let next = match key_code {
+        key if key == next_key => current.map_or(0, |index| (index + 1) % enabled.len()),
+        key if key == previous_key => current.map_or(enabled.len() - 1, |index| {
+            (index + enabled.len() - 1) % enabled.len()
+        }),
+        KeyCode::Home => 0,
+        KeyCode::End => enabled.len() - 1,
+        _ => return None,
+    };
+
+    focus.0 = Some(enabled[next]);
+    focus_visible.0 = true;
+    Some(enabled[next])
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use crate::themes::focus_ring::{FocusRingSizeProperties, FocusRingSizeStyles};
+
+/// A widget that draws a focus ring when it receives keyboard focus.
+pub trait FocusableWidget: Component {
+    fn focus_ring_size(&self, sizes: &FocusRingSizeStyles) -> FocusRingSizeProperties;
+}
+
+/// The outline a widget had before its focus ring was drawn, restored when the ring is hidden.
+/// `None` when the widget had no outline.
+#[derive(Component, Debug, Clone)]
+pub struct OutlineBeforeFocus(pub Option<Outline>);
@@ -0,0 +1,8 @@
+mod components;
+mod plugin;
+mod systems;
+mod widgets;
+
+pub use components::*;
+pub use plugin::StyledFocusRingPlugin;
+pub use systems::*;
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+use super::systems::update_focus_ring;
+use crate::ui::{
+    button::StyledButton, checkbox::StyledCheckbox, input::StyledInput,
+    radio_group::StyledRadioButton, range_slider::RangeSliderThumb, slider::StyledSlider,
+    switch::StyledSwitch, toggle::StyledToggle,
+};
+
+pub struct StyledFocusRingPlugin;
+impl Plugin for StyledFocusRingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                update_focus_ring::<StyledButton>,
+                update_focus_ring::<StyledCheckbox>,
+                update_focus_ring::<StyledSwitch>,
+                update_focus_ring::<StyledToggle>,
+                update_focus_ring::<StyledRadioButton>,
+                update_focus_ring::<StyledSlider>,
+                update_focus_ring::<RangeSliderThumb>,
+                update_focus_ring::<StyledInput>,
+            ),
+        );
+    }
+}
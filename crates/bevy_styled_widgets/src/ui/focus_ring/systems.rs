@@ -0,0 +1,70 @@
+use bevy::{
+    input_focus::{InputFocus, InputFocusVisible},
+    prelude::*,
+};
+
+use super::components::{FocusableWidget, OutlineBeforeFocus};
+use crate::themes::ThemeManager;
+
+// Show the focus ring on the focused widget while focus is visible, i.e. it was moved with
+// the keyboard. The widget's own outline is kept in `OutlineBeforeFocus` while the ring is
+// drawn and put back once the widget loses focus, outlines of unfocused widgets are left alone.
+#[allow(clippy::type_complexity)]
+pub fn update_focus_ring<W: FocusableWidget>(
+    mut commands: Commands,
+    theme_manager: Res<ThemeManager>,
+    focus: Res<InputFocus>,
+    focus_visible: Res<InputFocusVisible>,
+    mut query: Query<(
+        Entity,
+        &W,
+        Option<&mut Outline>,
+        Option<&OutlineBeforeFocus>,
+    )>,
+) {
+    let ring_color = theme_manager.styles.focus_ring.color;
+
+    for (entity, widget, outline, before_focus) in query.iter_mut() {
+        let is_focused = focus_visible.0 && focus.0 == Some(entity);
+
+        if !is_focused {
+            // Restore the outline the ring replaced
+            if let Some(before_focus) = before_focus {
+                match (&before_focus.0, outline) {
+                    (Some(original), Some(mut outline)) => *outline = *original,
+                    (Some(original), None) => {
+                        commands.entity(entity).insert(*original);
+                    }
+                    (None, _) => {
+                        commands.entity(entity).remove::<Outline>();
+                    }
+                }
+                commands.entity(entity).remove::<OutlineBeforeFocus>();
+            }
+            continue;
+        }
+
+        let ring_size = widget.focus_ring_size(&theme_manager.styles.focus_ring_sizes);
+        let ring = Outline::new(
+            Val::Px(ring_size.width),
+            Val::Px(ring_size.offset),
+            ring_color,
+        );
+
+        if before_focus.is_none() {
+            commands
+                .entity(entity)
+                .insert(OutlineBeforeFocus(outline.as_deref().copied()));
+        }
+        match outline {
+            Some(mut outline) => {
+                if *outline != ring {
+                    *outline = ring;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(ring);
+            }
+        }
+    }
+}
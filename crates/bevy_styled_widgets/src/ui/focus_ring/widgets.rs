@@ -0,0 +1,105 @@
+use super::components::FocusableWidget;
+use crate::{
+    themes::focus_ring::{FocusRingSizeProperties, FocusRingSizeStyles},
+    ui::{
+        button::{ButtonSize, StyledButton},
+        checkbox::{CheckboxSize, StyledCheckbox},
+        input::StyledInput,
+        radio_group::{RadioButtonSize, StyledRadioButton},
+        range_slider::RangeSliderThumb,
+        slider::{SliderSize, StyledSlider},
+        switch::{StyledSwitch, SwitchSize},
+        toggle::{StyledToggle, ToggleSize},
+    },
+};
+
+impl FocusableWidget for StyledButton {
+    fn focus_ring_size(&self, sizes: &FocusRingSizeStyles) -> FocusRingSizeProperties {
+        match self.size.unwrap_or_default() {
+            ButtonSize::XSmall => sizes.xsmall,
+            ButtonSize::Small => sizes.small,
+            ButtonSize::Medium => sizes.medium,
+            ButtonSize::Large => sizes.large,
+            ButtonSize::XLarge => sizes.xlarge,
+        }
+    }
+}
+
+impl FocusableWidget for StyledCheckbox {
+    fn focus_ring_size(&self, sizes: &FocusRingSizeStyles) -> FocusRingSizeProperties {
+        match self.size.unwrap_or_default() {
+            CheckboxSize::XSmall => sizes.xsmall,
+            CheckboxSize::Small => sizes.small,
+            CheckboxSize::Medium => sizes.medium,
+            CheckboxSize::Large => sizes.large,
+            CheckboxSize::XLarge => sizes.xlarge,
+        }
+    }
+}
+
+impl FocusableWidget for StyledSwitch {
+    fn focus_ring_size(&self, sizes: &FocusRingSizeStyles) -> FocusRingSizeProperties {
+        match self.size.unwrap_or_default() {
+            SwitchSize::XSmall => sizes.xsmall,
+            SwitchSize::Small => sizes.small,
+            SwitchSize::Medium => sizes.medium,
+            SwitchSize::Large => sizes.large,
+            SwitchSize::XLarge => sizes.xlarge,
+        }
+    }
+}
+
+impl FocusableWidget for StyledToggle {
+    fn focus_ring_size(&self, sizes: &FocusRingSizeStyles) -> FocusRingSizeProperties {
+        match self.size.unwrap_or_default() {
+            ToggleSize::XSmall => sizes.xsmall,
+            ToggleSize::Small => sizes.small,
+            ToggleSize::Medium => sizes.medium,
+            ToggleSize::Large => sizes.large,
+            ToggleSize::XLarge => sizes.xlarge,
+        }
+    }
+}
+
+impl FocusableWidget for StyledRadioButton {
+    fn focus_ring_size(&self, sizes: &FocusRingSizeStyles) -> FocusRingSizeProperties {
+        match self.size.unwrap_or_default() {
+            RadioButtonSize::XSmall => sizes.xsmall,
+            RadioButtonSize::Small => sizes.small,
+            RadioButtonSize::Medium => sizes.medium,
+            RadioButtonSize::Large => sizes.large,
+            RadioButtonSize::XLarge => sizes.xlarge,
+        }
+    }
+}
+
+impl FocusableWidget for StyledSlider {
+    fn focus_ring_size(&self, sizes: &FocusRingSizeStyles) -> FocusRingSizeProperties {
+        match self.size.unwrap_or_default() {
+            SliderSize::XSmall => sizes.xsmall,
+            SliderSize::Small => sizes.small,
+            SliderSize::Medium => sizes.medium,
+            SliderSize::Large => sizes.large,
+            SliderSize::XLarge => sizes.xlarge,
+        }
+    }
+}
+
+impl FocusableWidget for RangeSliderThumb {
+    fn focus_ring_size(&self, sizes: &FocusRingSizeStyles) -> FocusRingSizeProperties {
+        match self.size.unwrap_or_default() {
+            SliderSize::XSmall => sizes.xsmall,
+            SliderSize::Small => sizes.small,
+            SliderSize::Medium => sizes.medium,
+            SliderSize::Large => sizes.large,
+            SliderSize::XLarge => sizes.xlarge,
+        }
+    }
+}
+
+impl FocusableWidget for StyledInput {
+    // Inputs have a single size
+    fn focus_ring_size(&self, sizes: &FocusRingSizeStyles) -> FocusRingSizeProperties {
+        sizes.medium
+    }
+}
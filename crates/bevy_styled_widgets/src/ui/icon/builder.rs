@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+use super::components::{IconId, StyledIcon};
+
+/// Icon size used when neither the builder nor the owning widget sets one.
+pub const DEFAULT_ICON_SIZE: f32 = 16.0;
+
+#[derive(Default)]
+pub struct IconBuilder {
+    icon: IconId,
+    size: Option<f32>,
+    color: Option<Color>,
+}
+
+impl IconBuilder {
+    pub fn icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let size = self.size.unwrap_or(DEFAULT_ICON_SIZE);
+        (
+            Node {
+                width: Val::Px(size),
+                height: Val::Px(size),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Name::new(format!("Icon: {}", self.icon.0)),
+            StyledIcon {
+                icon: self.icon,
+                size: self.size,
+                color: self.color,
+            },
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+/// Name of an icon, resolved through the [`IconRegistry`](super::IconRegistry).
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Reflect)]
+pub struct IconId(pub String);
+
+impl From<&str> for IconId {
+    fn from(name: &str) -> Self {
+        IconId(name.to_string())
+    }
+}
+
+impl From<String> for IconId {
+    fn from(name: String) -> Self {
+        IconId(name)
+    }
+}
+
+#[derive(Component, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct StyledIcon {
+    pub icon: IconId,
+    pub size: Option<f32>,
+    pub color: Option<Color>,
+}
+
+impl StyledIcon {
+    pub fn builder() -> super::builder::IconBuilder {
+        super::builder::IconBuilder::default()
+    }
+
+    pub fn new<I: Into<IconId>>(icon: I) -> impl Bundle {
+        Self::builder().icon(icon).build()
+    }
+}
@@ -0,0 +1,11 @@
+mod builder;
+mod components;
+mod plugin;
+mod registry;
+mod systems;
+
+pub use builder::*;
+pub use components::*;
+pub use plugin::StyledIconPlugin;
+pub use registry::*;
+pub use systems::*;
@@ -0,0 +1,19 @@
+use super::{
+    registry::{DEFAULT_ICON_FONT, IconRegistry},
+    systems::update_icons,
+};
+use bevy::{asset::load_internal_binary_asset, prelude::*};
+
+pub struct StyledIconPlugin;
+impl Plugin for StyledIconPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_binary_asset!(
+            app,
+            DEFAULT_ICON_FONT,
+            "font-icons.ttf",
+            |bytes: &[u8], _path: String| { Font::try_from_bytes(bytes.to_vec()).unwrap() }
+        );
+        app.init_resource::<IconRegistry>();
+        app.add_systems(Update, update_icons);
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use bevy::{asset::weak_handle, prelude::*};
+
+use super::IconId;
+
+/// The icon font bundled with the crate, used for theme glyphs until a default font is set.
+pub const DEFAULT_ICON_FONT: Handle<Font> = weak_handle!("5c3e8a9f-2b1d-4f6e-9a7c-0d4b8e1f3a62");
+
+/// Where an icon is drawn from.
+#[derive(Debug, Clone)]
+pub enum IconSource {
+    /// A glyph in an icon font, e.g. `fonts/font-icons.ttf`.
+    Glyph { font: Handle<Font>, glyph: String },
+    /// An image handle. SVG icons are expected to be rasterised by their asset loader.
+    Image(Handle<Image>),
+}
+
+/// Maps icon names to glyphs or images.
+///
+/// Names that are not registered explicitly fall back to the current theme's `icons` map,
+/// drawn with the default icon font, or with [`DEFAULT_ICON_FONT`] when none is set.
+#[derive(Resource, Default)]
+pub struct IconRegistry {
+    default_font: Option<Handle<Font>>,
+    icons: HashMap<IconId, IconSource>,
+}
+
+impl IconRegistry {
+    /// Sets the font used for glyphs coming from the theme's `icons` map.
+    pub fn set_default_font(&mut self, font: Handle<Font>) {
+        self.default_font = Some(font);
+    }
+
+    pub fn default_font(&self) -> &Handle<Font> {
+        self.default_font.as_ref().unwrap_or(&DEFAULT_ICON_FONT)
+    }
+
+    pub fn register_glyph<I: Into<IconId>, S: Into<String>>(
+        &mut self,
+        icon: I,
+        font: Handle<Font>,
+        glyph: S,
+    ) {
+        self.icons.insert(
+            icon.into(),
+            IconSource::Glyph {
+                font,
+                glyph: glyph.into(),
+            },
+        );
+    }
+
+    pub fn register_image<I: Into<IconId>>(&mut self, icon: I, image: Handle<Image>) {
+        self.icons.insert(icon.into(), IconSource::Image(image));
+    }
+
+    pub fn remove(&mut self, icon: &IconId) -> Option<IconSource> {
+        self.icons.remove(icon)
+    }
+
+    pub fn get(&self, icon: &IconId) -> Option<&IconSource> {
+        self.icons.get(icon)
+    }
+
+    /// Resolves an icon, checking registered icons first and then the theme's glyphs.
+    pub fn resolve(
+        &self,
+        icon: &IconId,
+        theme_icons: &HashMap<String, String>,
+    ) -> Option<IconSource> {
+        if let Some(source) = self.icons.get(icon) {
+            return Some(source.clone());
+        }
+
+        theme_icons.get(&icon.0).map(|glyph| IconSource::Glyph {
+            font: self.default_font().clone(),
+            glyph: glyph.clone(),
+        })
+    }
+}
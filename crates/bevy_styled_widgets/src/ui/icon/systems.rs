@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::themes::ThemeManager;
+
+use super::{
+    DEFAULT_ICON_SIZE,
+    components::StyledIcon,
+    registry::{IconRegistry, IconSource},
+};
+
+// Resolve icons into glyph text or image nodes.
+pub fn update_icons(
+    mut commands: Commands,
+    theme_manager: Res<ThemeManager>,
+    registry: Res<IconRegistry>,
+    mut query: Query<(Entity, Ref<StyledIcon>, &mut Node)>,
+) {
+    let restyle_all = theme_manager.is_changed() || registry.is_changed();
+
+    for (entity, icon, mut node) in query.iter_mut() {
+        if !restyle_all && !icon.is_changed() {
+            continue;
+        }
+
+        let size = icon.size.unwrap_or(DEFAULT_ICON_SIZE);
+        node.width = Val::Px(size);
+        node.height = Val::Px(size);
+
+        match registry.resolve(&icon.icon, &theme_manager.styles.icons) {
+            Some(IconSource::Glyph { font, glyph }) => {
+                commands.entity(entity).remove::<ImageNode>().insert((
+                    Text::new(glyph),
+                    TextFont {
+                        font,
+                        font_size: size,
+                        ..default()
+                    },
+                    TextColor(icon.color.unwrap_or(theme_manager.styles.text.color)),
+                ));
+            }
+            Some(IconSource::Image(image)) => {
+                // Images keep their own colors unless a tint is set explicitly
+                let mut image_node = ImageNode::new(image);
+                if let Some(tint) = icon.color {
+                    image_node.color = tint;
+                }
+                commands
+                    .entity(entity)
+                    .remove::<(Text, TextFont, TextColor)>()
+                    .insert(image_node);
+            }
+            None => {
+                // An empty id is used by widgets for an unset icon slot
+                if !icon.icon.0.is_empty() {
+                    warn!("Icon {:?} is not registered", icon.icon);
+                }
+                commands
+                    .entity(entity)
+                    .remove::<(Text, TextFont, TextColor, ImageNode)>();
+            }
+        }
+    }
+}
@@ -19,9 +19,12 @@ use bevy_cosmic_edit::{
     prelude::TextEdit,
 };
 
-use crate::themes::ThemeManager;
+use crate::{
+    themes::ThemeManager,
+    ui::icon::{IconId, StyledIcon},
+};
 
-use super::components::{AccessibleName, InputVariant, StyledInput};
+use super::components::{AccessibleName, InputVariant, StyledInput, StyledInputIcon};
 
 #[derive(Default)]
 pub struct InputBuilder<'a> {
@@ -30,6 +33,7 @@ pub struct InputBuilder<'a> {
     height: Option<Val>,
     value: Option<String>,
     placeholder: Option<&'static str>,
+    icon: Option<IconId>,
     on_change: Option<SystemId<In<(Entity, String)>>>,
     text_color: Option<Color>,
     placeholder_color: Option<Color>,
@@ -64,6 +68,11 @@ impl<'a> InputBuilder<'a> {
         self
     }
 
+    pub fn icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
     pub fn text_color(mut self, color: Color) -> Self {
         self.text_color = Some(color);
         self
@@ -149,6 +158,7 @@ impl<'a> InputBuilder<'a> {
                 disabled: self.disabled,
                 value: self.value.clone(),
                 placeholder: self.placeholder,
+                icon: self.icon.clone(),
                 font_size: self.font_size,
                 line_height: self.line_height,
                 width: self.width.unwrap_or(Val::Px(200.0)),
@@ -161,19 +171,28 @@ impl<'a> InputBuilder<'a> {
                     .unwrap_or_else(|| "text input".to_string()),
             ),
             TabIndex(0),
-            children![get_text_input(
-                self.font_system,
-                self.value.clone().unwrap_or("".to_string()),
-                self.placeholder,
-                self.text_color.unwrap_or(text_input_style.text_color),
-                self.border_color.unwrap_or(text_input_style.border_color),
-                self.background_color
-                    .unwrap_or(text_input_style.background_color),
-                self.placeholder_color.unwrap_or(LIGHT_GRAY.into()),
-                // self.background_color.unwrap_or(Color::WHITE),
-                self.width,
-                self.height
-            )],
+            children![
+                (
+                    StyledIcon::builder()
+                        .icon(self.icon.unwrap_or_default())
+                        .color(text_input_style.text_color)
+                        .build(),
+                    StyledInputIcon,
+                ),
+                get_text_input(
+                    self.font_system,
+                    self.value.clone().unwrap_or("".to_string()),
+                    self.placeholder,
+                    self.text_color.unwrap_or(text_input_style.text_color),
+                    self.border_color.unwrap_or(text_input_style.border_color),
+                    self.background_color
+                        .unwrap_or(text_input_style.background_color),
+                    self.placeholder_color.unwrap_or(LIGHT_GRAY.into()),
+                    // self.background_color.unwrap_or(Color::WHITE),
+                    self.width,
+                    self.height
+                )
+            ],
         )
     }
 }
@@ -2,6 +2,8 @@ use bevy::a11y::AccessibilityNode;
 use bevy::ecs::{component::HookContext, system::SystemId, world::DeferredWorld};
 use bevy::prelude::*;
 
+use crate::ui::icon::IconId;
+
 #[derive(Component, Reflect)]
 #[reflect(from_reflect = false)]
 pub struct StyledInput {
@@ -12,6 +14,7 @@ pub struct StyledInput {
     pub height: Val,
     pub value: Option<String>,
     pub placeholder: Option<&'static str>,
+    pub icon: Option<IconId>,
     #[reflect(ignore)]
     pub on_change: Option<SystemId<In<(Entity, String)>>>,
     pub text_color: Option<Color>,
@@ -31,6 +34,9 @@ impl StyledInput {
     }
 }
 
+#[derive(Component)]
+pub struct StyledInputIcon;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
 pub enum InputVariant {
     Text,
@@ -1,11 +1,18 @@
-use super::{init, on_input_change, update_input_colors};
+use super::{StyledInput, blur_disabled_inputs, init, on_input_change, update_input_colors};
+use crate::ui::disabled::sync_disabled;
 use bevy::prelude::*;
 
 pub struct StyledInputPlugin;
 impl Plugin for StyledInputPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(PostStartup, init)
-            .add_systems(Update, update_input_colors)
+            .add_systems(
+                Update,
+                (
+                    (sync_disabled::<StyledInput>, blur_disabled_inputs).chain(),
+                    update_input_colors,
+                ),
+            )
             .add_observer(on_input_change);
     }
 }
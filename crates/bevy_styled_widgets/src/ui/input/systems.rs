@@ -7,44 +7,99 @@ use bevy_cosmic_edit::{
     prelude::focus_on_click,
 };
 
-use crate::themes::ThemeManager;
+use crate::{
+    themes::ThemeManager,
+    ui::{events::WidgetChanged, icon::StyledIcon},
+};
 
-use super::StyledInput;
+use super::{StyledInput, StyledInputIcon};
 
+#[allow(clippy::type_complexity)]
 pub fn update_input_colors(
     theme_manager: Res<ThemeManager>,
-    mut q_text_inputs: Query<(&StyledInput, &Children)>,
-    mut q_children: Query<(
-        &mut CosmicBackgroundColor,
-        &mut BorderColor,
-        &mut CosmicEditBuffer,
-    )>,
+    mut q_text_inputs: Query<(Ref<StyledInput>, &Children)>,
+    mut q_children: Query<
+        (
+            &mut CosmicBackgroundColor,
+            &mut BorderColor,
+            &mut CosmicEditBuffer,
+            &mut Node,
+            &mut BorderRadius,
+        ),
+        Without<StyledInputIcon>,
+    >,
+    mut q_icon: Query<(&mut StyledIcon, &mut Node), With<StyledInputIcon>>,
     font_system: Option<ResMut<CosmicFontSystem>>,
 ) {
     let Some(mut font_system) = font_system else {
         return;
     };
-    for (_styled_input, children) in q_text_inputs.iter_mut() {
+    for (styled_input, children) in q_text_inputs.iter_mut() {
         let text_input_style = theme_manager.styles.input.clone();
 
         for child in children.iter() {
-            if let Ok((mut bg_color, mut border_color, mut buffer)) = q_children.get_mut(child) {
-                bg_color.0 = text_input_style.background_color;
-                border_color.0 = text_input_style.border_color;
+            if let Ok((mut icon, mut icon_node)) = q_icon.get_mut(child) {
+                icon_node.display = if styled_input.icon.is_some() {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+                if let Some(icon_id) = &styled_input.icon {
+                    if icon.icon != *icon_id {
+                        icon.icon = icon_id.clone();
+                    }
+                }
+                let icon_color = styled_input
+                    .text_color
+                    .unwrap_or(text_input_style.text_color);
+                if icon.color != Some(icon_color) {
+                    icon.color = Some(icon_color);
+                }
+                continue;
+            }
+
+            if let Ok((mut bg_color, mut border_color, mut buffer, mut node, mut border_radius)) =
+                q_children.get_mut(child)
+            {
+                bg_color.0 = styled_input
+                    .background_color
+                    .unwrap_or(text_input_style.background_color);
+                border_color.0 = styled_input
+                    .border_color
+                    .unwrap_or(text_input_style.border_color);
+                node.width = styled_input.width;
+                node.height = styled_input.height;
+                node.border = styled_input
+                    .border_width
+                    .unwrap_or(UiRect::all(Val::Px(2.)));
+                *border_radius = styled_input
+                    .border_radius
+                    .unwrap_or(BorderRadius::all(Val::Px(6.)));
 
                 // Update text color
                 let mut attrs = Attrs::new();
                 attrs = attrs.color(cosmic_text::Color(
-                    text_input_style.text_color.to_linear().as_u32(),
+                    styled_input
+                        .text_color
+                        .unwrap_or(text_input_style.text_color)
+                        .to_linear()
+                        .as_u32(),
                 ));
 
                 let default_attrs = AttrsOwned::new(Attrs::new());
                 let text_from_buffer = buffer.get_text_spans(default_attrs);
-                let text_value = text_from_buffer
+                let mut text_value = text_from_buffer
                     .iter()
                     .flat_map(|line| line.iter().map(|(text, _)| text.as_str()))
                     .collect::<String>();
 
+                // A value set on the styled input replaces the edited text
+                if styled_input.is_changed() {
+                    if let Some(value) = &styled_input.value {
+                        text_value = value.clone();
+                    }
+                }
+
                 buffer.set_text(&mut font_system, &text_value, attrs);
             }
         }
@@ -55,7 +110,7 @@ pub fn on_input_change(
     // mut trigger: Trigger<CosmicTextChanged>,
     mut trigger: Trigger<ValueChange<CosmicTextChanged>>,
     mut events: EventReader<CosmicTextChanged>,
-    query: Query<&StyledInput>,
+    mut query: Query<&mut StyledInput>,
     mut commands: Commands,
 ) {
     for event in events.read() {
@@ -73,10 +128,17 @@ pub fn on_input_change(
     // let (entity, text_value) = &trigger.event().0;
     info!("Entity {:?} changed text: {:?}", entity, text_value);
 
-    if let Ok(styled_input) = query.get(entity) {
-        if styled_input.disabled {
-            commands.entity(entity).insert(InteractionDisabled);
-        }
+    if let Ok(mut styled_input) = query.get_mut(entity) {
+        commands.trigger_targets(
+            WidgetChanged {
+                entity,
+                old: styled_input.value.clone().unwrap_or_default(),
+                new: text_value.clone(),
+            },
+            entity,
+        );
+
+        styled_input.value = Some(text_value.clone());
         if let Some(system_id) = styled_input.on_change {
             // Defer the callback system using commands
             commands.run_system_with(system_id, (entity, text_value.clone()));
@@ -84,6 +146,19 @@ pub fn on_input_change(
     }
 }
 
+// Disabled inputs can't keep the text focus.
+pub fn blur_disabled_inputs(
+    focused: Option<ResMut<FocusedWidget>>,
+    query: Query<(), (With<StyledInput>, With<InteractionDisabled>)>,
+) {
+    let Some(mut focused) = focused else {
+        return;
+    };
+    if focused.0.is_some_and(|entity| query.contains(entity)) {
+        focused.0 = None;
+    }
+}
+
 pub fn init(mut commands: Commands, mut query: Query<(Entity, &StyledInput)>) {
     for (entity, text_input) in query.iter_mut() {
         if let Some(_text_input) = text_input.value.clone() {
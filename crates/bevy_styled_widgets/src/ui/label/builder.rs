@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use super::components::{LabelRole, StyledLabel};
+use crate::ui::text::{StyledText, TextVariant};
+
+#[derive(Default)]
+pub struct LabelBuilder {
+    text: String,
+    target: Option<Entity>,
+    role: LabelRole,
+    color: Option<Color>,
+    font_size: Option<f32>,
+}
+
+impl LabelBuilder {
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    /// The control this label names, like the HTML `for` attribute.
+    pub fn target(mut self, target: Entity) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn role(mut self, role: LabelRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn font_size(mut self, size: f32) -> Self {
+        self.font_size = Some(size);
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let mut text = StyledText::builder()
+            .variant(TextVariant::Label)
+            .content(self.text);
+        if let Some(color) = self.color {
+            text = text.color(color);
+        }
+        if let Some(font_size) = self.font_size {
+            text = text.font_size(font_size);
+        }
+
+        (
+            text.build(),
+            StyledLabel {
+                target: self.target,
+                role: self.role,
+            },
+        )
+    }
+}
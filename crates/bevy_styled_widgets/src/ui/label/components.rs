@@ -0,0 +1,49 @@
+use bevy::{prelude::*, ui::widget::Label};
+
+/// Text that names or describes a control, like an HTML `<label for=...>`.
+///
+/// The control is `target`, or the nearest labelable ancestor when `target` is `None`, so
+/// captions inside a widget label that widget. Clicking a label with a `target` activates
+/// and focuses the control.
+#[derive(Component, Reflect, Default)]
+#[reflect(from_reflect = false)]
+#[require(Label)]
+pub struct StyledLabel {
+    pub target: Option<Entity>,
+    #[reflect(ignore)]
+    pub role: LabelRole,
+}
+
+impl StyledLabel {
+    pub fn builder() -> super::builder::LabelBuilder {
+        super::builder::LabelBuilder::default()
+    }
+
+    /// Labels the nearest labelable ancestor, for captions built into widgets.
+    pub fn caption() -> Self {
+        Self::default()
+    }
+
+    /// Describes the nearest labelable ancestor, for descriptions built into widgets.
+    pub fn description() -> Self {
+        Self {
+            target: None,
+            role: LabelRole::Description,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LabelRole {
+    /// Provides the accessible name of the control.
+    #[default]
+    Label,
+    /// Provides the accessible description of the control.
+    Description,
+}
+
+/// A widget that can be named by a [`StyledLabel`].
+pub trait LabelableWidget: Component {
+    /// Runs when a label pointing at the widget is clicked, after the widget is focused.
+    fn activate(&self, _entity: Entity, _commands: &mut Commands) {}
+}
@@ -0,0 +1,10 @@
+mod builder;
+mod components;
+mod plugin;
+mod systems;
+mod widgets;
+
+pub use builder::*;
+pub use components::*;
+pub use plugin::StyledLabelPlugin;
+pub use systems::*;
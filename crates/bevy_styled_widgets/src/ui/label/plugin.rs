@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+use super::systems::{on_label_click, update_label_accessibility};
+use crate::ui::{
+    checkbox::StyledCheckbox, input::StyledInput, radio_group::StyledRadioButton,
+    slider::StyledSlider, switch::StyledSwitch, toggle::StyledToggle,
+};
+
+pub struct StyledLabelPlugin;
+impl Plugin for StyledLabelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_label_click::<StyledCheckbox>)
+            .add_observer(on_label_click::<StyledSwitch>)
+            .add_observer(on_label_click::<StyledToggle>)
+            .add_observer(on_label_click::<StyledRadioButton>)
+            .add_observer(on_label_click::<StyledSlider>)
+            .add_observer(on_label_click::<StyledInput>);
+        app.add_systems(
+            Update,
+            (
+                update_label_accessibility::<StyledCheckbox>,
+                update_label_accessibility::<StyledSwitch>,
+                update_label_accessibility::<StyledToggle>,
+                update_label_accessibility::<StyledRadioButton>,
+                update_label_accessibility::<StyledSlider>,
+                update_label_accessibility::<StyledInput>,
+            ),
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use accesskit::NodeId;
+use bevy::{
+    a11y::AccessibilityNode,
+    input_focus::{InputFocus, InputFocusVisible},
+    prelude::*,
+};
+use bevy_core_widgets::InteractionDisabled;
+
+use super::components::{LabelRole, LabelableWidget, StyledLabel};
+
+// The control named by a label: its target, or the nearest `W` ancestor when it has none.
+fn label_control(
+    label: Entity,
+    styled_label: &StyledLabel,
+    q_parent: &Query<&ChildOf>,
+    is_widget: impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    match styled_label.target {
+        Some(target) => Some(target).filter(|target| is_widget(*target)),
+        None => q_parent
+            .iter_ancestors(label)
+            .find(|ancestor| is_widget(*ancestor)),
+    }
+}
+
+// Name a control by its labels and describe it by its descriptions, rebuilt every frame so
+// removed labels drop out and several labels combine their texts. The label's own node keeps
+// its text so the `labelled_by` relation resolves to it.
+#[allow(clippy::type_complexity)]
+pub fn update_label_accessibility<W: LabelableWidget>(
+    mut q_label: Query<(Entity, &StyledLabel, &Text, Option<&mut AccessibilityNode>), Without<W>>,
+    mut q_widget: Query<(Entity, Option<&mut AccessibilityNode>), With<W>>,
+    q_parent: Query<&ChildOf>,
+) {
+    let mut labelled_by: HashMap<Entity, Vec<NodeId>> = HashMap::new();
+    let mut labels: HashMap<Entity, Vec<String>> = HashMap::new();
+    let mut descriptions: HashMap<Entity, Vec<String>> = HashMap::new();
+
+    for (label, styled_label, text, label_accessibility) in q_label.iter_mut() {
+        if let Some(mut label_accessibility) = label_accessibility {
+            if label_accessibility.label() != Some(text.0.as_str()) {
+                label_accessibility.set_label(text.0.as_str());
+            }
+        }
+
+        // Empty texts add nothing, empty captions leave the widget's own accessible name
+        if text.0.is_empty() {
+            continue;
+        }
+        let Some(control) = label_control(label, styled_label, &q_parent, |entity| {
+            q_widget.contains(entity)
+        }) else {
+            continue;
+        };
+
+        match styled_label.role {
+            LabelRole::Label => {
+                labelled_by
+                    .entry(control)
+                    .or_default()
+                    .push(NodeId(label.to_bits()));
+                labels.entry(control).or_default().push(text.0.clone());
+            }
+            LabelRole::Description => {
+                descriptions
+                    .entry(control)
+                    .or_default()
+                    .push(text.0.clone());
+            }
+        }
+    }
+
+    for (control, accessibility) in q_widget.iter_mut() {
+        let Some(mut accessibility) = accessibility else {
+            continue;
+        };
+
+        let node_ids = labelled_by.remove(&control).unwrap_or_default();
+        if accessibility.labelled_by() != node_ids.as_slice() {
+            if node_ids.is_empty() {
+                accessibility.clear_labelled_by();
+            } else {
+                accessibility.set_labelled_by(node_ids);
+            }
+        }
+
+        if let Some(texts) = labels.remove(&control) {
+            let label = texts.join(" ");
+            if accessibility.label() != Some(label.as_str()) {
+                accessibility.set_label(label);
+            }
+        }
+
+        match descriptions.remove(&control) {
+            Some(texts) => {
+                let description = texts.join(" ");
+                if accessibility.description() != Some(description.as_str()) {
+                    accessibility.set_description(description);
+                }
+            }
+            None => {
+                if accessibility.description().is_some() {
+                    accessibility.clear_description();
+                }
+            }
+        }
+    }
+}
+
+// Clicking a label with a `target` focuses and activates the control. Labels without one
+// sit inside their control, which receives the click as it bubbles.
+pub fn on_label_click<W: LabelableWidget>(
+    trigger: Trigger<Pointer<Click>>,
+    q_label: Query<&StyledLabel>,
+    q_widget: Query<(&W, Has<InteractionDisabled>)>,
+    q_parent: Query<&ChildOf>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+    mut commands: Commands,
+) {
+    let label = trigger.target();
+    let Ok(styled_label) = q_label.get(label) else {
+        return;
+    };
+    let Some(target) = styled_label.target else {
+        return;
+    };
+    let Ok((widget, is_disabled)) = q_widget.get(target) else {
+        return;
+    };
+    if is_disabled
+        || q_parent
+            .iter_ancestors(label)
+            .any(|ancestor| ancestor == target)
+    {
+        return;
+    }
+
+    focus.0 = Some(target);
+    focus_visible.0 = false;
+    widget.activate(target, &mut commands);
+}
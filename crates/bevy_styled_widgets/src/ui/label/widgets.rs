@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use bevy_core_widgets::{CoreRadioGroup, ValueChange};
+
+use super::components::LabelableWidget;
+use crate::ui::{
+    checkbox::StyledCheckbox, input::StyledInput, radio_group::StyledRadioButton,
+    slider::StyledSlider, switch::StyledSwitch, toggle::StyledToggle,
+};
+
+impl LabelableWidget for StyledCheckbox {
+    fn activate(&self, entity: Entity, commands: &mut Commands) {
+        commands.trigger_targets(ValueChange(!self.checked), entity);
+    }
+}
+
+impl LabelableWidget for StyledSwitch {
+    fn activate(&self, entity: Entity, commands: &mut Commands) {
+        commands.trigger_targets(ValueChange(!self.state), entity);
+    }
+}
+
+impl LabelableWidget for StyledToggle {
+    fn activate(&self, entity: Entity, commands: &mut Commands) {
+        commands.trigger_targets(ValueChange(!self.active), entity);
+    }
+}
+
+impl LabelableWidget for StyledRadioButton {
+    // Selection is handled by the group, like a click on the radio itself
+    fn activate(&self, entity: Entity, commands: &mut Commands) {
+        commands.queue(move |world: &mut World| {
+            let mut ancestor = world.get::<ChildOf>(entity).map(ChildOf::parent);
+            while let Some(parent) = ancestor {
+                if world.get::<CoreRadioGroup>(parent).is_some() {
+                    world.trigger_targets(ValueChange(entity), parent);
+                    return;
+                }
+                ancestor = world.get::<ChildOf>(parent).map(ChildOf::parent);
+            }
+        });
+    }
+}
+
+impl LabelableWidget for StyledSlider {}
+
+impl LabelableWidget for StyledInput {}
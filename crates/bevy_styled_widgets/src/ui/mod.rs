@@ -1,10 +1,23 @@
+pub mod binding;
 pub mod button;
+pub mod button_group;
 pub mod checkbox;
+pub mod checkbox_group;
+pub mod disabled;
+pub mod focus;
+pub mod focus_ring;
+pub mod events;
+pub mod icon;
 pub mod input;
+pub mod label;
+pub mod panel;
 pub mod progress;
+pub mod range_slider;
 pub mod slider;
+pub mod split_button;
 pub mod switch;
 pub mod text;
 pub mod toggle;
+pub mod toggle_group;
 
-pub mod radio_group;
\ No newline at end of file
+pub mod radio_group;
@@ -0,0 +1,181 @@
+use bevy::{ecs::spawn::SpawnableList, prelude::*};
+
+use super::{
+    PanelSize,
+    components::{PanelContent, PanelFooter, PanelHeader, PanelVariant, StyledPanel},
+};
+use crate::themes::ThemeManager;
+
+#[derive(Default)]
+pub struct PanelBuilder {
+    pub variant: PanelVariant,
+    pub size: Option<PanelSize>,
+    pub bordered: bool,
+    pub header: Option<String>,
+    pub footer: Option<String>,
+    pub background_color: Option<Color>,
+    pub border_color: Option<Color>,
+    pub fill: bool,
+}
+
+impl PanelBuilder {
+    pub fn variant(mut self, variant: PanelVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn size(mut self, size: PanelSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn bordered(mut self, bordered: bool) -> Self {
+        self.bordered = bordered;
+        self
+    }
+
+    pub fn header<S: Into<String>>(mut self, header: S) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    pub fn footer<S: Into<String>>(mut self, footer: S) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    pub fn background_color(mut self, color: Color) -> Self {
+        self.background_color = Some(color);
+        self
+    }
+
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    /// Covers the whole parent, or the window for a root panel.
+    pub fn fill(mut self) -> Self {
+        self.fill = true;
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        self.build_with(())
+    }
+
+    /// Builds the panel, spawning `content` between the header and footer slots.
+    pub fn build_with<L: SpawnableList<ChildOf> + Send + Sync + 'static>(
+        self,
+        content: L,
+    ) -> impl Bundle {
+        let theme_manager = ThemeManager::default();
+
+        let panel_size_styles = theme_manager.styles.panel_sizes.clone();
+        let panel_size_style = match self.size.unwrap_or_default() {
+            PanelSize::XSmall => panel_size_styles.xsmall,
+            PanelSize::Small => panel_size_styles.small,
+            PanelSize::Medium => panel_size_styles.medium,
+            PanelSize::Large => panel_size_styles.large,
+            PanelSize::XLarge => panel_size_styles.xlarge,
+        };
+
+        let style = match self.variant {
+            PanelVariant::Background => &theme_manager.styles.panels.background,
+            PanelVariant::Card => &theme_manager.styles.panels.card,
+            PanelVariant::Popover => &theme_manager.styles.panels.popover,
+            PanelVariant::Sidebar => &theme_manager.styles.panels.sidebar,
+            PanelVariant::Muted => &theme_manager.styles.panels.muted,
+        };
+
+        let border_width = if self.bordered {
+            panel_size_style.border_width
+        } else {
+            0.0
+        };
+
+        let inset = if self.fill { Val::ZERO } else { Val::Auto };
+
+        let slot_display = |slot: &Option<String>| {
+            if slot.is_some() {
+                Display::Flex
+            } else {
+                Display::None
+            }
+        };
+
+        (
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                position_type: if self.fill {
+                    PositionType::Absolute
+                } else {
+                    PositionType::Relative
+                },
+                left: inset,
+                top: inset,
+                right: inset,
+                bottom: inset,
+                padding: UiRect::all(Val::Px(panel_size_style.padding)),
+                row_gap: Val::Px(panel_size_style.row_gap),
+                border: UiRect::all(Val::Px(border_width)),
+                ..default()
+            },
+            Name::new("Panel"),
+            BackgroundColor(self.background_color.unwrap_or(style.background_color)),
+            BorderColor(self.border_color.unwrap_or(style.border_color)),
+            BorderRadius::all(Val::Px(panel_size_style.border_radius)),
+            StyledPanel {
+                variant: self.variant,
+                size: self.size,
+                bordered: self.bordered,
+                header: self.header.clone(),
+                footer: self.footer.clone(),
+                background_color: self.background_color,
+                border_color: self.border_color,
+            },
+            Children::spawn((
+                // Header slot
+                Spawn((
+                    Node {
+                        display: slot_display(&self.header),
+                        ..default()
+                    },
+                    Text::new(self.header.clone().unwrap_or_default()),
+                    TextFont {
+                        font_size: panel_size_style.header_font_size,
+                        ..default()
+                    },
+                    TextColor(style.text_color),
+                    PanelHeader,
+                )),
+                // Content slot
+                Spawn((
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(panel_size_style.row_gap),
+                        ..default()
+                    },
+                    PanelContent,
+                    Children::spawn(content),
+                )),
+                // Footer slot
+                Spawn((
+                    Node {
+                        display: slot_display(&self.footer),
+                        ..default()
+                    },
+                    Text::new(self.footer.clone().unwrap_or_default()),
+                    TextFont {
+                        font_size: panel_size_style.footer_font_size,
+                        ..default()
+                    },
+                    TextColor(style.muted_text_color),
+                    PanelFooter,
+                )),
+            )),
+        )
+    }
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+#[derive(Component, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct StyledPanel {
+    #[reflect(ignore)]
+    pub variant: PanelVariant,
+    #[reflect(ignore)]
+    pub size: Option<PanelSize>,
+    pub bordered: bool,
+    pub header: Option<String>,
+    pub footer: Option<String>,
+    pub background_color: Option<Color>,
+    pub border_color: Option<Color>,
+}
+
+impl StyledPanel {
+    pub fn builder() -> super::builder::PanelBuilder {
+        super::builder::PanelBuilder::default()
+    }
+}
+
+/// A card is a bordered panel using the theme's `card` colors.
+pub struct StyledCard;
+
+impl StyledCard {
+    pub fn builder() -> super::builder::PanelBuilder {
+        super::builder::PanelBuilder::default()
+            .variant(PanelVariant::Card)
+            .bordered(true)
+    }
+}
+
+#[derive(Component)]
+pub struct PanelHeader;
+
+#[derive(Component)]
+pub struct PanelContent;
+
+#[derive(Component)]
+pub struct PanelFooter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub enum PanelVariant {
+    Background,
+    Card,
+    Popover,
+    Sidebar,
+    Muted,
+}
+
+impl Default for PanelVariant {
+    fn default() -> Self {
+        PanelVariant::Background
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub enum PanelSize {
+    XSmall,
+    Small,
+    #[default]
+    Medium,
+    Large,
+    XLarge,
+}
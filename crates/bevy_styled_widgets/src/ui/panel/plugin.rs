@@ -0,0 +1,9 @@
+use super::systems::update_panel_styles;
+use bevy::prelude::*;
+
+pub struct StyledPanelPlugin;
+impl Plugin for StyledPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_panel_styles);
+    }
+}
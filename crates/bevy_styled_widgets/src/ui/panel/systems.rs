@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+
+use crate::themes::ThemeManager;
+
+use super::{
+    PanelSize,
+    components::{PanelContent, PanelFooter, PanelVariant, StyledPanel},
+};
+
+// Restyle panels when the theme or the panel itself changes.
+#[allow(clippy::type_complexity)]
+pub fn update_panel_styles(
+    theme_manager: Res<ThemeManager>,
+    mut query: Query<(
+        Ref<StyledPanel>,
+        &mut Node,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        &mut BorderRadius,
+        &Children,
+    )>,
+    mut q_slots: Query<
+        (
+            &mut Node,
+            &mut Text,
+            &mut TextFont,
+            &mut TextColor,
+            Has<PanelFooter>,
+        ),
+        Without<StyledPanel>,
+    >,
+    mut q_content: Query<&mut Node, (With<PanelContent>, Without<StyledPanel>, Without<Text>)>,
+) {
+    for (panel, mut panel_node, mut bg_color, mut border_color, mut border_radius, children) in
+        query.iter_mut()
+    {
+        if !theme_manager.is_changed() && !panel.is_changed() {
+            continue;
+        }
+
+        let panel_styles = theme_manager.styles.panels.clone();
+        let panel_size_styles = theme_manager.styles.panel_sizes.clone();
+
+        let panel_style = match panel.variant {
+            PanelVariant::Background => panel_styles.background,
+            PanelVariant::Card => panel_styles.card,
+            PanelVariant::Popover => panel_styles.popover,
+            PanelVariant::Sidebar => panel_styles.sidebar,
+            PanelVariant::Muted => panel_styles.muted,
+        };
+
+        let panel_size_style = match panel.size.unwrap_or_default() {
+            PanelSize::XSmall => panel_size_styles.xsmall,
+            PanelSize::Small => panel_size_styles.small,
+            PanelSize::Medium => panel_size_styles.medium,
+            PanelSize::Large => panel_size_styles.large,
+            PanelSize::XLarge => panel_size_styles.xlarge,
+        };
+
+        bg_color.0 = panel
+            .background_color
+            .unwrap_or(panel_style.background_color);
+        border_color.0 = panel.border_color.unwrap_or(panel_style.border_color);
+        *border_radius = BorderRadius::all(Val::Px(panel_size_style.border_radius));
+
+        panel_node.padding = UiRect::all(Val::Px(panel_size_style.padding));
+        panel_node.row_gap = Val::Px(panel_size_style.row_gap);
+        panel_node.border = UiRect::all(Val::Px(if panel.bordered {
+            panel_size_style.border_width
+        } else {
+            0.0
+        }));
+
+        for child in children.iter() {
+            if let Ok(mut content_node) = q_content.get_mut(child) {
+                content_node.row_gap = Val::Px(panel_size_style.row_gap);
+                continue;
+            }
+
+            let Ok((mut slot_node, mut text, mut text_font, mut text_color, is_footer)) =
+                q_slots.get_mut(child)
+            else {
+                continue;
+            };
+
+            let (slot, font_size, color) = if is_footer {
+                (
+                    &panel.footer,
+                    panel_size_style.footer_font_size,
+                    panel_style.muted_text_color,
+                )
+            } else {
+                (
+                    &panel.header,
+                    panel_size_style.header_font_size,
+                    panel_style.text_color,
+                )
+            };
+
+            slot_node.display = if slot.is_some() {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            text.0 = slot.clone().unwrap_or_default();
+            text_font.font_size = font_size;
+            text_color.0 = color;
+        }
+    }
+}
@@ -18,6 +18,7 @@ pub struct ProgressIndicator;
 #[derive(Default)]
 pub struct ProgressBuilder {
     value: f32,
+    max: Option<f32>,
     root_color: Option<Color>,
     indicator_color: Option<Color>,
 }
@@ -28,6 +29,12 @@ impl ProgressBuilder {
         self
     }
 
+    /// Sets the value of a full progress bar, 100 by default.
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = Some(max);
+        self
+    }
+
     pub fn root_color(mut self, color: Color) -> Self {
         self.root_color = Some(color);
         self
@@ -39,12 +46,8 @@ impl ProgressBuilder {
     }
 
     pub fn build(self) -> impl Bundle {
-        let max = if self.value.clone() > 10.0 {
-            100.
-        } else {
-            10.0
-        };
-        let progress_value = (self.value / max) * 100.0;
+        let max = self.max.unwrap_or(100.0);
+        let progress_value = indicator_percent(self.value, max);
 
         (
             Node {
@@ -64,6 +67,7 @@ impl ProgressBuilder {
             CursorIcon::System(SystemCursorIcon::Default),
             StyledProgress {
                 value: self.value.clone(),
+                max,
                 root_color: self.root_color.clone(),
                 indicator_color: self.indicator_color.clone(),
             },
@@ -75,7 +79,7 @@ impl ProgressBuilder {
                         height: Val::Px(6.0),
                         ..default()
                     },
-                    BackgroundColor(self.indicator_color.unwrap_or(GREY.into())),
+                    BackgroundColor(self.root_color.unwrap_or(GREY.into())),
                     BorderRadius::all(Val::Px(3.0)),
                     ProgressRoot,
                 )),
@@ -97,3 +101,11 @@ impl ProgressBuilder {
         )
     }
 }
+
+/// Width of the indicator in percent of the progress bar.
+pub fn indicator_percent(value: f32, max: f32) -> f32 {
+    if max <= 0.0 {
+        return 0.0;
+    }
+    (value / max * 100.0).clamp(0.0, 100.0)
+}
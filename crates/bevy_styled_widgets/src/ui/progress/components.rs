@@ -6,6 +6,8 @@ use bevy::prelude::*;
 #[reflect(from_reflect = false)]
 pub struct StyledProgress {
     pub value: f32,
+    /// The value of a full progress bar.
+    pub max: f32,
     pub root_color: Option<Color>,
     pub indicator_color: Option<Color>,
 }
@@ -1,10 +1,10 @@
 use bevy::prelude::*;
 
-use super::update_progress_colors;
+use super::{update_progress_colors, update_progress_value};
 
 pub struct StyledProgessPlugin;
 impl Plugin for StyledProgessPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, update_progress_colors);
+        app.add_systems(Update, (update_progress_colors, update_progress_value));
     }
 }
@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use crate::themes::ThemeManager;
 
-use super::{ProgressIndicator, ProgressRoot, StyledProgress};
+use super::{ProgressIndicator, ProgressRoot, StyledProgress, indicator_percent};
 
 #[allow(clippy::type_complexity)]
 pub fn update_progress_colors(
@@ -36,3 +36,17 @@ pub fn update_progress_colors(
         }
     }
 }
+
+// Resize the indicator when the progress value changes.
+pub fn update_progress_value(
+    query: Query<(&StyledProgress, &Children), Changed<StyledProgress>>,
+    mut q_indicators: Query<&mut Node, With<ProgressIndicator>>,
+) {
+    for (progress, children) in query.iter() {
+        for child in children.iter() {
+            if let Ok(mut node) = q_indicators.get_mut(child) {
+                node.width = Val::Percent(indicator_percent(progress.value, progress.max));
+            }
+        }
+    }
+}
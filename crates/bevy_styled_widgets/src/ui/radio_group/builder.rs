@@ -1,14 +1,30 @@
+use accesskit::Role;
 use bevy::{
-    ecs::system::SystemId, input_focus::tab_navigation::TabIndex, prelude::*,
-    window::SystemCursorIcon, winit::cursor::CursorIcon,
+    a11y::AccessibilityNode,
+    ecs::{spawn::SpawnIter, system::SystemId},
+    input_focus::tab_navigation::TabIndex,
+    prelude::*,
+    window::SystemCursorIcon,
+    winit::cursor::CursorIcon,
 };
 use bevy_core_widgets::{Checked, CoreRadio, CoreRadioGroup, hover::Hovering};
 
 use super::{
     RadioButtonSize,
-    components::{AccessibleName, RadioButtonVariant, StyledRadioButton, RadioButtonDirection},
+    components::{
+        AccessibleName, RadioButtonDirection, RadioButtonVariant, RadioCaption, RadioDescription,
+        RadioGroup, RadioGroupValue, RadioIcon, RadioIndicator, RadioIndicatorDot, RadioOption,
+        StyledRadioButton, StyledRadioGroup,
+    },
+};
+use crate::{
+    themes::ThemeManager,
+    ui::{
+        disabled::Fieldset,
+        icon::{IconId, StyledIcon},
+        label::StyledLabel,
+    },
 };
-use crate::themes::ThemeManager;
 
 #[derive(Component, Default)]
 pub struct RootComponent;
@@ -24,6 +40,8 @@ pub struct RadioButtonBuilder {
     pub disabled: bool,
     pub size: Option<RadioButtonSize>,
     pub caption: Option<String>,
+    pub description: Option<String>,
+    pub icon: Option<IconId>,
     pub value: String,
 }
 
@@ -58,6 +76,16 @@ impl RadioButtonBuilder {
         self
     }
 
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
     pub fn value<S: Into<String>>(mut self, value: S) -> Self {
         self.value = value.into();
         self
@@ -78,6 +106,8 @@ impl RadioButtonBuilder {
 
         let style = match self.variant {
             RadioButtonVariant::Default => &theme_manager.styles.radio_buttons.default,
+            RadioButtonVariant::Card => &theme_manager.styles.radio_buttons.card,
+            RadioButtonVariant::Segmented => &theme_manager.styles.radio_buttons.segmented,
         };
 
         let is_checked = self.checked;
@@ -105,23 +135,23 @@ impl RadioButtonBuilder {
 
         let caption = self.caption.clone().unwrap_or_else(|| "".to_string());
 
-        let cursor_icon = if self.disabled {
-            SystemCursorIcon::NotAllowed
-        } else {
-            SystemCursorIcon::Pointer
-        };
-
         let child_nodes = Children::spawn((
             Spawn((
                 // Radio outer
                 Node {
-                    display: Display::Flex,
+                    display: if style.show_indicator {
+                        Display::Flex
+                    } else {
+                        Display::None
+                    },
                     width: Val::Px(radio_size_style.outer_width),
                     height: Val::Px(radio_size_style.outer_height),
                     border: UiRect::all(Val::Px(radio_size_style.outer_border_width)),
+                    flex_shrink: 0.0,
                     ..default()
                 },
-                BorderColor(border_color), 
+                RadioIndicator,
+                BorderColor(border_color),
                 BorderRadius::all(Val::Percent(radio_size_style.outer_corner_radius)),
                 children![
                     // Radio inner
@@ -135,6 +165,7 @@ impl RadioButtonBuilder {
                             top: Val::Px(radio_size_style.inner_circle_top),
                             ..default()
                         },
+                        RadioIndicatorDot,
                         BackgroundColor(background_color),
                         BorderRadius::all(Val::Percent(
                             radio_size_style.inner_circle_corner_radius
@@ -143,12 +174,42 @@ impl RadioButtonBuilder {
                 ],
             )),
             Spawn((
-                Text::new(caption.clone()),
-                TextFont {
-                    font_size: radio_size_style.caption_font_size,
+                StyledIcon::builder()
+                    .icon(self.icon.clone().unwrap_or_default())
+                    .size(radio_size_style.icon_size)
+                    .build(),
+                RadioIcon,
+            )),
+            // Caption with the description below it
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
                     ..default()
                 },
-                TextColor(style.caption_color),
+                children![
+                    (
+                        Text::new(caption.clone()),
+                        TextFont {
+                            font_size: radio_size_style.caption_font_size,
+                            ..default()
+                        },
+                        TextColor(style.caption_color),
+                        RadioCaption,
+                        StyledLabel::caption(),
+                    ),
+                    (
+                        Text::new(self.description.clone().unwrap_or_default()),
+                        TextFont {
+                            font_size: radio_size_style.description_font_size,
+                            ..default()
+                        },
+                        TextColor(style.description_color),
+                        RadioDescription,
+                        StyledLabel::description(),
+                    ),
+                ],
             )),
         ));
 
@@ -160,8 +221,11 @@ impl RadioButtonBuilder {
                 ..default()
             },
             Name::new("Radio"),
+            BackgroundColor::default(),
+            BorderColor::default(),
+            BorderRadius::default(),
             Hovering::default(),
-            CursorIcon::System(cursor_icon),
+            CursorIcon::System(SystemCursorIcon::Pointer),
             StyledRadioButton {
                 checked: self.checked,
                 on_change: self.on_change,
@@ -169,6 +233,8 @@ impl RadioButtonBuilder {
                 disabled: self.disabled,
                 size: self.size,
                 caption: self.caption,
+                description: self.description,
+                icon: self.icon,
                 value: self.value.clone(),
             },
             CoreRadio,
@@ -187,6 +253,9 @@ pub struct RadioGroupBuilder {
     pub on_change: Option<SystemId<In<Entity>>>,
     pub children: Vec<RadioButtonBuilder>,
     pub direction: RadioButtonDirection,
+    pub variant: Option<RadioButtonVariant>,
+    pub required: bool,
+    pub disabled: bool,
 }
 
 impl RadioGroupBuilder {
@@ -205,13 +274,42 @@ impl RadioGroupBuilder {
         self
     }
 
-    pub fn build(self) -> (impl Bundle, Vec<impl Bundle>) {
+    /// Overrides the variant of every radio passed to `children`.
+    pub fn variant(mut self, variant: RadioButtonVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    pub fn build(mut self) -> (impl Bundle, Vec<impl Bundle>) {
+        if let Some(variant) = self.variant {
+            for child in self.children.iter_mut() {
+                child.variant = variant;
+            }
+        }
+
         let child_bundles = self
             .children
+            .clone()
             .into_iter()
             .map(|builder| builder.build())
             .collect::<Vec<_>>();
 
+        // Cards are spaced apart, segments and default radios sit next to each other
+        let gap = match self.variant {
+            Some(RadioButtonVariant::Card) => Val::Px(8.0),
+            _ => Val::ZERO,
+        };
+
         let flex_direction = match self.direction {
             RadioButtonDirection::Vertical => FlexDirection::Column,
             RadioButtonDirection::Horizontal => FlexDirection::Row,
@@ -221,17 +319,118 @@ impl RadioGroupBuilder {
             Node {
                 display: Display::Flex,
                 flex_direction,
+                row_gap: gap,
+                column_gap: gap,
                 justify_content: JustifyContent::Start,
                 align_items: AlignItems::Start,
                 align_content: AlignContent::Start,
                 ..default()
             },
-            CoreRadioGroup {
+            StyledRadioGroup {
+                buttons: self.children,
                 on_change: self.on_change,
+                direction: self.direction,
+                required: self.required,
+                disabled: self.disabled,
             },
-            TabIndex(0),
+            // `on_change` is run by the styled observer, so the core widget triggers `ValueChange`
+            CoreRadioGroup { on_change: None },
+            Fieldset {
+                disabled: self.disabled,
+            },
+            AccessibilityNode(accesskit::Node::new(Role::RadioGroup)),
         );
 
         (parent_bundle, child_bundles)
     }
 }
+
+/// Builds a radio group with a [`RadioGroup<T>`] and its radios as children, each standing
+/// for a value of type `T`.
+pub struct TypedRadioGroupBuilder<T: RadioGroupValue> {
+    group: RadioGroupBuilder,
+    options: Vec<(T, RadioButtonBuilder)>,
+    value: Option<T>,
+    on_change: Option<SystemId<In<T>>>,
+}
+
+impl<T: RadioGroupValue> Default for TypedRadioGroupBuilder<T> {
+    fn default() -> Self {
+        Self {
+            group: RadioGroupBuilder::default(),
+            options: Vec::new(),
+            value: None,
+            on_change: None,
+        }
+    }
+}
+
+impl<T: RadioGroupValue> TypedRadioGroupBuilder<T> {
+    /// Adds a radio captioned `label` standing for `value`.
+    pub fn option<S: Into<String>>(self, value: T, label: S) -> Self {
+        self.radio(value, RadioButtonBuilder::default().caption(label))
+    }
+
+    /// Adds a radio standing for `value`, e.g. a card with a description.
+    pub fn radio(mut self, value: T, radio: RadioButtonBuilder) -> Self {
+        self.options.push((value, radio));
+        self
+    }
+
+    /// Checks the radio standing for `value`.
+    pub fn value(mut self, value: T) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn on_change(mut self, system_id: SystemId<In<T>>) -> Self {
+        self.on_change = Some(system_id);
+        self
+    }
+
+    pub fn direction(mut self, direction: RadioButtonDirection) -> Self {
+        self.group = self.group.direction(direction);
+        self
+    }
+
+    /// Sets the variant of every radio in the group.
+    pub fn variant(mut self, variant: RadioButtonVariant) -> Self {
+        self.group = self.group.variant(variant);
+        self
+    }
+
+    pub fn required(mut self) -> Self {
+        self.group = self.group.required();
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.group = self.group.disabled();
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let variant = self.group.variant;
+        let radios: Vec<_> = self
+            .options
+            .into_iter()
+            .map(|(value, mut radio)| {
+                radio.checked = self.value.as_ref() == Some(&value);
+                if let Some(variant) = variant {
+                    radio.variant = variant;
+                }
+                (radio.build(), RadioOption(value))
+            })
+            .collect();
+        let (group_bundle, _) = self.group.build();
+
+        (
+            group_bundle,
+            RadioGroup {
+                value: self.value,
+                on_change: self.on_change,
+            },
+            Children::spawn(SpawnIter(radios.into_iter())),
+        )
+    }
+}
@@ -3,6 +3,8 @@ use bevy::a11y::AccessibilityNode;
 use bevy::ecs::{component::HookContext, system::SystemId, world::DeferredWorld};
 use bevy::prelude::*;
 
+use crate::ui::icon::IconId;
+
 #[derive(Component, Reflect)]
 #[reflect(from_reflect = false)]
 pub struct StyledRadioButton {
@@ -15,6 +17,9 @@ pub struct StyledRadioButton {
     pub size: Option<RadioButtonSize>,
     pub disabled: bool,
     pub caption: Option<String>,
+    /// Secondary text below the caption, shown by the card variant.
+    pub description: Option<String>,
+    pub icon: Option<IconId>,
     pub value: String,
 }
 
@@ -31,6 +36,11 @@ pub struct StyledRadioGroup {
     pub buttons: Vec<RadioButtonBuilder>,
     #[reflect(ignore)]
     pub on_change: Option<SystemId<In<Entity>>>,
+    #[reflect(ignore)]
+    pub direction: RadioButtonDirection,
+    /// Keeps a radio checked, the first enabled one when nothing is selected.
+    pub required: bool,
+    pub disabled: bool,
 }
 
 impl StyledRadioGroup {
@@ -39,9 +49,56 @@ impl StyledRadioGroup {
     }
 }
 
+pub trait RadioGroupValue: Clone + PartialEq + Send + Sync + 'static {}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> RadioGroupValue for T {}
+
+/// Value-typed selection of a radio group, `T` is registered with
+/// [`RadioGroupPlugin`](super::RadioGroupPlugin).
+///
+/// Setting `value` checks the radio with the matching [`RadioOption`], `None` clears the
+/// selection. Radios may be nested in layout nodes below the group. With an `on_change`
+/// callback the group is controlled and the callback decides the new value.
+#[derive(Component)]
+#[require(TypedRadioGroup)]
+pub struct RadioGroup<T: RadioGroupValue> {
+    pub value: Option<T>,
+    pub on_change: Option<SystemId<In<T>>>,
+}
+
+impl<T: RadioGroupValue> RadioGroup<T> {
+    pub fn builder() -> super::builder::TypedRadioGroupBuilder<T> {
+        super::builder::TypedRadioGroupBuilder::default()
+    }
+
+    pub fn new(value: Option<T>) -> Self {
+        Self {
+            value,
+            on_change: None,
+        }
+    }
+
+    pub fn on_change(mut self, system_id: SystemId<In<T>>) -> Self {
+        self.on_change = Some(system_id);
+        self
+    }
+}
+
+/// The value a radio stands for in a [`RadioGroup`].
+#[derive(Component, Debug, Clone)]
+pub struct RadioOption<T: RadioGroupValue>(pub T);
+
+/// Marks groups whose selection is handled by a [`RadioGroup`].
+#[derive(Component, Default)]
+pub struct TypedRadioGroup;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
 pub enum RadioButtonVariant {
     Default,
+    /// Bordered card with caption, description and icon, the whole card is the hit area.
+    Card,
+    /// Button-like segment without an indicator, the borders of adjacent segments are merged.
+    Segmented,
 }
 
 impl Default for RadioButtonVariant {
@@ -72,9 +129,24 @@ fn on_set_label(mut world: DeferredWorld, context: HookContext) {
     }
 }
 
+#[derive(Component)]
+pub struct RadioIndicator;
+
+#[derive(Component)]
+pub struct RadioIndicatorDot;
+
+#[derive(Component)]
+pub struct RadioCaption;
+
+#[derive(Component)]
+pub struct RadioDescription;
+
+#[derive(Component)]
+pub struct RadioIcon;
+
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RadioButtonDirection {
     #[default]
     Vertical,
     Horizontal,
-}
\ No newline at end of file
+}
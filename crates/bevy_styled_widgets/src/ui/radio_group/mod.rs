@@ -5,5 +5,5 @@ mod systems;
 
 pub use builder::*;
 pub use components::*;
-pub use plugin::StyledRadioGroupPlugin;
+pub use plugin::{RadioGroupPlugin, StyledRadioGroupPlugin};
 pub use systems::*;
@@ -1,10 +1,53 @@
-use super::systems::{observe_radio_group_selection, update_radio_button_visuals};
+use std::marker::PhantomData;
+
+use super::{
+    StyledRadioButton,
+    components::RadioGroupValue,
+    systems::{
+        observe_radio_group_selection, observe_typed_radio_group_selection,
+        on_radio_group_key_input, sync_radio_button_state, sync_radio_group_state,
+        sync_radio_group_value, update_radio_button_visuals, update_radio_group_tab_index,
+        update_segmented_radio_group_styles,
+    },
+};
+use crate::ui::disabled::sync_disabled;
 use bevy::prelude::*;
 
 pub struct StyledRadioGroupPlugin;
 impl Plugin for StyledRadioGroupPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(observe_radio_group_selection);
-        app.add_systems(Update, update_radio_button_visuals);
+        app.add_observer(observe_radio_group_selection)
+            .add_observer(on_radio_group_key_input);
+        app.add_systems(
+            Update,
+            (
+                sync_radio_group_state,
+                sync_radio_button_state,
+                sync_disabled::<StyledRadioButton>,
+                update_radio_button_visuals,
+                update_segmented_radio_group_styles,
+            )
+                .chain(),
+        );
+        app.add_systems(Update, update_radio_group_tab_index);
+    }
+}
+
+/// Enables [`RadioGroup`](super::RadioGroup) selection with values of type `T`.
+pub struct RadioGroupPlugin<T>(PhantomData<T>);
+
+impl<T> Default for RadioGroupPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: RadioGroupValue> Plugin for RadioGroupPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(observe_typed_radio_group_selection::<T>);
+        app.add_systems(
+            Update,
+            sync_radio_group_value::<T>.before(sync_radio_group_state),
+        );
     }
 }
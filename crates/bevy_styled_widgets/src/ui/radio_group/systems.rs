@@ -1,39 +1,95 @@
-use bevy::prelude::*;
+use bevy::{
+    a11y::AccessibilityNode,
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::{FocusedInput, InputFocus, InputFocusVisible, tab_navigation::TabIndex},
+    prelude::*,
+};
 use bevy_core_widgets::{Checked, InteractionDisabled, ValueChange, hover::Hovering};
 
 use super::{
     RadioButtonSize,
     builder::{RadioValue, RootComponent},
-    components::{RadioButtonVariant, StyledRadioButton},
+    components::{
+        AccessibleName, RadioButtonDirection, RadioButtonVariant, RadioCaption, RadioDescription,
+        RadioGroup, RadioGroupValue, RadioIcon, RadioIndicator, RadioIndicatorDot, RadioOption,
+        StyledRadioButton, StyledRadioGroup, TypedRadioGroup,
+    },
+};
+use crate::{
+    themes::ThemeManager,
+    ui::{
+        disabled::Fieldset,
+        events::WidgetSelected,
+        focus::{move_roving_focus, update_roving_tab_index},
+        icon::StyledIcon,
+    },
 };
-use crate::themes::ThemeManager;
 use bevy_core_widgets::{CoreRadio, CoreRadioGroup};
 
 #[allow(clippy::type_complexity)]
 pub fn update_radio_button_visuals(
     theme_manager: Res<ThemeManager>,
     mut query: Query<
-        (    
-            &mut Node,       
+        (
+            Entity,
+            &mut Node,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            &mut BorderRadius,
             &StyledRadioButton,
             &Hovering,
             &Checked,
             Has<InteractionDisabled>,
-            &Children,
         ),
-        (With<RootComponent>,),
+        With<RootComponent>,
+    >,
+    q_children: Query<&Children>,
+    mut q_indicator: Query<
+        (&mut Node, &mut BorderColor, &mut BorderRadius),
+        (With<RadioIndicator>, Without<RootComponent>),
+    >,
+    mut q_inner: Query<
+        (&mut Node, &mut BackgroundColor, &mut BorderRadius),
+        (
+            With<RadioIndicatorDot>,
+            Without<RadioIndicator>,
+            Without<RootComponent>,
+        ),
+    >,
+    mut q_caption_text: Query<
+        (&mut Text, &mut TextColor, &mut TextFont),
+        (With<RadioCaption>, Without<RadioDescription>),
+    >,
+    mut q_description: Query<
+        (&mut Node, &mut Text, &mut TextColor, &mut TextFont),
+        (
+            With<RadioDescription>,
+            Without<RadioIndicator>,
+            Without<RadioIndicatorDot>,
+            Without<RootComponent>,
+        ),
+    >,
+    mut q_icon: Query<
+        (&mut Node, &mut StyledIcon),
+        (
+            With<RadioIcon>,
+            Without<RadioDescription>,
+            Without<RadioIndicator>,
+            Without<RadioIndicatorDot>,
+            Without<RootComponent>,
+        ),
     >,
-    mut q_border_color: Query<(&mut BorderColor, &mut Children), Without<RootComponent>>,
-    mut q_inner: Query<&mut BackgroundColor>,
-    mut q_caption_text: Query<&mut TextColor>,
 ) {
     for (
+        entity,
         mut button_node,
+        mut button_background,
+        mut button_border_color,
+        mut button_radius,
         radio_button,
         Hovering(is_hovering),
         Checked(checked),
         is_disabled,
-        children,
     ) in query.iter_mut()
     {
         let button_size_styles = theme_manager.styles.radio_button_sizes.clone();
@@ -44,89 +100,574 @@ pub fn update_radio_button_visuals(
             RadioButtonSize::Large => button_size_styles.large,
             RadioButtonSize::XLarge => button_size_styles.xlarge,
         };
-        button_node.padding = UiRect::axes(
-            Val::Px(radio_button_size_style.padding_horizontal),
-            Val::Px(radio_button_size_style.padding_vertical),
-        );
-
-        button_node.column_gap = Val::Px(radio_button_size_style.column_gap);
 
         let radio_button_styles = theme_manager.styles.radio_buttons.clone();
 
         let radio_button_style = match radio_button.variant {
             RadioButtonVariant::Default => radio_button_styles.default,
+            RadioButtonVariant::Card => radio_button_styles.card,
+            RadioButtonVariant::Segmented => radio_button_styles.segmented,
         };
 
-        let Some(radio_button_id) = children.first() else {
-            continue;
-        };
-        let Some(caption_id) = children.get(1) else {
-            continue;
+        // Container layout, the card and the segment are bordered boxes
+        match radio_button.variant {
+            RadioButtonVariant::Default => {
+                button_node.padding = UiRect::axes(
+                    Val::Px(radio_button_size_style.padding_horizontal),
+                    Val::Px(radio_button_size_style.padding_vertical),
+                );
+                button_node.border = UiRect::ZERO;
+                button_node.justify_content = JustifyContent::Center;
+                button_node.align_items = AlignItems::Center;
+                button_node.align_self = AlignSelf::Auto;
+            }
+            RadioButtonVariant::Card => {
+                button_node.padding = UiRect::all(Val::Px(radio_button_size_style.card_padding));
+                button_node.border =
+                    UiRect::all(Val::Px(radio_button_size_style.container_border_width));
+                button_node.justify_content = JustifyContent::Start;
+                button_node.align_items = AlignItems::Start;
+                button_node.align_self = AlignSelf::Stretch;
+            }
+            RadioButtonVariant::Segmented => {
+                button_node.padding = UiRect::axes(
+                    Val::Px(radio_button_size_style.padding_horizontal),
+                    Val::Px(radio_button_size_style.padding_vertical),
+                );
+                button_node.border =
+                    UiRect::all(Val::Px(radio_button_size_style.container_border_width));
+                button_node.justify_content = JustifyContent::Center;
+                button_node.align_items = AlignItems::Center;
+                button_node.align_self = AlignSelf::Auto;
+            }
+        }
+        button_node.column_gap = Val::Px(radio_button_size_style.column_gap);
+        *button_radius =
+            BorderRadius::all(Val::Px(radio_button_size_style.container_corner_radius));
+
+        let (container_background, container_border) = match (*checked, is_disabled, is_hovering) {
+            (true, true, _) => (
+                radio_button_style.disabled_checked_background,
+                radio_button_style.disabled_border,
+            ),
+            (false, true, _) => (
+                radio_button_style.disabled_background,
+                radio_button_style.disabled_border,
+            ),
+            (true, false, _) => (
+                radio_button_style.checked_background,
+                radio_button_style.checked_border,
+            ),
+            (false, false, true) => (
+                radio_button_style.hovered_background,
+                radio_button_style.hovered_border,
+            ),
+            (false, false, false) => (radio_button_style.background, radio_button_style.border),
         };
+        button_background.0 = container_background;
+        button_border_color.0 = container_border;
 
-        // Outer ring node
-        let Ok((mut border_color, main_children)) = q_border_color.get_mut(*radio_button_id) else {
-            continue;
+        let caption_color = if *checked {
+            radio_button_style.checked_caption_color
+        } else {
+            radio_button_style.caption_color
         };
 
-        // Inner circle
-        let inner_component_id = main_children[0];
-        if let Ok(mut bg_color) = q_inner.get_mut(inner_component_id) {
-            let (new_bg, new_border_color) = match (*checked, is_disabled, is_hovering) {
-                (true, true, _) => (
-                    radio_button_style.disabled_inner_checked_background,
-                    radio_button_style.disabled_outer_border,
-                ),
-                (false, true, _) => (
-                    radio_button_style.disabled_inner_unchecked_background,
-                    radio_button_style.disabled_outer_border,
-                ),
-                (true, false, true) => (
-                    radio_button_style.inner_checked_background,
-                    radio_button_style.outer_border,
-                ),
-                (false, false, true) => (
-                    radio_button_style.hovered_inner_unchecked_background,
-                    radio_button_style.hovered_outer_border,
-                ),
-                (true, false, false) => (
-                    radio_button_style.inner_checked_background,
-                    radio_button_style.outer_border,
-                ),
-                (false, false, false) => (
-                    radio_button_style.inner_unchecked_background,
-                    radio_button_style.outer_border,
-                ),
+        for child in q_children.iter_descendants(entity) {
+            // Outer ring node
+            if let Ok((mut outer_node, mut border_color, mut outer_radius)) =
+                q_indicator.get_mut(child)
+            {
+                outer_node.display = if radio_button_style.show_indicator {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+                outer_node.width = Val::Px(radio_button_size_style.outer_width);
+                outer_node.height = Val::Px(radio_button_size_style.outer_height);
+                outer_node.border =
+                    UiRect::all(Val::Px(radio_button_size_style.outer_border_width));
+                *outer_radius =
+                    BorderRadius::all(Val::Percent(radio_button_size_style.outer_corner_radius));
+
+                border_color.0 = match (is_disabled, *is_hovering && !*checked) {
+                    (true, _) => radio_button_style.disabled_outer_border,
+                    (false, true) => radio_button_style.hovered_outer_border,
+                    (false, false) => radio_button_style.outer_border,
+                };
+            }
+
+            // Inner circle
+            if let Ok((mut inner_node, mut bg_color, mut inner_radius)) = q_inner.get_mut(child) {
+                inner_node.width = Val::Px(radio_button_size_style.inner_circle_width);
+                inner_node.height = Val::Px(radio_button_size_style.inner_circle_height);
+                inner_node.left = Val::Px(radio_button_size_style.inner_circle_left);
+                inner_node.top = Val::Px(radio_button_size_style.inner_circle_top);
+                *inner_radius = BorderRadius::all(Val::Percent(
+                    radio_button_size_style.inner_circle_corner_radius,
+                ));
+
+                bg_color.0 = match (*checked, is_disabled, is_hovering) {
+                    (true, true, _) => radio_button_style.disabled_inner_checked_background,
+                    (false, true, _) => radio_button_style.disabled_inner_unchecked_background,
+                    (true, false, _) => radio_button_style.inner_checked_background,
+                    (false, false, true) => radio_button_style.hovered_inner_unchecked_background,
+                    (false, false, false) => radio_button_style.inner_unchecked_background,
+                };
+            }
+
+            // Caption node
+            if let Ok((mut caption_text, mut caption_text_color, mut caption_font)) =
+                q_caption_text.get_mut(child)
+            {
+                let caption = radio_button.caption.clone().unwrap_or_default();
+                if caption_text.0 != caption {
+                    caption_text.0 = caption;
+                }
+                caption_text_color.0 = caption_color;
+                caption_font.font_size = radio_button_size_style.caption_font_size;
+            }
+
+            if let Ok((
+                mut description_node,
+                mut description_text,
+                mut description_color,
+                mut description_font,
+            )) = q_description.get_mut(child)
+            {
+                let description = radio_button.description.clone().unwrap_or_default();
+                description_node.display = if description.is_empty() {
+                    Display::None
+                } else {
+                    Display::Flex
+                };
+                if description_text.0 != description {
+                    description_text.0 = description;
+                }
+                description_color.0 = radio_button_style.description_color;
+                description_font.font_size = radio_button_size_style.description_font_size;
+            }
+
+            if let Ok((mut icon_node, mut icon)) = q_icon.get_mut(child) {
+                icon_node.display = if radio_button.icon.is_some() {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+                if let Some(icon_id) = &radio_button.icon {
+                    if icon.icon != *icon_id {
+                        icon.icon = icon_id.clone();
+                    }
+                }
+                if icon.size != Some(radio_button_size_style.icon_size) {
+                    icon.size = Some(radio_button_size_style.icon_size);
+                }
+                if icon.color != Some(caption_color) {
+                    icon.color = Some(caption_color);
+                }
+            }
+        }
+    }
+}
+
+// Merge the borders of adjacent segmented radios and keep the radius on the outer corners
+// only. Runs after `update_radio_button_visuals`, which resets the border and radius.
+pub fn update_segmented_radio_group_styles(
+    q_group: Query<(Entity, &StyledRadioGroup)>,
+    mut q_radio: Query<(&StyledRadioButton, &mut Node, &mut BorderRadius), With<CoreRadio>>,
+    q_children: Query<&Children>,
+    q_parent: Query<&ChildOf>,
+    q_radio_group: Query<(), With<CoreRadioGroup>>,
+) {
+    for (entity, group) in q_group.iter() {
+        let segments: Vec<Entity> =
+            radio_group_members(entity, &q_children, &q_parent, &q_radio_group, |radio| {
+                q_radio.get(radio).is_ok_and(|(radio_button, _, _)| {
+                    radio_button.variant == RadioButtonVariant::Segmented
+                })
+            });
+        let last = segments.len().saturating_sub(1);
+
+        for (index, segment) in segments.iter().enumerate() {
+            let Ok((_, mut node, mut border_radius)) = q_radio.get_mut(*segment) else {
+                continue;
             };
+            let is_first = index == 0;
+            let is_last = index == last;
+
+            match group.direction {
+                RadioButtonDirection::Horizontal => {
+                    if !is_first {
+                        border_radius.top_left = Val::ZERO;
+                        border_radius.bottom_left = Val::ZERO;
+                        node.border.left = Val::ZERO;
+                    }
+                    if !is_last {
+                        border_radius.top_right = Val::ZERO;
+                        border_radius.bottom_right = Val::ZERO;
+                    }
+                }
+                RadioButtonDirection::Vertical => {
+                    if !is_first {
+                        border_radius.top_left = Val::ZERO;
+                        border_radius.top_right = Val::ZERO;
+                        node.border.top = Val::ZERO;
+                    }
+                    if !is_last {
+                        border_radius.bottom_left = Val::ZERO;
+                        border_radius.bottom_right = Val::ZERO;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Keep the core radio in sync with `StyledRadioButton`, which is the source of truth.
+// `Checked` set directly, e.g. by the group selection, is copied back.
+#[allow(clippy::type_complexity)]
+pub fn sync_radio_button_state(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut StyledRadioButton,
+        Ref<Checked>,
+        &RadioValue,
+        &AccessibleName,
+    )>,
+) {
+    for (entity, mut radio_button, checked, radio_value, name) in query.iter_mut() {
+        if !radio_button.is_changed() {
+            if checked.is_changed() && checked.0 != radio_button.checked {
+                radio_button.bypass_change_detection().checked = checked.0;
+            }
+            continue;
+        }
 
-            bg_color.0 = new_bg;
-            border_color.0 = new_border_color;
+        if checked.0 != radio_button.checked {
+            commands
+                .entity(entity)
+                .insert(Checked(radio_button.checked));
+        }
+        if radio_value.0 != radio_button.value {
+            commands
+                .entity(entity)
+                .insert(RadioValue(radio_button.value.clone()));
         }
 
-        // Caption node
-        if let Ok(mut caption_color) = q_caption_text.get_mut(*caption_id) {
-            caption_color.0 = radio_button_style.caption_color;
+        let caption = radio_button.caption.clone().unwrap_or_default();
+        if name.0 != caption {
+            commands.entity(entity).insert(AccessibleName(caption));
         }
     }
 }
 
+// Radios belonging to `group`, at any depth below it but not inside a nested group.
+pub fn radio_group_members(
+    group: Entity,
+    q_children: &Query<&Children>,
+    q_parent: &Query<&ChildOf>,
+    q_radio_group: &Query<(), With<CoreRadioGroup>>,
+    is_radio: impl Fn(Entity) -> bool,
+) -> Vec<Entity> {
+    q_children
+        .iter_descendants(group)
+        .filter(|descendant| is_radio(*descendant))
+        .filter(|radio| {
+            q_parent
+                .iter_ancestors(*radio)
+                .find(|ancestor| q_radio_group.contains(*ancestor))
+                == Some(group)
+        })
+        .collect()
+}
+
+// Emit `WidgetSelected` for every selection. With an `on_change` callback the group is
+// controlled and the callback decides the checked radio, otherwise it is applied here.
+// Groups with a `RadioGroup<T>` are left to `observe_typed_radio_group_selection`, which
+// emits the typed `WidgetSelected<T>` instead.
+#[allow(clippy::type_complexity)]
 pub fn observe_radio_group_selection(
     mut trigger: Trigger<ValueChange<Entity>>,
-    q_radio_group: Query<&Children, With<CoreRadioGroup>>,
-    q_radio: Query<(&ChildOf, &RadioValue), With<CoreRadio>>,
+    q_styled_group: Query<(Option<&StyledRadioGroup>, Has<TypedRadioGroup>), With<CoreRadioGroup>>,
+    q_radio: Query<(&RadioValue, &Checked, Has<InteractionDisabled>), With<CoreRadio>>,
+    q_children: Query<&Children>,
+    q_parent: Query<&ChildOf>,
+    q_radio_group: Query<(), With<CoreRadioGroup>>,
     mut commands: Commands,
 ) {
     trigger.propagate(false);
-    if q_radio_group.contains(trigger.target()) {
-        let selected_entity = trigger.event().0;
-        let (child_of, radio_value) = q_radio.get(selected_entity).unwrap();
-        let group_children = q_radio_group.get(child_of.parent()).unwrap();
-        for radio_child in group_children.iter() {
-            if let Ok((_, value)) = q_radio.get(radio_child) {
-                commands
-                    .entity(radio_child)
-                    .insert(Checked(value.0 == radio_value.0));
+    let entity = trigger.target();
+    let Ok((styled_group, is_typed)) = q_styled_group.get(entity) else {
+        return;
+    };
+    let selected_entity = trigger.event().0;
+    let Ok((selected_value, _, is_disabled)) = q_radio.get(selected_entity) else {
+        return;
+    };
+    if is_disabled || styled_group.is_some_and(|group| group.disabled) {
+        return;
+    }
+    if is_typed {
+        return;
+    }
+
+    let members = radio_group_members(entity, &q_children, &q_parent, &q_radio_group, |radio| {
+        q_radio.contains(radio)
+    });
+    let old = members
+        .iter()
+        .filter_map(|radio| q_radio.get(*radio).ok())
+        .find(|(_, checked, _)| checked.0)
+        .map(|(value, _, _)| value.0.clone());
+    commands.trigger_targets(
+        WidgetSelected {
+            entity,
+            selected: selected_entity,
+            old,
+            new: selected_value.0.clone(),
+        },
+        entity,
+    );
+
+    if let Some(system_id) = styled_group.and_then(|group| group.on_change) {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, selected_entity);
+        return;
+    }
+
+    for radio in members {
+        commands
+            .entity(radio)
+            .insert(Checked(radio == selected_entity));
+    }
+}
+
+// Mirror the group's disabled and required flags, and check the first enabled radio of a
+// required group that has no selection.
+#[allow(clippy::type_complexity)]
+pub fn sync_radio_group_state(
+    mut commands: Commands,
+    mut q_group: Query<(
+        Entity,
+        &StyledRadioGroup,
+        &mut Fieldset,
+        Option<&mut AccessibilityNode>,
+    )>,
+    q_radio: Query<(&Checked, Has<InteractionDisabled>), With<CoreRadio>>,
+    q_children: Query<&Children>,
+    q_parent: Query<&ChildOf>,
+    q_radio_group: Query<(), With<CoreRadioGroup>>,
+) {
+    for (entity, group, mut fieldset, accessibility) in q_group.iter_mut() {
+        if fieldset.disabled != group.disabled {
+            fieldset.disabled = group.disabled;
+        }
+
+        if let Some(mut accessibility) = accessibility {
+            if accessibility.is_required() != group.required {
+                if group.required {
+                    accessibility.set_required();
+                } else {
+                    accessibility.clear_required();
+                }
+            }
+            if accessibility.is_disabled() != group.disabled {
+                if group.disabled {
+                    accessibility.set_disabled();
+                } else {
+                    accessibility.clear_disabled();
+                }
             }
         }
+
+        if !group.required {
+            continue;
+        }
+        let members =
+            radio_group_members(entity, &q_children, &q_parent, &q_radio_group, |radio| {
+                q_radio.contains(radio)
+            });
+        let has_selection = members
+            .iter()
+            .any(|radio| q_radio.get(*radio).is_ok_and(|(checked, _)| checked.0));
+        if has_selection {
+            continue;
+        }
+        if let Some(first_enabled) = members
+            .iter()
+            .find(|radio| q_radio.get(**radio).is_ok_and(|(_, disabled)| !disabled))
+        {
+            commands.entity(*first_enabled).insert(Checked(true));
+        }
     }
-}
\ No newline at end of file
+}
+
+// Check the radio matching a changed `RadioGroup` value, or copy the checked radio's value
+// back into the group when the radios changed.
+#[allow(clippy::type_complexity)]
+pub fn sync_radio_group_value<T: RadioGroupValue>(
+    mut commands: Commands,
+    mut q_group: Query<(Entity, &mut RadioGroup<T>)>,
+    q_option: Query<(&RadioOption<T>, Ref<Checked>)>,
+    q_children: Query<&Children>,
+    q_parent: Query<&ChildOf>,
+    q_radio_group: Query<(), With<CoreRadioGroup>>,
+) {
+    for (entity, mut group) in q_group.iter_mut() {
+        let members =
+            radio_group_members(entity, &q_children, &q_parent, &q_radio_group, |radio| {
+                q_option.contains(radio)
+            });
+
+        if group.is_changed() {
+            for radio in members {
+                if let Ok((option, checked)) = q_option.get(radio) {
+                    let is_selected = group.value.as_ref() == Some(&option.0);
+                    if checked.0 != is_selected {
+                        commands.entity(radio).insert(Checked(is_selected));
+                    }
+                }
+            }
+            continue;
+        }
+
+        let radios_changed = members.iter().any(|radio| {
+            q_option
+                .get(*radio)
+                .is_ok_and(|(_, checked)| checked.is_changed())
+        });
+        if radios_changed {
+            let value = members
+                .iter()
+                .filter_map(|radio| q_option.get(*radio).ok())
+                .find(|(_, checked)| checked.0)
+                .map(|(option, _)| option.0.clone());
+            if group.value != value {
+                group.bypass_change_detection().value = value;
+            }
+        }
+    }
+}
+
+// Emit the typed `WidgetSelected` and apply the selection to the group's value, or hand it
+// to the `on_change` callback of a controlled group.
+pub fn observe_typed_radio_group_selection<T: RadioGroupValue>(
+    trigger: Trigger<ValueChange<Entity>>,
+    mut q_group: Query<(&mut RadioGroup<T>, Option<&StyledRadioGroup>)>,
+    q_option: Query<&RadioOption<T>, Without<InteractionDisabled>>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((mut group, styled_group)) = q_group.get_mut(entity) else {
+        return;
+    };
+    if styled_group.is_some_and(|styled_group| styled_group.disabled) {
+        return;
+    }
+    let selected_entity = trigger.event().0;
+    let Ok(option) = q_option.get(selected_entity) else {
+        return;
+    };
+
+    commands.trigger_targets(
+        WidgetSelected {
+            entity,
+            selected: selected_entity,
+            old: group.value.clone(),
+            new: option.0.clone(),
+        },
+        entity,
+    );
+
+    if let Some(system_id) = group.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, option.0.clone());
+    } else if group.value.as_ref() != Some(&option.0) {
+        group.value = Some(option.0.clone());
+    }
+}
+
+// Only one radio of a group is reachable with Tab: the focused one, else the checked one,
+// else the first enabled one.
+#[allow(clippy::type_complexity)]
+pub fn update_radio_group_tab_index(
+    focus: Res<InputFocus>,
+    q_group: Query<Entity, With<StyledRadioGroup>>,
+    q_checked: Query<&Checked, With<CoreRadio>>,
+    mut q_radio: Query<(&mut TabIndex, Has<InteractionDisabled>), With<CoreRadio>>,
+    q_children: Query<&Children>,
+    q_parent: Query<&ChildOf>,
+    q_radio_group: Query<(), With<CoreRadioGroup>>,
+) {
+    for group in q_group.iter() {
+        let radios = radio_group_members(group, &q_children, &q_parent, &q_radio_group, |radio| {
+            q_radio.contains(radio)
+        });
+        let checked = radios
+            .iter()
+            .copied()
+            .find(|radio| q_checked.get(*radio).is_ok_and(|checked| checked.0));
+
+        update_roving_tab_index(&radios, &focus, checked, &mut q_radio);
+    }
+}
+
+// Move focus and selection between radios with the arrow keys of the group's direction,
+// Home and End, wrapping around and skipping disabled radios. Keys are handled on the focused
+// radio and stop there, so `CoreRadioGroup` doesn't move the selection a second time.
+#[allow(clippy::too_many_arguments)]
+pub fn on_radio_group_key_input(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    q_group: Query<&StyledRadioGroup>,
+    q_radio: Query<Has<InteractionDisabled>, With<CoreRadio>>,
+    q_children: Query<&Children>,
+    q_parent: Query<&ChildOf>,
+    q_radio_group: Query<(), With<CoreRadioGroup>>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+    mut commands: Commands,
+) {
+    let radio = trigger.target();
+    if !q_radio.contains(radio) {
+        return;
+    }
+    let Some(entity) = q_parent
+        .iter_ancestors(radio)
+        .find(|ancestor| q_radio_group.contains(*ancestor))
+    else {
+        return;
+    };
+    let Ok(group) = q_group.get(entity) else {
+        return;
+    };
+    let event = &trigger.event().input;
+    if event.state != ButtonState::Pressed
+        || !matches!(
+            event.key_code,
+            KeyCode::ArrowUp
+                | KeyCode::ArrowDown
+                | KeyCode::ArrowLeft
+                | KeyCode::ArrowRight
+                | KeyCode::Home
+                | KeyCode::End
+        )
+    {
+        return;
+    }
+    trigger.propagate(false);
+
+    let members = radio_group_members(entity, &q_children, &q_parent, &q_radio_group, |radio| {
+        q_radio.contains(radio)
+    });
+    if let Some(next) = move_roving_focus(
+        event.key_code,
+        group.direction == RadioButtonDirection::Vertical,
+        &members,
+        |radio| q_radio.get(radio).is_ok_and(|disabled| !disabled),
+        &mut focus,
+        &mut focus_visible,
+    ) {
+        commands.trigger_targets(ValueChange(next), entity);
+    }
+}
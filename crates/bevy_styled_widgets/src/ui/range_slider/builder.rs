@@ -0,0 +1,273 @@
+use accesskit::Role;
+use bevy::{
+    a11y::AccessibilityNode, ecs::system::SystemId, input_focus::tab_navigation::TabIndex,
+    prelude::*, window::SystemCursorIcon, winit::cursor::CursorIcon,
+};
+use bevy_additional_core_widgets::{CoreRangeSlider, CoreRangeSliderThumb, RangeThumb};
+use bevy_core_widgets::hover::Hovering;
+
+use crate::themes::ThemeManager;
+use crate::ui::slider::{AccessibleName, SliderOrientation, SliderSize, Track};
+
+use super::{RangeSliderFill, RangeSliderThumb, StyledRangeSlider};
+
+#[derive(Default)]
+pub struct RangeSliderBuilder {
+    min: f32,
+    max: f32,
+    value: (f32, f32),
+    min_gap: f32,
+    push: bool,
+    step: Option<f32>,
+    page_step: Option<f32>,
+    on_change: Option<SystemId<In<(f32, f32)>>>,
+    track_color: Option<Color>,
+    fill_color: Option<Color>,
+    thumb_color: Option<Color>,
+    hovered_thumb_color: Option<Color>,
+    size: Option<SliderSize>,
+    orientation: SliderOrientation,
+    disabled: bool,
+}
+
+impl RangeSliderBuilder {
+    pub fn on_change(mut self, system_id: SystemId<In<(f32, f32)>>) -> Self {
+        self.on_change = Some(system_id);
+        self
+    }
+
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn value(mut self, low: f32, high: f32) -> Self {
+        self.value = (low, high);
+        self
+    }
+
+    pub fn min_gap(mut self, min_gap: f32) -> Self {
+        self.min_gap = min_gap;
+        self
+    }
+
+    pub fn push(mut self) -> Self {
+        self.push = true;
+        self
+    }
+
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = Some(page_step);
+        self
+    }
+
+    pub fn track_color(mut self, color: Color) -> Self {
+        self.track_color = Some(color);
+        self
+    }
+
+    pub fn fill_color(mut self, color: Color) -> Self {
+        self.fill_color = Some(color);
+        self
+    }
+
+    pub fn thumb_color(mut self, color: Color) -> Self {
+        self.thumb_color = Some(color);
+        self
+    }
+
+    pub fn hovered_thumb_color(mut self, color: Color) -> Self {
+        self.hovered_thumb_color = Some(color);
+        self
+    }
+
+    pub fn size(mut self, size: SliderSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn orientation(mut self, orientation: SliderOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn vertical(mut self) -> Self {
+        self.orientation = SliderOrientation::Vertical;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let theme_manager = ThemeManager::default();
+        let slider_styles = theme_manager.styles.slider.clone();
+        let slider_size_styles = theme_manager.styles.slider_sizes.clone();
+        let slider_size_style = match self.size.unwrap_or_default() {
+            SliderSize::XSmall => slider_size_styles.xsmall,
+            SliderSize::Small => slider_size_styles.small,
+            SliderSize::Medium => slider_size_styles.medium,
+            SliderSize::Large => slider_size_styles.large,
+            SliderSize::XLarge => slider_size_styles.xlarge,
+        };
+        let thumb_size = slider_size_style.thumb_size;
+        let thickness = slider_size_style.track_thickness;
+        let vertical = self.orientation == SliderOrientation::Vertical;
+        let increment = self.step.unwrap_or(1.0);
+
+        let thumb_color = self.thumb_color.unwrap_or(slider_styles.thumb_color);
+        let thumb = |thumb: RangeThumb, name: &str| {
+            (
+                Node {
+                    display: Display::Flex,
+                    width: Val::Px(thumb_size),
+                    height: Val::Px(thumb_size),
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                Name::new(name.to_string()),
+                CoreRangeSliderThumb(thumb),
+                AccessibleName(name.to_string()),
+                RangeSliderThumb { size: self.size },
+                Hovering::default(),
+                // Disabled thumbs are left out of the tab order
+                TabIndex(if self.disabled { -1 } else { 0 }),
+                BorderRadius::all(Val::Px(slider_size_style.thumb_corner_radius)),
+                BackgroundColor(thumb_color),
+            )
+        };
+
+        (
+            Node {
+                display: Display::Flex,
+                flex_direction: if vertical {
+                    FlexDirection::Row
+                } else {
+                    FlexDirection::Column
+                },
+                justify_content: JustifyContent::Center,
+                align_self: AlignSelf::Stretch,
+                align_items: AlignItems::Stretch,
+                justify_items: JustifyItems::Center,
+                width: if vertical {
+                    Val::Px(thumb_size)
+                } else {
+                    Val::Percent(100.0)
+                },
+                height: if vertical {
+                    Val::Percent(100.0)
+                } else {
+                    Val::Px(thumb_size)
+                },
+                ..default()
+            },
+            Name::new("Range Slider"),
+            AccessibilityNode(accesskit::Node::new(Role::Group)),
+            AccessibleName("Range Slider".to_string()),
+            CursorIcon::System(SystemCursorIcon::Pointer),
+            StyledRangeSlider {
+                min: self.min,
+                max: self.max,
+                value: self.value,
+                min_gap: self.min_gap,
+                push: self.push,
+                step: self.step,
+                page_step: self.page_step,
+                on_change: self.on_change,
+                track_color: self.track_color,
+                fill_color: self.fill_color,
+                thumb_color: self.thumb_color,
+                hovered_thumb_color: self.hovered_thumb_color,
+                size: self.size,
+                orientation: self.orientation,
+                disabled: self.disabled,
+            },
+            // `on_change` is run by the styled observer, so the core widget triggers `ValueChange`
+            CoreRangeSlider {
+                min: self.min,
+                max: self.max,
+                low: self.value.0,
+                high: self.value.1,
+                min_gap: self.min_gap,
+                push: self.push,
+                step: self.step,
+                increment,
+                page_increment: self.page_step.unwrap_or(increment * 10.0),
+                thumb_size,
+                vertical,
+                on_change: None,
+            },
+            Children::spawn((
+                // Slider background rail
+                Spawn((
+                    Node {
+                        width: if vertical {
+                            Val::Px(thickness)
+                        } else {
+                            Val::Auto
+                        },
+                        height: if vertical {
+                            Val::Auto
+                        } else {
+                            Val::Px(thickness)
+                        },
+                        ..default()
+                    },
+                    Track,
+                    BackgroundColor(self.track_color.unwrap_or(slider_styles.track_color)),
+                    BorderRadius::all(Val::Px(slider_size_style.track_corner_radius)),
+                    // The thumb centers travel between half a thumb from either end of the rail
+                    children![(
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(if vertical { 0.0 } else { thumb_size * 0.5 }),
+                            right: Val::Px(if vertical { 0.0 } else { thumb_size * 0.5 }),
+                            top: Val::Px(if vertical { thumb_size * 0.5 } else { 0.0 }),
+                            bottom: Val::Px(if vertical { thumb_size * 0.5 } else { 0.0 }),
+                            ..default()
+                        },
+                        children![(
+                            // Fill between the thumbs, placed by the slider's range
+                            Node {
+                                position_type: PositionType::Absolute,
+                                ..default()
+                            },
+                            RangeSliderFill,
+                            BorderRadius::all(Val::Px(slider_size_style.track_corner_radius)),
+                            BackgroundColor(self.fill_color.unwrap_or(slider_styles.fill_color)),
+                        )],
+                    )],
+                )),
+                // Invisible track, shorter than the slider by the thumb size, to place the
+                // thumbs with percentages.
+                Spawn((
+                    Node {
+                        display: Display::Flex,
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.0),
+                        right: Val::Px(if vertical { 0.0 } else { thumb_size }),
+                        top: Val::Px(0.0),
+                        bottom: Val::Px(if vertical { thumb_size } else { 0.0 }),
+                        ..default()
+                    },
+                    Children::spawn((
+                        Spawn(thumb(RangeThumb::Low, "Minimum")),
+                        Spawn(thumb(RangeThumb::High, "Maximum")),
+                    )),
+                )),
+            )),
+        )
+    }
+}
@@ -0,0 +1,49 @@
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+
+use crate::ui::slider::{SliderOrientation, SliderSize};
+
+/// A slider selecting a range with two thumbs, styled like [`StyledSlider`].
+///
+/// [`StyledSlider`]: crate::ui::slider::StyledSlider
+#[derive(Component, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct StyledRangeSlider {
+    pub min: f32,
+    pub max: f32,
+    /// The low and the high end of the range.
+    pub value: (f32, f32),
+    /// Smallest distance kept between the two thumbs.
+    pub min_gap: f32,
+    /// A thumb moved into the other one pushes it along, instead of stopping at it.
+    pub push: bool,
+    /// Values snap to multiples of `step` above `min`, it is also the arrow key increment.
+    pub step: Option<f32>,
+    /// Increment of PageUp and PageDown, ten arrow key increments by default.
+    pub page_step: Option<f32>,
+    pub track_color: Option<Color>,
+    pub fill_color: Option<Color>,
+    pub thumb_color: Option<Color>,
+    pub hovered_thumb_color: Option<Color>,
+    pub size: Option<SliderSize>,
+    pub orientation: SliderOrientation,
+    pub disabled: bool,
+    #[reflect(ignore)]
+    pub on_change: Option<SystemId<In<(f32, f32)>>>,
+}
+
+impl StyledRangeSlider {
+    pub fn builder() -> super::builder::RangeSliderBuilder {
+        super::builder::RangeSliderBuilder::default()
+    }
+}
+
+/// A thumb of a range slider. Each thumb takes focus on its own.
+#[derive(Component, Default)]
+pub struct RangeSliderThumb {
+    pub size: Option<SliderSize>,
+}
+
+/// The part of the track between the two thumbs.
+#[derive(Component)]
+pub struct RangeSliderFill;
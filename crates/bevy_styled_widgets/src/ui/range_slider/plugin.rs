@@ -0,0 +1,25 @@
+use bevy::prelude::*;
+
+use super::{
+    StyledRangeSlider, on_range_slider_changed, sync_range_slider_state,
+    sync_range_slider_thumbs_disabled, update_range_slider_layout, update_range_slider_visuals,
+};
+use crate::ui::disabled::sync_disabled;
+
+pub struct StyledRangeSliderPlugin;
+impl Plugin for StyledRangeSliderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_range_slider_changed);
+        app.add_systems(
+            Update,
+            (
+                sync_range_slider_state,
+                sync_disabled::<StyledRangeSlider>,
+                sync_range_slider_thumbs_disabled,
+                update_range_slider_layout,
+                update_range_slider_visuals,
+            )
+                .chain(),
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use bevy::{a11y::AccessibilityNode, input_focus::tab_navigation::TabIndex, prelude::*};
+use bevy_additional_core_widgets::{
+    CoreRangeSlider, CoreRangeSliderDrag, CoreRangeSliderThumb, RangeThumb,
+};
+use bevy_core_widgets::{InteractionDisabled, ValueChange, hover::Hovering};
+
+use crate::{
+    themes::ThemeManager,
+    ui::{
+        events::WidgetChanged,
+        slider::{SliderOrientation, SliderSize, Track},
+    },
+};
+
+use super::{RangeSliderFill, RangeSliderThumb, StyledRangeSlider};
+
+// Emit `WidgetChanged` for every change of the range. With an `on_change` callback the slider
+// is controlled and the callback decides the new range, otherwise it is applied here.
+pub fn on_range_slider_changed(
+    mut trigger: Trigger<ValueChange<(f32, f32)>>,
+    mut query: Query<&mut StyledRangeSlider>,
+    mut commands: Commands,
+) {
+    trigger.propagate(false);
+    let entity = trigger.target();
+
+    let Ok(mut range_slider) = query.get_mut(entity) else {
+        return;
+    };
+    let value = trigger.event().0;
+    if value == range_slider.value {
+        return;
+    }
+
+    commands.trigger_targets(
+        WidgetChanged {
+            entity,
+            old: range_slider.value,
+            new: value,
+        },
+        entity,
+    );
+
+    if let Some(system_id) = range_slider.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, value);
+    } else {
+        range_slider.value = value;
+    }
+}
+
+// Copy the styled range slider's state into the core range slider.
+pub fn sync_range_slider_state(
+    theme_manager: Res<ThemeManager>,
+    mut query: Query<(&StyledRangeSlider, &mut CoreRangeSlider), Changed<StyledRangeSlider>>,
+) {
+    let slider_size_styles = &theme_manager.styles.slider_sizes;
+    for (range_slider, mut slider) in query.iter_mut() {
+        let thumb_size = match range_slider.size.unwrap_or_default() {
+            SliderSize::XSmall => slider_size_styles.xsmall.thumb_size,
+            SliderSize::Small => slider_size_styles.small.thumb_size,
+            SliderSize::Medium => slider_size_styles.medium.thumb_size,
+            SliderSize::Large => slider_size_styles.large.thumb_size,
+            SliderSize::XLarge => slider_size_styles.xlarge.thumb_size,
+        };
+        let increment = range_slider.step.unwrap_or(1.0);
+
+        slider.min = range_slider.min;
+        slider.max = range_slider.max;
+        (slider.low, slider.high) = range_slider.value;
+        slider.min_gap = range_slider.min_gap;
+        slider.push = range_slider.push;
+        slider.step = range_slider.step;
+        slider.increment = increment;
+        slider.page_increment = range_slider.page_step.unwrap_or(increment * 10.0);
+        slider.thumb_size = thumb_size;
+        slider.vertical = range_slider.orientation == SliderOrientation::Vertical;
+    }
+}
+
+// `sync_disabled` only marks the range slider itself. Take disabled thumbs out of the tab
+// order and mark their accessibility nodes, each thumb is a slider of its own.
+pub fn sync_range_slider_thumbs_disabled(
+    q_slider: Query<(Entity, Has<InteractionDisabled>), With<StyledRangeSlider>>,
+    q_children: Query<&Children>,
+    mut q_thumb: Query<(&mut TabIndex, &mut AccessibilityNode), With<CoreRangeSliderThumb>>,
+) {
+    for (entity, disabled) in q_slider.iter() {
+        for descendant in q_children.iter_descendants(entity) {
+            let Ok((mut tab_index, mut accessibility)) = q_thumb.get_mut(descendant) else {
+                continue;
+            };
+            let index = if disabled { -1 } else { 0 };
+            if tab_index.0 != index {
+                tab_index.0 = index;
+            }
+            if accessibility.is_disabled() != disabled {
+                if disabled {
+                    accessibility.set_disabled();
+                } else {
+                    accessibility.clear_disabled();
+                }
+            }
+        }
+    }
+}
+
+// Place the thumbs and the fill by the range, and color the rail, fill and thumbs.
+#[allow(clippy::type_complexity)]
+pub fn update_range_slider_visuals(
+    theme_manager: Res<ThemeManager>,
+    q_slider: Query<(
+        Entity,
+        &StyledRangeSlider,
+        &CoreRangeSlider,
+        &CoreRangeSliderDrag,
+        Has<InteractionDisabled>,
+    )>,
+    q_children: Query<&Children>,
+    mut q_track: Query<
+        &mut BackgroundColor,
+        (
+            With<Track>,
+            Without<RangeSliderFill>,
+            Without<RangeSliderThumb>,
+        ),
+    >,
+    mut q_fill: Query<
+        (&mut Node, &mut BackgroundColor),
+        (With<RangeSliderFill>, Without<RangeSliderThumb>),
+    >,
+    mut q_thumb: Query<
+        (
+            &CoreRangeSliderThumb,
+            &Hovering,
+            &mut Node,
+            &mut BackgroundColor,
+        ),
+        (With<RangeSliderThumb>, Without<RangeSliderFill>),
+    >,
+) {
+    let slider_styles = &theme_manager.styles.slider;
+
+    for (entity, range_slider, slider, drag, is_disabled) in q_slider.iter() {
+        let vertical = range_slider.orientation == SliderOrientation::Vertical;
+        let track_color = range_slider
+            .track_color
+            .unwrap_or(slider_styles.track_color);
+        let fill_color = range_slider.fill_color.unwrap_or(slider_styles.fill_color);
+        let thumb_color = range_slider
+            .thumb_color
+            .unwrap_or(slider_styles.thumb_color);
+        let (fill_color, thumb_color) = if is_disabled {
+            (fill_color.with_alpha(0.5), thumb_color.with_alpha(0.5))
+        } else {
+            (fill_color, thumb_color)
+        };
+
+        let low = slider.fraction(slider.low) * 100.0;
+        let high = slider.fraction(slider.high) * 100.0;
+
+        for descendant in q_children.iter_descendants(entity) {
+            if let Ok(mut bg) = q_track.get_mut(descendant) {
+                if bg.0 != track_color {
+                    bg.0 = track_color;
+                }
+            }
+
+            if let Ok((mut node, mut bg)) = q_fill.get_mut(descendant) {
+                let (left, right, top, bottom) = if vertical {
+                    (0.0, 0.0, 100.0 - high, low)
+                } else {
+                    (low, 100.0 - high, 0.0, 0.0)
+                };
+                if node.left != Val::Percent(left)
+                    || node.right != Val::Percent(right)
+                    || node.top != Val::Percent(top)
+                    || node.bottom != Val::Percent(bottom)
+                {
+                    node.left = Val::Percent(left);
+                    node.right = Val::Percent(right);
+                    node.top = Val::Percent(top);
+                    node.bottom = Val::Percent(bottom);
+                }
+                if bg.0 != fill_color {
+                    bg.0 = fill_color;
+                }
+            }
+
+            if let Ok((thumb, Hovering(is_hovering), mut node, mut bg)) =
+                q_thumb.get_mut(descendant)
+            {
+                let position = match thumb.0 {
+                    RangeThumb::Low => low,
+                    RangeThumb::High => high,
+                };
+                let (left, top) = if vertical {
+                    (Val::Px(0.0), Val::Percent(100.0 - position))
+                } else {
+                    (Val::Percent(position), Val::Px(0.0))
+                };
+                if node.left != left || node.top != top {
+                    node.left = left;
+                    node.top = top;
+                }
+
+                let color = if !is_disabled && (*is_hovering || drag.active == Some(thumb.0)) {
+                    range_slider
+                        .hovered_thumb_color
+                        .unwrap_or(thumb_color.with_alpha(0.9))
+                } else {
+                    thumb_color
+                };
+                if bg.0 != color {
+                    bg.0 = color;
+                }
+            }
+        }
+    }
+}
+
+// Apply the size and orientation of the range slider to its rail, insets and thumbs.
+#[allow(clippy::type_complexity)]
+pub fn update_range_slider_layout(
+    theme_manager: Res<ThemeManager>,
+    mut q_slider: Query<(Entity, &StyledRangeSlider, &mut Node), Changed<StyledRangeSlider>>,
+    q_children: Query<&Children>,
+    q_parent: Query<&ChildOf>,
+    mut q_rail: Query<(&mut Node, &mut BorderRadius), (With<Track>, Without<StyledRangeSlider>)>,
+    mut q_fill: Query<
+        &mut BorderRadius,
+        (
+            With<RangeSliderFill>,
+            Without<Track>,
+            Without<RangeSliderThumb>,
+        ),
+    >,
+    mut q_thumb: Query<
+        (&mut RangeSliderThumb, &mut Node, &mut BorderRadius),
+        (
+            Without<StyledRangeSlider>,
+            Without<Track>,
+            Without<RangeSliderFill>,
+        ),
+    >,
+    mut q_inset: Query<
+        &mut Node,
+        (
+            Without<StyledRangeSlider>,
+            Without<Track>,
+            Without<RangeSliderFill>,
+            Without<RangeSliderThumb>,
+        ),
+    >,
+) {
+    let slider_size_styles = &theme_manager.styles.slider_sizes;
+    for (entity, range_slider, mut node) in q_slider.iter_mut() {
+        let slider_size_style = match range_slider.size.unwrap_or_default() {
+            SliderSize::XSmall => &slider_size_styles.xsmall,
+            SliderSize::Small => &slider_size_styles.small,
+            SliderSize::Medium => &slider_size_styles.medium,
+            SliderSize::Large => &slider_size_styles.large,
+            SliderSize::XLarge => &slider_size_styles.xlarge,
+        };
+        let thumb_size = slider_size_style.thumb_size;
+        let thickness = slider_size_style.track_thickness;
+        let vertical = range_slider.orientation == SliderOrientation::Vertical;
+
+        let (direction, width, height) = if vertical {
+            (FlexDirection::Row, Val::Px(thumb_size), Val::Percent(100.0))
+        } else {
+            (
+                FlexDirection::Column,
+                Val::Percent(100.0),
+                Val::Px(thumb_size),
+            )
+        };
+        if node.flex_direction != direction || node.width != width || node.height != height {
+            node.flex_direction = direction;
+            node.width = width;
+            node.height = height;
+        }
+
+        for descendant in q_children.iter_descendants(entity) {
+            if let Ok((mut rail, mut radius)) = q_rail.get_mut(descendant) {
+                let (width, height) = if vertical {
+                    (Val::Px(thickness), Val::Auto)
+                } else {
+                    (Val::Auto, Val::Px(thickness))
+                };
+                if rail.width != width || rail.height != height {
+                    rail.width = width;
+                    rail.height = height;
+                }
+                *radius = BorderRadius::all(Val::Px(slider_size_style.track_corner_radius));
+            }
+
+            if let Ok(mut radius) = q_fill.get_mut(descendant) {
+                *radius = BorderRadius::all(Val::Px(slider_size_style.track_corner_radius));
+                // The fill's parent spans the travel of the thumb centers
+                if let Ok(mut inset) = q_parent
+                    .get(descendant)
+                    .and_then(|parent| q_inset.get_mut(parent.parent()))
+                {
+                    let (axis, cross) = (Val::Px(thumb_size * 0.5), Val::Px(0.0));
+                    (inset.left, inset.right, inset.top, inset.bottom) = if vertical {
+                        (cross, cross, axis, axis)
+                    } else {
+                        (axis, axis, cross, cross)
+                    };
+                }
+            }
+
+            if let Ok((mut thumb, mut thumb_node, mut radius)) = q_thumb.get_mut(descendant) {
+                thumb.size = range_slider.size;
+                thumb_node.width = Val::Px(thumb_size);
+                thumb_node.height = Val::Px(thumb_size);
+                *radius = BorderRadius::all(Val::Px(slider_size_style.thumb_corner_radius));
+                // The thumbs' parent is short by the thumb size along the slider's axis
+                if let Ok(mut inset) = q_parent
+                    .get(descendant)
+                    .and_then(|parent| q_inset.get_mut(parent.parent()))
+                {
+                    let (right, bottom) = if vertical {
+                        (Val::Px(0.0), Val::Px(thumb_size))
+                    } else {
+                        (Val::Px(thumb_size), Val::Px(0.0))
+                    };
+                    if inset.right != right || inset.bottom != bottom {
+                        inset.right = right;
+                        inset.bottom = bottom;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,18 @@
+use std::sync::Arc;
+
+use accesskit::Role;
 use bevy::{
-    ecs::system::SystemId, input_focus::tab_navigation::TabIndex, prelude::*,
-    window::SystemCursorIcon, winit::cursor::CursorIcon,
+    a11y::AccessibilityNode, ecs::system::SystemId, input_focus::tab_navigation::TabIndex,
+    prelude::*, window::SystemCursorIcon, winit::cursor::CursorIcon,
 };
 use bevy_core_widgets::{CoreSlider, hover::Hovering};
 
 use crate::themes::ThemeManager;
 
-use super::{StyledSlider, components::AccessibleName};
+use super::{
+    SliderFormat, SliderOrientation, SliderPointerDrag, SliderSize, SliderTickMarks, SliderTicks,
+    StyledSlider, components::AccessibleName,
+};
 
 #[derive(Component)]
 pub struct Track;
@@ -14,15 +20,34 @@ pub struct Track;
 #[derive(Component)]
 pub struct Thumb;
 
+/// The part of the track from the minimum up to the thumb.
+#[derive(Component)]
+pub struct SliderFill;
+
+/// The value shown next to the thumb, its child holds the bubble and the text.
+#[derive(Component)]
+pub struct SliderValueBubble;
+
 #[derive(Default)]
 pub struct SliderBuilder {
     min: f32,
     max: f32,
     value: f32,
+    step: Option<f32>,
+    page_step: Option<f32>,
+    precision: Option<u32>,
     on_change: Option<SystemId<In<f32>>>,
     track_color: Option<Color>,
+    fill_color: Option<Color>,
     thumb_color: Option<Color>,
     hovered_thumb_color: Option<Color>,
+    ticks: SliderTicks,
+    tick_labels: bool,
+    show_value: bool,
+    format: Option<SliderFormat>,
+    size: Option<SliderSize>,
+    orientation: SliderOrientation,
+    disabled: bool,
 }
 
 impl SliderBuilder {
@@ -46,11 +71,31 @@ impl SliderBuilder {
         self
     }
 
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    pub fn page_step(mut self, page_step: f32) -> Self {
+        self.page_step = Some(page_step);
+        self
+    }
+
+    pub fn precision(mut self, precision: u32) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
     pub fn track_color(mut self, color: Color) -> Self {
         self.track_color = Some(color);
         self
     }
 
+    pub fn fill_color(mut self, color: Color) -> Self {
+        self.fill_color = Some(color);
+        self
+    }
+
     pub fn thumb_color(mut self, color: Color) -> Self {
         self.thumb_color = Some(color);
         self
@@ -61,22 +106,88 @@ impl SliderBuilder {
         self
     }
 
+    pub fn ticks(mut self, ticks: SliderTicks) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    pub fn tick_labels(mut self) -> Self {
+        self.tick_labels = true;
+        self
+    }
+
+    pub fn show_value(mut self) -> Self {
+        self.show_value = true;
+        self
+    }
+
+    /// Formats the tick labels and the shown value.
+    pub fn format(mut self, format: impl Fn(f32) -> String + Send + Sync + 'static) -> Self {
+        self.format = Some(Arc::new(format));
+        self
+    }
+
+    pub fn size(mut self, size: SliderSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn orientation(mut self, orientation: SliderOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn vertical(mut self) -> Self {
+        self.orientation = SliderOrientation::Vertical;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
     pub fn build(self) -> impl Bundle {
         let theme_manager = ThemeManager::default();
         let slider_styles = theme_manager.styles.slider.clone();
+        let slider_size_styles = theme_manager.styles.slider_sizes.clone();
+        let slider_size_style = match self.size.unwrap_or_default() {
+            SliderSize::XSmall => slider_size_styles.xsmall,
+            SliderSize::Small => slider_size_styles.small,
+            SliderSize::Medium => slider_size_styles.medium,
+            SliderSize::Large => slider_size_styles.large,
+            SliderSize::XLarge => slider_size_styles.xlarge,
+        };
+        let thumb_size = slider_size_style.thumb_size;
+        let thickness = slider_size_style.track_thickness;
+        let vertical = self.orientation == SliderOrientation::Vertical;
+
         (
             Node {
                 display: Display::Flex,
-                flex_direction: FlexDirection::Column,
+                flex_direction: if vertical {
+                    FlexDirection::Row
+                } else {
+                    FlexDirection::Column
+                },
                 justify_content: JustifyContent::Center,
                 align_self: AlignSelf::Stretch,
                 align_items: AlignItems::Stretch,
                 justify_items: JustifyItems::Center,
-                height: Val::Px(12.0),
-                width: Val::Percent(100.0),
+                width: if vertical {
+                    Val::Px(thumb_size)
+                } else {
+                    Val::Percent(100.0)
+                },
+                height: if vertical {
+                    Val::Percent(100.0)
+                } else {
+                    Val::Px(thumb_size)
+                },
                 ..default()
             },
             Name::new("Slider"),
+            AccessibilityNode(accesskit::Node::new(Role::Slider)),
             AccessibleName("Slider".to_string()),
             Hovering::default(),
             CursorIcon::System(SystemCursorIcon::Pointer),
@@ -84,57 +195,147 @@ impl SliderBuilder {
                 min: self.min.clone(),
                 max: self.max.clone(),
                 value: self.value.clone(),
+                step: self.step,
+                page_step: self.page_step,
+                precision: self.precision,
                 on_change: self.on_change,
                 track_color: self.track_color,
+                fill_color: self.fill_color,
                 thumb_color: self.thumb_color,
                 hovered_thumb_color: self.hovered_thumb_color,
+                ticks: self.ticks,
+                tick_labels: self.tick_labels,
+                show_value: self.show_value,
+                format: self.format,
+                size: self.size,
+                orientation: self.orientation,
+                disabled: self.disabled,
             },
+            // `on_change` is run by the styled observer, so the core widget triggers `ValueChange`
             CoreSlider {
                 min: self.min,
                 max: self.max,
                 value: self.value,
-                on_change: self.on_change,
-                thumb_size: 12.0,
+                on_change: None,
+                thumb_size,
+                increment: self.step.unwrap_or(1.0),
                 ..default()
             },
+            SliderPointerDrag::default(),
             TabIndex(0),
             Children::spawn((
                 // Slider background rail
                 Spawn((
                     Node {
-                        height: Val::Px(6.0),
+                        width: if vertical {
+                            Val::Px(thickness)
+                        } else {
+                            Val::Auto
+                        },
+                        height: if vertical {
+                            Val::Auto
+                        } else {
+                            Val::Px(thickness)
+                        },
                         ..default()
                     },
                     Track,
                     BackgroundColor(self.track_color.unwrap_or(slider_styles.track_color)),
-                    BorderRadius::all(Val::Px(3.0)),
+                    BorderRadius::all(Val::Px(slider_size_style.track_corner_radius)),
+                    // The fill is measured like the thumb, on a rail short by the thumb size, and
+                    // reaches half a thumb further to the thumb's center.
+                    children![(
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(0.0),
+                            right: Val::Px(if vertical { 0.0 } else { thumb_size }),
+                            top: Val::Px(if vertical { thumb_size } else { 0.0 }),
+                            bottom: Val::Px(0.0),
+                            ..default()
+                        },
+                        children![(
+                            Node {
+                                position_type: PositionType::Absolute,
+                                box_sizing: BoxSizing::ContentBox,
+                                ..default()
+                            },
+                            SliderFill,
+                            BackgroundColor(self.fill_color.unwrap_or(slider_styles.fill_color)),
+                            BorderRadius::all(Val::Px(slider_size_style.track_corner_radius)),
+                        )],
+                    )],
+                )),
+                // Tick marks and their labels, outside of the slider
+                Spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    SliderTickMarks::default(),
                 )),
-                // Invisible track to allow absolute placement of thumb entity. This is narrower than
-                // the actual slider, which allows us to position the thumb entity using simple
-                // percentages, without having to measure the actual width of the slider thumb.
+                // Invisible track to allow absolute placement of thumb entity. This is shorter than
+                // the actual slider by the thumb size, which allows us to position the thumb entity
+                // using simple percentages, without having to measure the actual length of the slider.
                 Spawn((
                     Node {
                         display: Display::Flex,
                         position_type: PositionType::Absolute,
                         left: Val::Px(0.0),
-                        right: Val::Px(12.0), // Track is short by 12px to accommodate the thumb
+                        right: Val::Px(if vertical { 0.0 } else { thumb_size }),
                         top: Val::Px(0.0),
-                        bottom: Val::Px(0.0),
+                        bottom: Val::Px(if vertical { thumb_size } else { 0.0 }),
                         ..default()
                     },
                     children![(
                         // Thumb
                         Node {
                             display: Display::Flex,
-                            width: Val::Px(12.0),
-                            height: Val::Px(12.0),
+                            width: Val::Px(thumb_size),
+                            height: Val::Px(thumb_size),
                             position_type: PositionType::Absolute,
-                            left: Val::Percent(50.0), // This will be updated by the slider's value
+                            // This will be updated by the slider's value
+                            left: if vertical {
+                                Val::Auto
+                            } else {
+                                Val::Percent(50.0)
+                            },
+                            top: if vertical {
+                                Val::Percent(50.0)
+                            } else {
+                                Val::Auto
+                            },
                             ..default()
                         },
                         Thumb,
-                        BorderRadius::all(Val::Px(6.0)),
+                        BorderRadius::all(Val::Px(slider_size_style.thumb_corner_radius)),
                         BackgroundColor(self.thumb_color.unwrap_or(slider_styles.thumb_color)),
+                        children![(
+                            // Placed by the slider's orientation and shown while hovered or dragged
+                            Node {
+                                display: Display::None,
+                                position_type: PositionType::Absolute,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            SliderValueBubble,
+                            GlobalZIndex(1),
+                            children![(
+                                Node {
+                                    padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(slider_styles.bubble_background),
+                                BorderRadius::all(Val::Px(4.0)),
+                                children![(
+                                    Text::new(""),
+                                    TextFont {
+                                        font_size: slider_size_style.bubble_font_size,
+                                        ..default()
+                                    },
+                                    TextColor(slider_styles.bubble_text_color),
+                                )],
+                            )],
+                        )],
                     )],
                 )),
             )),
@@ -1,6 +1,9 @@
+use std::sync::Arc;
+
 use bevy::a11y::AccessibilityNode;
 use bevy::ecs::{component::HookContext, system::SystemId, world::DeferredWorld};
 use bevy::prelude::*;
+use bevy_additional_core_widgets::snap_to_step;
 
 #[derive(Component, Reflect)]
 #[reflect(from_reflect = false)]
@@ -8,9 +11,28 @@ pub struct StyledSlider {
     pub min: f32,
     pub max: f32,
     pub value: f32,
+    /// Values snap to multiples of `step` above `min`, it is also the arrow key increment.
+    pub step: Option<f32>,
+    /// Increment of PageUp and PageDown, ten arrow key increments by default.
+    pub page_step: Option<f32>,
+    /// Decimal places kept of a new value. Changes that round away don't reach `on_change`.
+    pub precision: Option<u32>,
     pub track_color: Option<Color>,
+    /// Part of the track from `min` up to the thumb.
+    pub fill_color: Option<Color>,
     pub thumb_color: Option<Color>,
     pub hovered_thumb_color: Option<Color>,
+    pub ticks: SliderTicks,
+    /// Label each tick mark with its formatted value.
+    pub tick_labels: bool,
+    /// Show the formatted value next to the thumb while it is hovered or dragged.
+    pub show_value: bool,
+    /// Formats the tick labels and the shown value.
+    #[reflect(ignore)]
+    pub format: Option<SliderFormat>,
+    pub size: Option<SliderSize>,
+    pub orientation: SliderOrientation,
+    pub disabled: bool,
     #[reflect(ignore)]
     pub on_change: Option<SystemId<In<f32>>>,
 }
@@ -19,6 +41,103 @@ impl StyledSlider {
     pub fn builder() -> super::builder::SliderBuilder {
         super::builder::SliderBuilder::default()
     }
+
+    /// Clamps `value` to the range and rounds it to `step` and `precision`. When `step` doesn't
+    /// divide the range, values past the last step snap to the nearer of it and `max`.
+    pub fn snap(&self, value: f32) -> f32 {
+        let mut value = snap_to_step(value, self.min, self.max, self.step);
+        if let Some(precision) = self.precision {
+            let factor = 10f32.powi(precision as i32);
+            value = (value * factor).round() / factor;
+        }
+        value
+    }
+
+    /// Formats `value` with `format`, or with `precision` decimal places.
+    pub fn format_value(&self, value: f32) -> String {
+        match (&self.format, self.precision) {
+            (Some(format), _) => format(value),
+            (None, Some(precision)) => format!("{:.*}", precision as usize, value),
+            (None, None) => format!("{}", (value * 100.0).round() / 100.0),
+        }
+    }
+
+    /// Values of the tick marks inside the range.
+    pub fn tick_values(&self) -> Vec<f32> {
+        let span = self.max - self.min;
+        match &self.ticks {
+            SliderTicks::None => Vec::new(),
+            SliderTicks::Steps => {
+                let step = self.step.unwrap_or(span / 10.0);
+                if step <= 0.0 || span <= 0.0 {
+                    return Vec::new();
+                }
+                let count = (span / step + 0.001).floor() as usize;
+                let mut values: Vec<f32> = (0..=count)
+                    .map(|index| self.min + index as f32 * step)
+                    .collect();
+                // The last step may fall short of the maximum
+                if span - count as f32 * step > step * 0.001 {
+                    values.push(self.max);
+                }
+                values
+            }
+            SliderTicks::Values(values) => values
+                .iter()
+                .copied()
+                .filter(|value| *value >= self.min && *value <= self.max)
+                .collect(),
+        }
+    }
+}
+
+/// Formats a slider value for display.
+pub type SliderFormat = Arc<dyn Fn(f32) -> String + Send + Sync>;
+
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub enum SliderTicks {
+    #[default]
+    None,
+    /// A tick at every step, or at every tenth of the range without a step.
+    Steps,
+    /// Ticks at the given values.
+    Values(Vec<f32>),
+}
+
+/// The tick marks of a slider, rebuilt when the ticks, labels or layout change.
+#[derive(Component, Default, PartialEq)]
+pub struct SliderTickMarks {
+    /// Position of each tick along the track, from 0.0 to 1.0, and its label.
+    pub marks: Vec<(f32, Option<String>)>,
+    pub size: SliderSize,
+    pub orientation: SliderOrientation,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SliderSize {
+    XSmall,
+    Small,
+    #[default]
+    Medium,
+    Large,
+    XLarge,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SliderOrientation {
+    #[default]
+    Horizontal,
+    /// The minimum is at the bottom and the maximum at the top.
+    Vertical,
+}
+
+/// Pointer state of a vertical slider. The core slider only measures horizontal movement, so
+/// vertical sliders track presses and drags themselves.
+#[derive(Component, Default)]
+pub struct SliderPointerDrag {
+    pub pressed: bool,
+    /// Value at the start of the drag.
+    pub origin: f32,
 }
 
 #[derive(Component, Default)]
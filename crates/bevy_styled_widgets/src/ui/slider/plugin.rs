@@ -1,12 +1,37 @@
 use bevy::prelude::*;
 
-use super::{change_slider_colors, on_thumb_changed, update_slider_thumb};
+use super::{
+    StyledSlider, change_slider_colors, on_slider_key_input, on_thumb_changed,
+    on_vertical_slider_cancel, on_vertical_slider_drag, on_vertical_slider_drag_end,
+    on_vertical_slider_pressed, on_vertical_slider_released, sync_slider_state,
+    update_slider_accessibility, update_slider_layout, update_slider_thumb, update_slider_ticks,
+    update_slider_value_bubble,
+};
+use crate::ui::disabled::sync_disabled;
 
 pub struct StyledSliderPlugin;
 impl Plugin for StyledSliderPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(on_thumb_changed);
-        app.add_systems(Update, update_slider_thumb);
+        app.add_observer(on_thumb_changed)
+            .add_observer(on_slider_key_input)
+            .add_observer(on_vertical_slider_pressed)
+            .add_observer(on_vertical_slider_drag)
+            .add_observer(on_vertical_slider_released)
+            .add_observer(on_vertical_slider_drag_end)
+            .add_observer(on_vertical_slider_cancel);
+        app.add_systems(
+            Update,
+            (
+                sync_slider_state,
+                sync_disabled::<StyledSlider>,
+                update_slider_layout,
+                update_slider_thumb,
+                update_slider_ticks,
+                update_slider_value_bubble,
+                update_slider_accessibility,
+            )
+                .chain(),
+        );
         app.add_systems(Update, change_slider_colors);
     }
 }
@@ -1,59 +1,279 @@
-use bevy::prelude::*;
-use bevy_core_widgets::{CoreSlider, SliderDragState, ValueChange, hover::Hovering};
+use bevy::{
+    a11y::AccessibilityNode,
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::FocusedInput,
+    prelude::*,
+};
+use bevy_core_widgets::{
+    CoreSlider, InteractionDisabled, SliderDragState, ValueChange, hover::Hovering,
+};
 
-use crate::themes::ThemeManager;
+use crate::{themes::ThemeManager, ui::events::WidgetChanged};
 
-use super::{StyledSlider, Thumb, Track};
+use super::{
+    SliderFill, SliderOrientation, SliderPointerDrag, SliderSize, SliderTickMarks,
+    SliderValueBubble, StyledSlider, Thumb, Track,
+};
 
+// Emit `WidgetChanged` for every change of the snapped value, changes within the step or
+// precision are dropped. With an `on_change` callback the slider is controlled and the
+// callback decides the new value, otherwise it is applied here.
 pub fn on_thumb_changed(
     mut trigger: Trigger<ValueChange<f32>>,
-    mut q_slider: Query<&mut CoreSlider>,
-    query: Query<&StyledSlider>,
+    mut query: Query<&mut StyledSlider>,
     mut commands: Commands,
 ) {
     trigger.propagate(false);
     let entity = trigger.target();
+
+    let Ok(mut styled_slider) = query.get_mut(entity) else {
+        return;
+    };
     let value = trigger.event().0;
+    // The core slider maps horizontal pointer positions, which may not even be finite on a
+    // narrow vertical slider. Vertical sliders handle the pointer and keys themselves.
+    if !value.is_finite() || styled_slider.orientation == SliderOrientation::Vertical {
+        return;
+    }
+    change_slider_value(entity, &mut styled_slider, value, &mut commands);
+}
+
+fn change_slider_value(
+    entity: Entity,
+    styled_slider: &mut Mut<StyledSlider>,
+    value: f32,
+    commands: &mut Commands,
+) {
+    let value = styled_slider.snap(value);
+    if value == styled_slider.value {
+        return;
+    }
+
+    commands.trigger_targets(
+        WidgetChanged {
+            entity,
+            old: styled_slider.value,
+            new: value,
+        },
+        entity,
+    );
 
-    if let Ok(mut slider) = q_slider.get_mut(trigger.target()) {
-        slider.set_value(trigger.event().0);
+    if let Some(system_id) = styled_slider.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, value);
+    } else {
+        styled_slider.value = value;
+    }
+}
+
+// Length the thumb can travel along a vertical slider, in logical pixels.
+fn vertical_travel(node: &ComputedNode, slider: &CoreSlider) -> f32 {
+    (node.size().y * node.inverse_scale_factor() - slider.thumb_size).max(1.0)
+}
+
+// Jump a vertical slider to the pressed position, the top of the track is the maximum.
+pub fn on_vertical_slider_pressed(
+    trigger: Trigger<Pointer<Pressed>>,
+    mut q_slider: Query<
+        (
+            &mut StyledSlider,
+            &mut SliderPointerDrag,
+            &CoreSlider,
+            &ComputedNode,
+            &GlobalTransform,
+        ),
+        Without<InteractionDisabled>,
+    >,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((mut styled_slider, mut pointer_drag, slider, node, transform)) =
+        q_slider.get_mut(entity)
+    else {
+        return;
+    };
+    if styled_slider.orientation != SliderOrientation::Vertical
+        || trigger.event().button != PointerButton::Primary
+    {
+        return;
+    }
+
+    let scale = node.inverse_scale_factor();
+    let top = (transform.translation().y - node.size().y * 0.5) * scale;
+    let offset = trigger.event().pointer_location.position.y - top - slider.thumb_size * 0.5;
+    let fraction = 1.0 - (offset / vertical_travel(node, slider)).clamp(0.0, 1.0);
+    let value = styled_slider.min + fraction * (styled_slider.max - styled_slider.min);
+
+    pointer_drag.pressed = true;
+    pointer_drag.origin = styled_slider.snap(value);
+    change_slider_value(entity, &mut styled_slider, value, &mut commands);
+}
+
+// Move a vertical slider by the vertical drag distance.
+pub fn on_vertical_slider_drag(
+    trigger: Trigger<Pointer<Drag>>,
+    mut q_slider: Query<
+        (
+            &mut StyledSlider,
+            &SliderPointerDrag,
+            &CoreSlider,
+            &ComputedNode,
+        ),
+        Without<InteractionDisabled>,
+    >,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((mut styled_slider, pointer_drag, slider, node)) = q_slider.get_mut(entity) else {
+        return;
+    };
+    if styled_slider.orientation != SliderOrientation::Vertical || !pointer_drag.pressed {
+        return;
+    }
+
+    let span = styled_slider.max - styled_slider.min;
+    let distance = trigger.event().distance.y;
+    let value = pointer_drag.origin - distance * span / vertical_travel(node, slider);
+    change_slider_value(entity, &mut styled_slider, value, &mut commands);
+}
+
+pub fn on_vertical_slider_released(
+    trigger: Trigger<Pointer<Released>>,
+    mut q_slider: Query<&mut SliderPointerDrag>,
+) {
+    if let Ok(mut pointer_drag) = q_slider.get_mut(trigger.target()) {
+        pointer_drag.pressed = false;
+    }
+}
+
+// The pointer may be released away from the slider, which ends the drag without `Released`.
+pub fn on_vertical_slider_drag_end(
+    trigger: Trigger<Pointer<DragEnd>>,
+    mut q_slider: Query<&mut SliderPointerDrag>,
+) {
+    if let Ok(mut pointer_drag) = q_slider.get_mut(trigger.target()) {
+        pointer_drag.pressed = false;
+    }
+}
+
+pub fn on_vertical_slider_cancel(
+    trigger: Trigger<Pointer<Cancel>>,
+    mut q_slider: Query<&mut SliderPointerDrag>,
+) {
+    if let Ok(mut pointer_drag) = q_slider.get_mut(trigger.target()) {
+        pointer_drag.pressed = false;
+    }
+}
+
+// Step with ArrowUp and ArrowDown, and page with PageUp and PageDown. The core slider
+// handles ArrowLeft, ArrowRight, Home and End of horizontal sliders, its changes are ignored
+// for vertical sliders, so they handle these keys here.
+pub fn on_slider_key_input(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    mut q_slider: Query<(&mut StyledSlider, &CoreSlider), Without<InteractionDisabled>>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let Ok((mut styled_slider, slider)) = q_slider.get_mut(entity) else {
+        return;
+    };
+    let event = &trigger.event().input;
+    if event.state != ButtonState::Pressed {
+        return;
     }
 
-    if let Ok(styled_slider) = query.get(entity) {
-        if let Some(system_id) = styled_slider.on_change {
-            // Defer the callback system using commands
-            commands.run_system_with(system_id, value);
+    let vertical = styled_slider.orientation == SliderOrientation::Vertical;
+    let page_step = styled_slider.page_step.unwrap_or(slider.increment * 10.0);
+    let value = match event.key_code {
+        KeyCode::ArrowUp => styled_slider.value + slider.increment,
+        KeyCode::ArrowDown => styled_slider.value - slider.increment,
+        KeyCode::PageUp => styled_slider.value + page_step,
+        KeyCode::PageDown => styled_slider.value - page_step,
+        KeyCode::ArrowRight if vertical => styled_slider.value + slider.increment,
+        KeyCode::ArrowLeft if vertical => styled_slider.value - slider.increment,
+        KeyCode::Home if vertical => styled_slider.min,
+        KeyCode::End if vertical => styled_slider.max,
+        _ => return,
+    };
+
+    trigger.propagate(false);
+    change_slider_value(entity, &mut styled_slider, value, &mut commands);
+}
+
+// Copy the styled slider's range and value into the core slider.
+pub fn sync_slider_state(
+    mut query: Query<(&StyledSlider, &mut CoreSlider), Changed<StyledSlider>>,
+) {
+    for (styled_slider, mut slider) in query.iter_mut() {
+        if slider.min != styled_slider.min {
+            slider.min = styled_slider.min;
+        }
+        if slider.max != styled_slider.max {
+            slider.max = styled_slider.max;
+        }
+        if slider.value != styled_slider.value {
+            slider.set_value(styled_slider.value);
+        }
+        if let Some(step) = styled_slider.step {
+            if slider.increment != step {
+                slider.increment = step;
+            }
         }
     }
 }
 
+// Expose the value, range and increments to accessibility.
+pub fn update_slider_accessibility(
+    mut query: Query<(&StyledSlider, &CoreSlider, &mut AccessibilityNode), Changed<CoreSlider>>,
+) {
+    for (styled_slider, slider, mut accessibility) in query.iter_mut() {
+        let page_step = styled_slider.page_step.unwrap_or(slider.increment * 10.0);
+        accessibility.set_numeric_value(slider.value.into());
+        accessibility.set_min_numeric_value(slider.min.into());
+        accessibility.set_max_numeric_value(slider.max.into());
+        accessibility.set_numeric_value_step(slider.increment.into());
+        accessibility.set_numeric_value_jump(page_step.into());
+    }
+}
+
 // Update the button's background color.
 #[allow(clippy::type_complexity)]
 pub fn update_slider_thumb(
     theme_manager: Res<ThemeManager>,
-    mut q_slider: Query<
-        (&CoreSlider, &SliderDragState, &Hovering, &Children),
-        (
-            With<StyledSlider>,
-            Or<(Added<StyledSlider>, Changed<Hovering>, Changed<CoreSlider>)>,
-        ),
-    >,
+    mut q_slider: Query<(
+        &StyledSlider,
+        &CoreSlider,
+        &SliderDragState,
+        &Hovering,
+        &Children,
+        Has<InteractionDisabled>,
+    )>,
     mut q_track: Query<&mut Children, Without<StyledSlider>>,
-    mut q_thumb: Query<
+    mut q_thumb: Query<(&mut BackgroundColor, &mut Node), (With<Thumb>, Without<StyledSlider>)>,
+    mut q_fill: Query<
         (&mut BackgroundColor, &mut Node),
-        (Without<StyledSlider>, Without<Children>),
+        (With<SliderFill>, Without<Thumb>, Without<StyledSlider>),
     >,
 ) {
     // Get styles from theme manager
     let slider_styles = theme_manager.styles.slider.clone();
 
-    for (slider_state, drag_state, Hovering(is_hovering), children) in q_slider.iter_mut() {
-        let color: Color = if *is_hovering || drag_state.dragging {
+    for (styled_slider, slider_state, drag_state, Hovering(is_hovering), children, is_disabled) in
+        q_slider.iter_mut()
+    {
+        let thumb_color = styled_slider
+            .thumb_color
+            .unwrap_or(slider_styles.thumb_color);
+        let color: Color = if is_disabled {
+            thumb_color.with_alpha(0.5)
+        } else if *is_hovering || drag_state.dragging {
             // If hovering, use a lighter color
-            slider_styles.thumb_color.with_alpha(0.9)
+            styled_slider
+                .hovered_thumb_color
+                .unwrap_or(thumb_color.with_alpha(0.9))
         } else {
             // Default color for the slider
-            slider_styles.thumb_color
+            thumb_color
         };
 
         let Some(track_id) = children.last() else {
@@ -79,9 +299,494 @@ pub fn update_slider_thumb(
             thumb_bg.0 = color;
         }
 
-        let thumb_position = Val::Percent(slider_state.thumb_position() * 100.0);
-        if node.left != thumb_position {
-            node.left = thumb_position;
+        match styled_slider.orientation {
+            SliderOrientation::Horizontal => {
+                let thumb_position = Val::Percent(slider_state.thumb_position() * 100.0);
+                if node.left != thumb_position {
+                    node.left = thumb_position;
+                }
+            }
+            SliderOrientation::Vertical => {
+                let thumb_position = Val::Percent((1.0 - slider_state.thumb_position()) * 100.0);
+                if node.top != thumb_position {
+                    node.top = thumb_position;
+                }
+            }
+        }
+
+        // The fill inside the rail follows the thumb
+        let fill_id = children
+            .first()
+            .and_then(|rail_id| q_track.get(*rail_id).ok())
+            .and_then(|rail_children| rail_children.first().copied())
+            .and_then(|inset_id| q_track.get(inset_id).ok())
+            .and_then(|inset_children| inset_children.first().copied());
+        let Some(Ok((mut fill_bg, mut fill))) = fill_id.map(|fill_id| q_fill.get_mut(fill_id))
+        else {
+            continue;
+        };
+
+        let fill_color = styled_slider.fill_color.unwrap_or(slider_styles.fill_color);
+        let fill_color = if is_disabled {
+            fill_color.with_alpha(0.5)
+        } else {
+            fill_color
+        };
+        if fill_bg.0 != fill_color {
+            fill_bg.0 = fill_color;
+        }
+
+        let length = Val::Percent(slider_state.thumb_position() * 100.0);
+        let (width, height) = match styled_slider.orientation {
+            SliderOrientation::Horizontal => (length, Val::Auto),
+            SliderOrientation::Vertical => (Val::Auto, length),
+        };
+        if fill.width != width || fill.height != height {
+            fill.width = width;
+            fill.height = height;
+        }
+    }
+}
+
+// Apply the size and orientation of the slider to its rail, inset track and thumb.
+#[allow(clippy::type_complexity)]
+pub fn update_slider_layout(
+    theme_manager: Res<ThemeManager>,
+    mut q_slider: Query<
+        (&StyledSlider, &mut CoreSlider, &mut Node, &Children),
+        Changed<StyledSlider>,
+    >,
+    q_children: Query<&Children>,
+    mut q_rail: Query<&mut Node, (With<Track>, Without<StyledSlider>)>,
+    mut q_track: Query<
+        (&mut Node, &Children),
+        (
+            Without<StyledSlider>,
+            Without<Track>,
+            Without<Thumb>,
+            Without<SliderFill>,
+        ),
+    >,
+    mut q_thumb: Query<
+        (&mut Node, &mut BorderRadius),
+        (With<Thumb>, Without<Track>, Without<StyledSlider>),
+    >,
+    mut q_radius: Query<&mut BorderRadius, (With<Track>, Without<Thumb>)>,
+    mut q_fill: Query<
+        (&mut Node, &mut BorderRadius),
+        (
+            With<SliderFill>,
+            Without<Track>,
+            Without<Thumb>,
+            Without<StyledSlider>,
+        ),
+    >,
+) {
+    let slider_size_styles = &theme_manager.styles.slider_sizes;
+    for (styled_slider, mut slider, mut node, children) in q_slider.iter_mut() {
+        let slider_size_style = match styled_slider.size.unwrap_or_default() {
+            SliderSize::XSmall => &slider_size_styles.xsmall,
+            SliderSize::Small => &slider_size_styles.small,
+            SliderSize::Medium => &slider_size_styles.medium,
+            SliderSize::Large => &slider_size_styles.large,
+            SliderSize::XLarge => &slider_size_styles.xlarge,
+        };
+        let thumb_size = slider_size_style.thumb_size;
+        let thickness = slider_size_style.track_thickness;
+        let vertical = styled_slider.orientation == SliderOrientation::Vertical;
+
+        if slider.thumb_size != thumb_size {
+            slider.thumb_size = thumb_size;
+        }
+
+        let (direction, width, height) = if vertical {
+            (FlexDirection::Row, Val::Px(thumb_size), Val::Percent(100.0))
+        } else {
+            (
+                FlexDirection::Column,
+                Val::Percent(100.0),
+                Val::Px(thumb_size),
+            )
+        };
+        if node.flex_direction != direction || node.width != width || node.height != height {
+            node.flex_direction = direction;
+            node.width = width;
+            node.height = height;
+        }
+
+        if let Some(rail_id) = children.first() {
+            if let Ok(mut rail) = q_rail.get_mut(*rail_id) {
+                let (width, height) = if vertical {
+                    (Val::Px(thickness), Val::Auto)
+                } else {
+                    (Val::Auto, Val::Px(thickness))
+                };
+                if rail.width != width || rail.height != height {
+                    rail.width = width;
+                    rail.height = height;
+                }
+            }
+            if let Ok(mut radius) = q_radius.get_mut(*rail_id) {
+                *radius = BorderRadius::all(Val::Px(slider_size_style.track_corner_radius));
+            }
+
+            // The fill's inset is short by the thumb size like the thumb's, and the fill reaches
+            // half a thumb further to the thumb's center
+            let fill_inset_id = q_children
+                .get(*rail_id)
+                .ok()
+                .and_then(|rail_children| rail_children.first().copied());
+            if let Some(Ok((mut fill_inset, fill_inset_children))) =
+                fill_inset_id.map(|fill_inset_id| q_track.get_mut(fill_inset_id))
+            {
+                let (top, right) = if vertical {
+                    (Val::Px(thumb_size), Val::Px(0.0))
+                } else {
+                    (Val::Px(0.0), Val::Px(thumb_size))
+                };
+                if fill_inset.top != top || fill_inset.right != right {
+                    fill_inset.top = top;
+                    fill_inset.right = right;
+                }
+
+                if let Some(Ok((mut fill, mut radius))) = fill_inset_children
+                    .first()
+                    .map(|fill_id| q_fill.get_mut(*fill_id))
+                {
+                    let half_thumb = Val::Px(thumb_size * 0.5);
+                    if vertical {
+                        (fill.left, fill.right, fill.top, fill.bottom) =
+                            (Val::Px(0.0), Val::Px(0.0), Val::Auto, Val::Px(0.0));
+                        fill.padding = UiRect::top(half_thumb);
+                    } else {
+                        (fill.left, fill.right, fill.top, fill.bottom) =
+                            (Val::Px(0.0), Val::Auto, Val::Px(0.0), Val::Px(0.0));
+                        fill.padding = UiRect::right(half_thumb);
+                    }
+                    *radius = BorderRadius::all(Val::Px(slider_size_style.track_corner_radius));
+                }
+            }
+        }
+
+        let Some(track_id) = children.last() else {
+            continue;
+        };
+        let Ok((mut track, track_children)) = q_track.get_mut(*track_id) else {
+            continue;
+        };
+        // The inset track is short by the thumb size along the slider's axis
+        let (right, bottom) = if vertical {
+            (Val::Px(0.0), Val::Px(thumb_size))
+        } else {
+            (Val::Px(thumb_size), Val::Px(0.0))
+        };
+        if track.right != right || track.bottom != bottom {
+            track.right = right;
+            track.bottom = bottom;
+        }
+
+        let Some(thumb_id) = track_children.first() else {
+            continue;
+        };
+        if let Ok((mut thumb, mut radius)) = q_thumb.get_mut(*thumb_id) {
+            thumb.width = Val::Px(thumb_size);
+            thumb.height = Val::Px(thumb_size);
+            if vertical {
+                thumb.left = Val::Auto;
+            } else {
+                thumb.top = Val::Auto;
+            }
+            *radius = BorderRadius::all(Val::Px(slider_size_style.thumb_corner_radius));
+        }
+    }
+}
+
+// Rebuild the tick marks and their labels when the ticks, range, layout or theme change. The
+// ticks are placed outside of the slider, below a horizontal one and right of a vertical one,
+// in the bottom or right margin of the slider.
+pub fn update_slider_ticks(
+    mut commands: Commands,
+    theme_manager: Res<ThemeManager>,
+    mut q_slider: Query<(Ref<StyledSlider>, &Children, &mut Node)>,
+    mut q_ticks: Query<(Entity, &mut SliderTickMarks, &mut Node), Without<StyledSlider>>,
+) {
+    let slider_styles = &theme_manager.styles.slider;
+    let slider_size_styles = &theme_manager.styles.slider_sizes;
+
+    for (styled_slider, children, mut node) in q_slider.iter_mut() {
+        if !styled_slider.is_changed() && !theme_manager.is_changed() {
+            continue;
+        }
+        let Some((ticks_id, mut tick_marks, mut ticks_node)) = children
+            .iter()
+            .find_map(|child| q_ticks.get_mut(child).ok())
+        else {
+            continue;
+        };
+
+        let span = styled_slider.max - styled_slider.min;
+        let new_tick_marks = SliderTickMarks {
+            marks: styled_slider
+                .tick_values()
+                .into_iter()
+                .map(|value| {
+                    let position = if span > 0.0 {
+                        (value - styled_slider.min) / span
+                    } else {
+                        0.0
+                    };
+                    let label = styled_slider
+                        .tick_labels
+                        .then(|| styled_slider.format_value(value));
+                    (position, label)
+                })
+                .collect(),
+            size: styled_slider.size.unwrap_or_default(),
+            orientation: styled_slider.orientation,
+        };
+        if *tick_marks == new_tick_marks && !theme_manager.is_changed() {
+            continue;
+        }
+
+        let slider_size_style = match new_tick_marks.size {
+            SliderSize::XSmall => &slider_size_styles.xsmall,
+            SliderSize::Small => &slider_size_styles.small,
+            SliderSize::Medium => &slider_size_styles.medium,
+            SliderSize::Large => &slider_size_styles.large,
+            SliderSize::XLarge => &slider_size_styles.xlarge,
+        };
+        let half_thumb = Val::Px(slider_size_style.thumb_size * 0.5);
+        let tick_length = slider_size_style.tick_length;
+        let label_font_size = slider_size_style.tick_label_font_size;
+        let label_width = slider_size_style.tick_label_width;
+        let label_height = label_font_size * 1.5;
+        let vertical = new_tick_marks.orientation == SliderOrientation::Vertical;
+        let has_labels = new_tick_marks
+            .marks
+            .iter()
+            .any(|(_, label)| label.is_some());
+
+        let room = match (new_tick_marks.marks.is_empty(), has_labels, vertical) {
+            (true, _, _) => 0.0,
+            (false, false, _) => tick_length + 4.0,
+            (false, true, false) => tick_length + 4.0 + label_height,
+            (false, true, true) => tick_length + 4.0 + label_width,
+        };
+        // Only the side holding the ticks is touched, a margin of a slider without ticks is
+        // left alone and the side of a previous orientation is cleared
+        let had_ticks = !tick_marks.marks.is_empty();
+        if had_ticks && tick_marks.orientation != new_tick_marks.orientation {
+            match tick_marks.orientation {
+                SliderOrientation::Horizontal => node.margin.bottom = Val::ZERO,
+                SliderOrientation::Vertical => node.margin.right = Val::ZERO,
+            }
+        }
+        if had_ticks || !new_tick_marks.marks.is_empty() {
+            let margin = Val::Px(room);
+            if vertical && node.margin.right != margin {
+                node.margin.right = margin;
+            } else if !vertical && node.margin.bottom != margin {
+                node.margin.bottom = margin;
+            }
+        }
+
+        // The ticks line up with the thumb's center, which travels half a thumb from the ends
+        *ticks_node = if vertical {
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(100.0),
+                top: half_thumb,
+                bottom: half_thumb,
+                width: Val::Px(room),
+                ..default()
+            }
+        } else {
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(100.0),
+                left: half_thumb,
+                right: half_thumb,
+                height: Val::Px(room),
+                ..default()
+            }
+        };
+
+        commands
+            .entity(ticks_id)
+            .despawn_related::<Children>()
+            .with_children(|parent| {
+                for (position, label) in new_tick_marks.marks.iter() {
+                    let offset = if vertical {
+                        Val::Percent((1.0 - position) * 100.0)
+                    } else {
+                        Val::Percent(position * 100.0)
+                    };
+                    parent.spawn((
+                        if vertical {
+                            Node {
+                                position_type: PositionType::Absolute,
+                                top: offset,
+                                left: Val::Px(2.0),
+                                width: Val::Px(tick_length),
+                                height: Val::Px(1.0),
+                                margin: UiRect::top(Val::Px(-0.5)),
+                                ..default()
+                            }
+                        } else {
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: offset,
+                                top: Val::Px(2.0),
+                                width: Val::Px(1.0),
+                                height: Val::Px(tick_length),
+                                margin: UiRect::left(Val::Px(-0.5)),
+                                ..default()
+                            }
+                        },
+                        BackgroundColor(slider_styles.tick_color),
+                    ));
+
+                    let Some(label) = label else {
+                        continue;
+                    };
+                    parent.spawn((
+                        if vertical {
+                            Node {
+                                position_type: PositionType::Absolute,
+                                top: offset,
+                                left: Val::Px(tick_length + 4.0),
+                                height: Val::Px(label_height),
+                                margin: UiRect::top(Val::Px(-label_height * 0.5)),
+                                align_items: AlignItems::Center,
+                                ..default()
+                            }
+                        } else {
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: offset,
+                                top: Val::Px(tick_length + 4.0),
+                                width: Val::Px(label_width),
+                                margin: UiRect::left(Val::Px(-label_width * 0.5)),
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            }
+                        },
+                        children![(
+                            Text::new(label.clone()),
+                            TextFont {
+                                font_size: label_font_size,
+                                ..default()
+                            },
+                            TextColor(slider_styles.tick_label_color),
+                        )],
+                    ));
+                }
+            });
+
+        *tick_marks = new_tick_marks;
+    }
+}
+
+// Show the formatted value while the slider is hovered or dragged, above a horizontal slider's
+// thumb and left of a vertical slider's thumb.
+#[allow(clippy::type_complexity)]
+pub fn update_slider_value_bubble(
+    theme_manager: Res<ThemeManager>,
+    q_slider: Query<(
+        Entity,
+        &StyledSlider,
+        &CoreSlider,
+        &SliderDragState,
+        &SliderPointerDrag,
+        &Hovering,
+    )>,
+    q_children: Query<&Children>,
+    mut q_bubble: Query<(&mut Node, &Children), With<SliderValueBubble>>,
+    mut q_background: Query<(&mut BackgroundColor, &Children), Without<SliderValueBubble>>,
+    mut q_text: Query<(&mut Text, &mut TextFont, &mut TextColor)>,
+) {
+    let slider_styles = &theme_manager.styles.slider;
+    let slider_size_styles = &theme_manager.styles.slider_sizes;
+
+    for (entity, styled_slider, slider, drag_state, pointer_drag, Hovering(is_hovering)) in
+        q_slider.iter()
+    {
+        let Some((mut node, bubble_children)) = q_children
+            .iter_descendants(entity)
+            .find(|descendant| q_bubble.contains(*descendant))
+            .and_then(|bubble_id| q_bubble.get_mut(bubble_id).ok())
+        else {
+            continue;
+        };
+
+        let slider_size_style = match styled_slider.size.unwrap_or_default() {
+            SliderSize::XSmall => &slider_size_styles.xsmall,
+            SliderSize::Small => &slider_size_styles.small,
+            SliderSize::Medium => &slider_size_styles.medium,
+            SliderSize::Large => &slider_size_styles.large,
+            SliderSize::XLarge => &slider_size_styles.xlarge,
+        };
+        let visible = styled_slider.show_value
+            && (*is_hovering || drag_state.dragging || pointer_drag.pressed);
+        let thumb_size = slider.thumb_size;
+        let bubble_width = slider_size_style.bubble_width;
+        let bubble_node = if !visible {
+            Node {
+                display: Display::None,
+                ..node.clone()
+            }
+        } else if styled_slider.orientation == SliderOrientation::Vertical {
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(thumb_size + 4.0),
+                top: Val::Px(0.0),
+                height: Val::Px(thumb_size),
+                align_items: AlignItems::Center,
+                ..default()
+            }
+        } else {
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px((thumb_size - bubble_width) * 0.5),
+                bottom: Val::Px(thumb_size + 4.0),
+                width: Val::Px(bubble_width),
+                justify_content: JustifyContent::Center,
+                ..default()
+            }
+        };
+        if *node != bubble_node {
+            *node = bubble_node;
+        }
+        if !visible {
+            continue;
+        }
+
+        let Some(Ok((mut background, background_children))) = bubble_children
+            .first()
+            .map(|background_id| q_background.get_mut(*background_id))
+        else {
+            continue;
+        };
+        if background.0 != slider_styles.bubble_background {
+            background.0 = slider_styles.bubble_background;
+        }
+
+        let Some(Ok((mut text, mut font, mut color))) = background_children
+            .first()
+            .map(|text_id| q_text.get_mut(*text_id))
+        else {
+            continue;
+        };
+        let value = styled_slider.format_value(styled_slider.value);
+        if text.0 != value {
+            text.0 = value;
+        }
+        let font_size = slider_size_style.bubble_font_size;
+        if font.font_size != font_size {
+            font.font_size = font_size;
+        }
+        if color.0 != slider_styles.bubble_text_color {
+            color.0 = slider_styles.bubble_text_color;
         }
     }
 }
@@ -0,0 +1,207 @@
+use accesskit::{HasPopup, Role};
+use bevy::{
+    a11y::AccessibilityNode,
+    ecs::{spawn::SpawnIter, system::SystemId},
+    prelude::*,
+};
+
+use super::components::{
+    SplitButtonMenu, SplitButtonMenuItem, SplitButtonPrimary, SplitButtonTrigger, StyledSplitButton,
+};
+use crate::{
+    themes::ThemeManager,
+    ui::{
+        button::{ButtonSize, ButtonVariant, StyledButton},
+        button_group::{ButtonGroupOrientation, StyledButtonGroup},
+        icon::IconId,
+    },
+};
+
+// Shown on the trigger when no trigger icon is set
+const DEFAULT_TRIGGER_TEXT: &str = "▾";
+
+struct SplitButtonItem {
+    text: String,
+    on_select: Option<SystemId>,
+}
+
+#[derive(Default)]
+pub struct SplitButtonBuilder {
+    variant: ButtonVariant,
+    size: Option<ButtonSize>,
+    text: Option<String>,
+    icon: Option<IconId>,
+    on_click: Option<SystemId>,
+    trigger_icon: Option<IconId>,
+    trigger_label: Option<String>,
+    items: Vec<SplitButtonItem>,
+    disabled: bool,
+}
+
+impl SplitButtonBuilder {
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the primary action.
+    pub fn on_click(mut self, system_id: SystemId) -> Self {
+        self.on_click = Some(system_id);
+        self
+    }
+
+    /// Sets the icon of the dropdown trigger, which defaults to a down arrow.
+    pub fn trigger_icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.trigger_icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the accessible label of the dropdown trigger.
+    pub fn trigger_label<S: Into<String>>(mut self, label: S) -> Self {
+        self.trigger_label = Some(label.into());
+        self
+    }
+
+    /// Adds a menu entry running `on_select` when chosen.
+    pub fn item<S: Into<String>>(mut self, text: S, on_select: SystemId) -> Self {
+        self.items.push(SplitButtonItem {
+            text: text.into(),
+            on_select: Some(on_select),
+        });
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let theme_manager = ThemeManager::default();
+        let menu_style = theme_manager.styles.panels.popover.clone();
+        let button_size_styles = theme_manager.styles.button_sizes.clone();
+        let button_size_style = match self.size.unwrap_or_default() {
+            ButtonSize::XSmall => button_size_styles.xsmall,
+            ButtonSize::Small => button_size_styles.small,
+            ButtonSize::Medium => button_size_styles.medium,
+            ButtonSize::Large => button_size_styles.large,
+            ButtonSize::XLarge => button_size_styles.xlarge,
+        };
+
+        let size = self.size.unwrap_or_default();
+        let trigger_label = self
+            .trigger_label
+            .unwrap_or_else(|| "More options".to_string());
+
+        let mut primary = StyledButton::builder().variant(self.variant).size(size);
+        if let Some(text) = self.text {
+            primary = primary.text(text);
+        }
+        if let Some(icon) = self.icon {
+            primary = primary.icon(icon);
+        }
+        if let Some(on_click) = self.on_click {
+            primary = primary.on_click(on_click);
+        }
+
+        let mut trigger = StyledButton::builder().variant(self.variant).size(size);
+        trigger = match self.trigger_icon {
+            Some(icon) => trigger.icon_only(icon, trigger_label),
+            None => trigger.text(DEFAULT_TRIGGER_TEXT).label(trigger_label),
+        };
+
+        if self.disabled {
+            primary = primary.disabled();
+            trigger = trigger.disabled();
+        }
+
+        let mut trigger_accessible = accesskit::Node::new(Role::Button);
+        trigger_accessible.set_has_popup(HasPopup::Menu);
+
+        let items: Vec<_> = self
+            .items
+            .into_iter()
+            .map(|item| {
+                let mut button = StyledButton::builder()
+                    .text(item.text)
+                    .variant(ButtonVariant::Ghost)
+                    .size(size);
+                if let Some(on_select) = item.on_select {
+                    button = button.on_click(on_select);
+                }
+                (
+                    button.build(),
+                    SplitButtonMenuItem,
+                    AccessibilityNode(accesskit::Node::new(Role::MenuItem)),
+                )
+            })
+            .collect();
+
+        (
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Row,
+                position_type: PositionType::Relative,
+                ..default()
+            },
+            Name::new("SplitButton"),
+            StyledSplitButton {
+                variant: self.variant,
+                size: self.size,
+                open: false,
+            },
+            // The primary and trigger buttons are laid out as a button group
+            StyledButtonGroup {
+                orientation: ButtonGroupOrientation::Horizontal,
+            },
+            AccessibilityNode(accesskit::Node::new(Role::Group)),
+            Children::spawn((
+                Spawn((primary.build(), SplitButtonPrimary)),
+                Spawn((
+                    trigger.build(),
+                    SplitButtonTrigger,
+                    AccessibilityNode(trigger_accessible),
+                )),
+                // Menu anchored below the split button
+                Spawn((
+                    Node {
+                        display: Display::None,
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Stretch,
+                        position_type: PositionType::Absolute,
+                        top: Val::Percent(100.0),
+                        left: Val::Px(0.0),
+                        min_width: Val::Percent(100.0),
+                        margin: UiRect::top(Val::Px(button_size_style.gap)),
+                        padding: UiRect::all(Val::Px(button_size_style.gap / 2.0)),
+                        border: UiRect::all(Val::Px(button_size_style.border_width.max(1.0))),
+                        ..default()
+                    },
+                    Name::new("SplitButtonMenu"),
+                    BackgroundColor(menu_style.background_color),
+                    BorderColor(menu_style.border_color),
+                    BorderRadius::all(Val::Px(button_size_style.border_radius)),
+                    GlobalZIndex(1),
+                    SplitButtonMenu,
+                    AccessibilityNode(accesskit::Node::new(Role::Menu)),
+                    Children::spawn(SpawnIter(items.into_iter())),
+                )),
+            )),
+        )
+    }
+}
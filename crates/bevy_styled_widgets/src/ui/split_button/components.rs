@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::ui::button::{ButtonSize, ButtonVariant};
+
+#[derive(Component, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct StyledSplitButton {
+    #[reflect(ignore)]
+    pub variant: ButtonVariant,
+    #[reflect(ignore)]
+    pub size: Option<ButtonSize>,
+    /// Whether the dropdown menu is shown.
+    pub open: bool,
+}
+
+impl StyledSplitButton {
+    pub fn builder() -> super::builder::SplitButtonBuilder {
+        super::builder::SplitButtonBuilder::default()
+    }
+}
+
+/// The button running the primary action.
+#[derive(Component)]
+pub struct SplitButtonPrimary;
+
+/// The button opening the dropdown menu.
+#[derive(Component)]
+pub struct SplitButtonTrigger;
+
+#[derive(Component)]
+pub struct SplitButtonMenu;
+
+#[derive(Component)]
+pub struct SplitButtonMenuItem;
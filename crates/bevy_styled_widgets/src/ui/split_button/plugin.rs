@@ -0,0 +1,17 @@
+use super::systems::{
+    on_split_button_item_click, on_split_button_key_input, on_split_button_outside_press,
+    on_split_button_trigger_click, update_split_button,
+};
+use crate::ui::button::update_button;
+use bevy::prelude::*;
+
+pub struct StyledSplitButtonPlugin;
+impl Plugin for StyledSplitButtonPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_split_button_trigger_click)
+            .add_observer(on_split_button_item_click)
+            .add_observer(on_split_button_key_input)
+            .add_observer(on_split_button_outside_press);
+        app.add_systems(Update, update_split_button.before(update_button));
+    }
+}
@@ -0,0 +1,230 @@
+use bevy::{
+    a11y::AccessibilityNode,
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::{FocusedInput, InputFocus, InputFocusVisible, tab_navigation::TabIndex},
+    prelude::*,
+};
+use bevy_core_widgets::InteractionDisabled;
+
+use crate::{themes::ThemeManager, ui::button::StyledButton};
+
+use super::components::{
+    SplitButtonMenu, SplitButtonMenuItem, SplitButtonPrimary, SplitButtonTrigger, StyledSplitButton,
+};
+
+// Show or hide the menu and keep the inner buttons in sync with the split button.
+#[allow(clippy::type_complexity)]
+pub fn update_split_button(
+    theme_manager: Res<ThemeManager>,
+    query: Query<(Ref<StyledSplitButton>, &Children)>,
+    mut q_menu: Query<
+        (
+            &mut Node,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            Option<&Children>,
+        ),
+        With<SplitButtonMenu>,
+    >,
+    mut q_item: Query<&mut TabIndex, With<SplitButtonMenuItem>>,
+    mut q_button: Query<
+        (
+            &mut StyledButton,
+            Option<&mut AccessibilityNode>,
+            Has<SplitButtonTrigger>,
+        ),
+        Or<(With<SplitButtonPrimary>, With<SplitButtonTrigger>)>,
+    >,
+) {
+    for (split_button, children) in query.iter() {
+        if !theme_manager.is_changed() && !split_button.is_changed() {
+            continue;
+        }
+
+        let menu_style = theme_manager.styles.panels.popover.clone();
+
+        for child in children.iter() {
+            if let Ok((mut menu_node, mut bg_color, mut border_color, items)) =
+                q_menu.get_mut(child)
+            {
+                menu_node.display = if split_button.open {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+                bg_color.0 = menu_style.background_color;
+                border_color.0 = menu_style.border_color;
+
+                // Items are reached with the arrow keys, not with Tab
+                for item in items.into_iter().flat_map(|items| items.iter()) {
+                    if let Ok(mut tab_index) = q_item.get_mut(item) {
+                        tab_index.0 = -1;
+                    }
+                }
+                continue;
+            }
+
+            if let Ok((mut button, accessibility, is_trigger)) = q_button.get_mut(child) {
+                if button.variant != split_button.variant {
+                    button.variant = split_button.variant;
+                }
+                if button.size != split_button.size {
+                    button.size = split_button.size;
+                }
+                if let (true, Some(mut accessibility)) = (is_trigger, accessibility) {
+                    accessibility.set_expanded(split_button.open);
+                }
+            }
+        }
+    }
+}
+
+pub fn on_split_button_trigger_click(
+    trigger: Trigger<Pointer<Click>>,
+    q_trigger: Query<&ChildOf, (With<SplitButtonTrigger>, Without<InteractionDisabled>)>,
+    mut q_split_button: Query<&mut StyledSplitButton>,
+) {
+    if let Ok(child_of) = q_trigger.get(trigger.target()) {
+        if let Ok(mut split_button) = q_split_button.get_mut(child_of.parent()) {
+            split_button.open = !split_button.open;
+        }
+    }
+}
+
+// Close the menu once an item was chosen with the pointer.
+pub fn on_split_button_item_click(
+    trigger: Trigger<Pointer<Click>>,
+    q_item: Query<&ChildOf, (With<SplitButtonMenuItem>, Without<InteractionDisabled>)>,
+    q_menu: Query<&ChildOf, With<SplitButtonMenu>>,
+    mut q_split_button: Query<&mut StyledSplitButton>,
+) {
+    let Ok(item_parent) = q_item.get(trigger.target()) else {
+        return;
+    };
+    if let Ok(menu_parent) = q_menu.get(item_parent.parent()) {
+        if let Ok(mut split_button) = q_split_button.get_mut(menu_parent.parent()) {
+            split_button.open = false;
+        }
+    }
+}
+
+// Close open menus when the pointer is pressed anywhere outside their split button.
+pub fn on_split_button_outside_press(
+    trigger: Trigger<Pointer<Pressed>>,
+    q_parent: Query<&ChildOf>,
+    mut q_split_button: Query<(Entity, &mut StyledSplitButton)>,
+) {
+    let pressed = trigger.event().target;
+    // The event bubbles, only handle it once on the entity that was pressed
+    if trigger.target() != pressed {
+        return;
+    }
+
+    for (entity, mut split_button) in q_split_button.iter_mut() {
+        if !split_button.open {
+            continue;
+        }
+        let is_inside = pressed == entity
+            || q_parent
+                .iter_ancestors(pressed)
+                .any(|ancestor| ancestor == entity);
+        if !is_inside {
+            split_button.open = false;
+        }
+    }
+}
+
+// Open the menu from the trigger and move between items with the keyboard.
+#[allow(clippy::too_many_arguments)]
+pub fn on_split_button_key_input(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    q_trigger: Query<&ChildOf, (With<SplitButtonTrigger>, Without<InteractionDisabled>)>,
+    q_menu: Query<(&ChildOf, &Children), With<SplitButtonMenu>>,
+    q_item: Query<Has<InteractionDisabled>, With<SplitButtonMenuItem>>,
+    q_children: Query<&Children>,
+    mut q_split_button: Query<&mut StyledSplitButton>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+) {
+    let event = &trigger.event().input;
+    if event.state != ButtonState::Pressed || event.repeat {
+        return;
+    }
+    let target = trigger.target();
+    let key_code = event.key_code;
+
+    // Keys pressed on the trigger
+    if let Ok(child_of) = q_trigger.get(target) {
+        let split_entity = child_of.parent();
+        let Ok(mut split_button) = q_split_button.get_mut(split_entity) else {
+            return;
+        };
+        match key_code {
+            KeyCode::Enter | KeyCode::Space | KeyCode::ArrowDown => {
+                trigger.propagate(false);
+                split_button.open = key_code == KeyCode::ArrowDown || !split_button.open;
+                if split_button.open {
+                    let first_item = q_children
+                        .get(split_entity)
+                        .into_iter()
+                        .flat_map(|children| children.iter())
+                        .filter_map(|child| q_menu.get(child).ok())
+                        .flat_map(|(_, items)| items.iter())
+                        .find(|item| q_item.get(*item).is_ok_and(|disabled| !disabled));
+                    if let Some(first_item) = first_item {
+                        focus.0 = Some(first_item);
+                        focus_visible.0 = true;
+                    }
+                }
+            }
+            KeyCode::Escape => {
+                split_button.open = false;
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    // Keys bubbling up from the menu items
+    let Ok((child_of, items)) = q_menu.get(target) else {
+        return;
+    };
+    let split_entity = child_of.parent();
+    let enabled: Vec<Entity> = items
+        .iter()
+        .filter(|item| q_item.get(*item).is_ok_and(|disabled| !disabled))
+        .collect();
+    let current = focus
+        .0
+        .and_then(|entity| enabled.iter().position(|item| *item == entity));
+
+    let next = match key_code {
+        KeyCode::ArrowDown if !enabled.is_empty() => {
+            current.map_or(0, |index| (index + 1) % enabled.len())
+        }
+        KeyCode::ArrowUp if !enabled.is_empty() => current.map_or(enabled.len() - 1, |index| {
+            (index + enabled.len() - 1) % enabled.len()
+        }),
+        KeyCode::Home if !enabled.is_empty() => 0,
+        KeyCode::End if !enabled.is_empty() => enabled.len() - 1,
+        KeyCode::Escape | KeyCode::Enter | KeyCode::Space => {
+            // The item itself already ran its action, close and return focus to the trigger
+            trigger.propagate(false);
+            if let Ok(mut split_button) = q_split_button.get_mut(split_entity) {
+                split_button.open = false;
+            }
+            focus.0 = q_children
+                .get(split_entity)
+                .into_iter()
+                .flat_map(|children| children.iter())
+                .find(|child| q_trigger.contains(*child));
+            focus_visible.0 = true;
+            return;
+        }
+        _ => return,
+    };
+
+    trigger.propagate(false);
+    focus.0 = Some(enabled[next]);
+    focus_visible.0 = true;
+}
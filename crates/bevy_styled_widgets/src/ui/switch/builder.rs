@@ -8,9 +8,18 @@ use bevy_additional_core_widgets::CoreSwitch;
 
 use super::{
     SwitchSize,
-    components::{AccessibleName, StyledSwitch, SwitchVariant},
+    components::{
+        AccessibleName, StyledSwitch, SwitchIcon, SwitchIconSlot, SwitchKnob, SwitchKnobPosition,
+        SwitchVariant,
+    },
+};
+use crate::{
+    themes::ThemeManager,
+    ui::{
+        icon::{IconId, StyledIcon},
+        label::StyledLabel,
+    },
 };
-use crate::themes::ThemeManager;
 
 #[derive(Default)]
 pub struct SwitchBuilder {
@@ -28,6 +37,11 @@ pub struct SwitchBuilder {
     pub off_text_color: Option<Color>,
     pub on_label: Option<String>,
     pub off_label: Option<String>,
+    pub caption: Option<String>,
+    pub on_icon: Option<IconId>,
+    pub off_icon: Option<IconId>,
+    pub on_track_icon: Option<IconId>,
+    pub off_track_icon: Option<IconId>,
     pub size: Option<SwitchSize>,
     pub disabled: bool, // Disable switch interaction
     pub name: Option<String>,
@@ -107,6 +121,45 @@ impl SwitchBuilder {
         self
     }
 
+    pub fn caption<S: Into<String>>(mut self, caption: S) -> Self {
+        self.caption = Some(caption.into());
+        self
+    }
+
+    /// Sets the knob icons for the on and off states.
+    pub fn icons<I: Into<IconId>, J: Into<IconId>>(mut self, on: I, off: J) -> Self {
+        self.on_icon = Some(on.into());
+        self.off_icon = Some(off.into());
+        self
+    }
+
+    pub fn on_icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.on_icon = Some(icon.into());
+        self
+    }
+
+    pub fn off_icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.off_icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the track icons for the on and off states.
+    pub fn track_icons<I: Into<IconId>, J: Into<IconId>>(mut self, on: I, off: J) -> Self {
+        self.on_track_icon = Some(on.into());
+        self.off_track_icon = Some(off.into());
+        self
+    }
+
+    pub fn on_track_icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.on_track_icon = Some(icon.into());
+        self
+    }
+
+    pub fn off_track_icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.off_track_icon = Some(icon.into());
+        self
+    }
+
     pub fn size(mut self, size: SwitchSize) -> Self {
         self.size = Some(size);
         self
@@ -138,12 +191,6 @@ impl SwitchBuilder {
         let is_on = self.state;
         let is_disabled = self.disabled;
 
-        let cursor_icon = if is_disabled {
-            SystemCursorIcon::NotAllowed
-        } else {
-            SystemCursorIcon::Pointer
-        };
-
         let style = match self.variant {
             SwitchVariant::Rounded => &theme_manager.styles.switches.rounded,
             SwitchVariant::Rectangular => &theme_manager.styles.switches.rectangular,
@@ -171,75 +218,62 @@ impl SwitchBuilder {
             self.knob_color.unwrap_or(style.knob_color)
         };
 
-        let child_nodes = match self.variant {
-            SwitchVariant::Rounded => Children::spawn((Spawn((
-                Node {
-                    width: Val::Px(switch_size_style.track_width),
-                    height: Val::Px(switch_size_style.track_height),
-                    border: UiRect::all(Val::Px(switch_size_style.track_border_width)),
-                    justify_content: JustifyContent::Start,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                BorderRadius::all(Val::Px(switch_size_style.track_corner_radius)),
-                BackgroundColor(track_color),
-                Children::spawn((
-                    Spawn((
-                        Node {
-                            position_type: PositionType::Absolute,
-                            width: Val::Px(switch_size_style.knob_width),
-                            height: Val::Px(switch_size_style.knob_height),
-                            left: Val::Px(if is_on {
-                                switch_size_style.knob_offset_x_on
-                            } else {
-                                switch_size_style.knob_offset_x
-                            }),
-                            ..default()
-                        },
-                        BackgroundColor(knob_color),
-                        BorderRadius::all(Val::Px(switch_size_style.knob_corner_radius)),
-                    )),
-                    Spawn((
-                        Node {
-                            position_type: PositionType::Absolute,
-                            left: Val::Px(0.0),
-                            ..default()
-                        },
-                        Text::new(""),
-                        TextFont {
-                            font_size: switch_size_style.label_font_size,
-                            ..default()
-                        },
-                        TextColor(if is_on {
-                            self.on_text_color.unwrap_or(style.on_text_color)
-                        } else {
-                            self.off_text_color.unwrap_or(style.off_text_color)
-                        }),
-                    )),
-                )),
-            )),)),
-
-            SwitchVariant::Rectangular => {
-                let text = if is_on {
-                    self.on_label.clone().unwrap_or("ON".into())
+        let caption = self.caption.clone().unwrap_or_default();
+        let caption_node = (
+            StyledLabel::builder().text(caption.clone()).build(),
+            Node {
+                display: if caption.is_empty() {
+                    Display::None
                 } else {
-                    self.off_label.clone().unwrap_or("OFF".into())
-                };
+                    Display::Flex
+                },
+                margin: UiRect::left(Val::Px(8.0)),
+                ..default()
+            },
+        );
+
+        // Icons of both states, `update_switch_colors` fades them with the knob position
+        let knob_icons = Children::spawn((
+            Spawn(switch_icon(
+                self.on_icon.clone(),
+                switch_size_style.knob_icon_size,
+                SwitchIconSlot::Knob,
+                true,
+            )),
+            Spawn(switch_icon(
+                self.off_icon.clone(),
+                switch_size_style.knob_icon_size,
+                SwitchIconSlot::Knob,
+                false,
+            )),
+        ));
+        let on_track_icon = switch_icon(
+            self.on_track_icon.clone(),
+            switch_size_style.track_icon_size,
+            SwitchIconSlot::Track,
+            true,
+        );
+        let off_track_icon = switch_icon(
+            self.off_track_icon.clone(),
+            switch_size_style.track_icon_size,
+            SwitchIconSlot::Track,
+            false,
+        );
 
-                Children::spawn((Spawn((
+        let child_nodes = match self.variant {
+            SwitchVariant::Rounded => Children::spawn((
+                Spawn((
                     Node {
                         width: Val::Px(switch_size_style.track_width),
                         height: Val::Px(switch_size_style.track_height),
                         border: UiRect::all(Val::Px(switch_size_style.track_border_width)),
                         justify_content: JustifyContent::Start,
                         align_items: AlignItems::Center,
-                        position_type: PositionType::Relative, // IMPORTANT for absolute children!
                         ..default()
                     },
-                    BorderRadius::all(Val::Px(0.0)),
-                    BackgroundColor(track_color), // unwrap Option<Color>
+                    BorderRadius::all(Val::Px(switch_size_style.track_corner_radius)),
+                    BackgroundColor(track_color),
                     Children::spawn((
-                        // Knob
                         Spawn((
                             Node {
                                 position_type: PositionType::Absolute,
@@ -253,20 +287,17 @@ impl SwitchBuilder {
                                 ..default()
                             },
                             BackgroundColor(knob_color),
-                            BorderRadius::all(Val::Px(0.0)),
+                            BorderRadius::all(Val::Px(switch_size_style.knob_corner_radius)),
+                            SwitchKnob,
+                            knob_icons,
                         )),
-                        // Label
                         Spawn((
                             Node {
                                 position_type: PositionType::Absolute,
-                                left: Val::Px(if is_on {
-                                    switch_size_style.label_offset_on
-                                } else {
-                                    switch_size_style.label_offset
-                                }),
+                                left: Val::Px(0.0),
                                 ..default()
                             },
-                            Text::new(text.clone()),
+                            Text::new(""),
                             TextFont {
                                 font_size: switch_size_style.label_font_size,
                                 ..default()
@@ -277,8 +308,80 @@ impl SwitchBuilder {
                                 self.off_text_color.unwrap_or(style.off_text_color)
                             }),
                         )),
+                        Spawn(on_track_icon),
+                        Spawn(off_track_icon),
                     )),
-                )),))
+                )),
+                Spawn(caption_node),
+            )),
+
+            SwitchVariant::Rectangular => {
+                let text = if is_on {
+                    self.on_label.clone().unwrap_or("ON".into())
+                } else {
+                    self.off_label.clone().unwrap_or("OFF".into())
+                };
+
+                Children::spawn((
+                    Spawn((
+                        Node {
+                            width: Val::Px(switch_size_style.track_width),
+                            height: Val::Px(switch_size_style.track_height),
+                            border: UiRect::all(Val::Px(switch_size_style.track_border_width)),
+                            justify_content: JustifyContent::Start,
+                            align_items: AlignItems::Center,
+                            position_type: PositionType::Relative, // IMPORTANT for absolute children!
+                            ..default()
+                        },
+                        BorderRadius::all(Val::Px(0.0)),
+                        BackgroundColor(track_color), // unwrap Option<Color>
+                        Children::spawn((
+                            // Knob
+                            Spawn((
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    width: Val::Px(switch_size_style.knob_width),
+                                    height: Val::Px(switch_size_style.knob_height),
+                                    left: Val::Px(if is_on {
+                                        switch_size_style.knob_offset_x_on
+                                    } else {
+                                        switch_size_style.knob_offset_x
+                                    }),
+                                    ..default()
+                                },
+                                BackgroundColor(knob_color),
+                                BorderRadius::all(Val::Px(0.0)),
+                                SwitchKnob,
+                                knob_icons,
+                            )),
+                            // Label
+                            Spawn((
+                                Node {
+                                    position_type: PositionType::Absolute,
+                                    left: Val::Px(if is_on {
+                                        switch_size_style.label_offset_on
+                                    } else {
+                                        switch_size_style.label_offset
+                                    }),
+                                    ..default()
+                                },
+                                Text::new(text.clone()),
+                                TextFont {
+                                    font_size: switch_size_style.label_font_size,
+                                    ..default()
+                                },
+                                TextColor(if is_on {
+                                    self.on_text_color.unwrap_or(style.on_text_color)
+                                } else {
+                                    self.off_text_color.unwrap_or(style.off_text_color)
+                                }),
+                            )),
+                            Spawn(on_track_icon),
+                            Spawn(off_track_icon),
+                        )),
+                    )),
+                    Spawn(caption_node),
+                ))
             }
         };
 
@@ -293,7 +396,7 @@ impl SwitchBuilder {
             },
             Name::new("Switch"),
             Hovering::default(),
-            CursorIcon::System(cursor_icon),
+            CursorIcon::System(SystemCursorIcon::Pointer),
             StyledSwitch {
                 variant: self.variant,
                 state: self.state,
@@ -306,18 +409,38 @@ impl SwitchBuilder {
                 off_text_color: self.off_text_color,
                 on_label: self.on_label.clone(),
                 off_label: self.off_label.clone(),
+                caption: self.caption.clone(),
+                on_icon: self.on_icon.clone(),
+                off_icon: self.off_icon.clone(),
+                on_track_icon: self.on_track_icon.clone(),
+                off_track_icon: self.off_track_icon.clone(),
                 size: self.size,
                 disabled: self.disabled,
                 knob_color: self.knob_color,
             },
-            CoreSwitch {
-                on_change: self.on_change,
-            },
+            // `on_change` is run by the styled observer, so the core widget triggers `ValueChange`
+            CoreSwitch { on_change: None },
             Checked(self.state),
+            SwitchKnobPosition(if is_on { 1.0 } else { 0.0 }),
             RootComponent,
-            AccessibleName(self.name.clone().unwrap_or_else(|| "Switch".to_string())),
+            AccessibleName(
+                self.name
+                    .clone()
+                    .or(self.caption.clone())
+                    .unwrap_or_else(|| "Switch".to_string()),
+            ),
             TabIndex(0),
             child_nodes,
         )
     }
 }
+
+fn switch_icon(icon: Option<IconId>, size: f32, slot: SwitchIconSlot, state: bool) -> impl Bundle {
+    (
+        StyledIcon::builder()
+            .icon(icon.unwrap_or_default())
+            .size(size)
+            .build(),
+        SwitchIcon { slot, state },
+    )
+}
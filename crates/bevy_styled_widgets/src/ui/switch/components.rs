@@ -2,6 +2,8 @@ use bevy::a11y::AccessibilityNode;
 use bevy::ecs::{component::HookContext, system::SystemId, world::DeferredWorld};
 use bevy::prelude::*;
 
+use crate::ui::icon::IconId;
+
 #[derive(Component, Reflect)]
 #[reflect(from_reflect = false)]
 pub struct StyledSwitch {
@@ -19,6 +21,14 @@ pub struct StyledSwitch {
     pub off_text_color: Option<Color>,
     pub on_label: Option<String>,
     pub off_label: Option<String>,
+    /// Label shown next to the track, it also names the switch.
+    pub caption: Option<String>,
+    /// Icons inside the knob while the switch is on or off.
+    pub on_icon: Option<IconId>,
+    pub off_icon: Option<IconId>,
+    /// Icons on the free side of the track while the switch is on or off.
+    pub on_track_icon: Option<IconId>,
+    pub off_track_icon: Option<IconId>,
     #[reflect(ignore)]
     pub size: Option<SwitchSize>,
     pub disabled: bool,
@@ -53,6 +63,28 @@ pub enum SwitchSize {
     XLarge,
 }
 
+/// The knob of a switch, it moves along the track.
+#[derive(Component, Default)]
+pub struct SwitchKnob;
+
+/// An icon of a switch, it fades in while the switch is in `state`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SwitchIcon {
+    pub slot: SwitchIconSlot,
+    pub state: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwitchIconSlot {
+    Knob,
+    Track,
+}
+
+/// Knob position from off (0.0) to on (1.0). It follows a knob drag and eases to the
+/// switch state over the style's `transition_duration`.
+#[derive(Component, Default, Debug, Clone, Copy)]
+pub struct SwitchKnobPosition(pub f32);
+
 #[derive(Component, Default)]
 #[component(immutable, on_add = on_set_label, on_replace = on_set_label)]
 pub struct AccessibleName(pub String);
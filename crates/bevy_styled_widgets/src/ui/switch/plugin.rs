@@ -1,10 +1,23 @@
-use super::systems::{on_switch_changed, update_switch_colors};
+use super::{
+    StyledSwitch,
+    systems::{animate_switch_knob, on_switch_changed, sync_switch_state, update_switch_colors},
+};
+use crate::ui::disabled::sync_disabled;
 use bevy::prelude::*;
 
 pub struct StyledSwitchPlugin;
 impl Plugin for StyledSwitchPlugin {
     fn build(&self, app: &mut App) {
         app.add_observer(on_switch_changed);
-        app.add_systems(Update, update_switch_colors);
+        app.add_systems(
+            Update,
+            (
+                sync_switch_state,
+                sync_disabled::<StyledSwitch>,
+                animate_switch_knob,
+                update_switch_colors,
+            )
+                .chain(),
+        );
     }
 }
@@ -1,12 +1,18 @@
-use bevy::{prelude::*, ui};
+use bevy::{prelude::*, window::RequestRedraw};
+use bevy_additional_core_widgets::CoreSwitchDrag;
 use bevy_core_widgets::{Checked, InteractionDisabled, ValueChange, hover::Hovering};
 
-use crate::themes::ThemeManager;
+use crate::{
+    themes::ThemeManager,
+    ui::{events::WidgetChanged, icon::StyledIcon},
+};
 
 use super::{
     SwitchSize,
     builder::RootComponent,
-    components::{StyledSwitch, SwitchVariant},
+    components::{
+        StyledSwitch, SwitchIcon, SwitchIconSlot, SwitchKnob, SwitchKnobPosition, SwitchVariant,
+    },
 };
 
 #[allow(clippy::type_complexity)]
@@ -17,22 +23,52 @@ pub fn update_switch_colors(
             &StyledSwitch,
             &Hovering,
             &Checked,
+            &SwitchKnobPosition,
             Has<InteractionDisabled>,
             &Children,
         ),
         (With<RootComponent>,),
     >,
     mut q_border_color: Query<
-        (&mut BorderColor, &mut BackgroundColor, &mut Children),
-        Without<RootComponent>,
+        (
+            &mut BorderColor,
+            &mut BackgroundColor,
+            &mut Children,
+            &mut Node,
+            &mut BorderRadius,
+        ),
+        (
+            Without<RootComponent>,
+            Without<Text>,
+            Without<SwitchKnob>,
+            Without<SwitchIcon>,
+        ),
     >,
     mut q_knob: Query<
-        (&mut BackgroundColor, &mut Node),
-        (Without<Children>, Without<RootComponent>, Without<Text>),
+        (
+            &mut BackgroundColor,
+            &mut Node,
+            &mut BorderRadius,
+            Option<&Children>,
+        ),
+        (
+            With<SwitchKnob>,
+            Without<RootComponent>,
+            Without<Text>,
+            Without<SwitchIcon>,
+        ),
     >,
-    mut q_text: Query<(&mut Text, &mut Node, &mut TextColor)>,
+    mut q_text: Query<(&mut Text, &mut Node, &mut TextColor, &mut TextFont), Without<SwitchIcon>>,
+    mut q_icon: Query<(&mut StyledIcon, &mut Node, &SwitchIcon)>,
 ) {
-    for (switch, Hovering(is_hovering), Checked(checked), is_disabled, children) in query.iter_mut()
+    for (
+        switch,
+        Hovering(is_hovering),
+        Checked(checked),
+        SwitchKnobPosition(knob_position),
+        is_disabled,
+        children,
+    ) in query.iter_mut()
     {
         let switch_styles = theme_manager.styles.switches.clone();
         let switch_size_styles = theme_manager.styles.switch_sizes.clone();
@@ -52,95 +88,269 @@ pub fn update_switch_colors(
             SwitchSize::XLarge => switch_size_styles.xlarge,
         };
 
+        // Caption next to the track
+        if let Some(caption_id) = children.get(1) {
+            if let Ok((mut text, mut node, _, _)) = q_text.get_mut(*caption_id) {
+                let caption = switch.caption.clone().unwrap_or_default();
+                node.display = if caption.is_empty() {
+                    Display::None
+                } else {
+                    Display::Flex
+                };
+                if text.0 != caption {
+                    text.0 = caption;
+                }
+            }
+        }
+
         let Some(track_id) = children.first() else {
             continue;
         };
 
-        let Ok((mut border_color, mut bg_color, track_children)) =
+        let Ok((mut border_color, mut bg_color, track_children, mut track_node, mut track_radius)) =
             q_border_color.get_mut(*track_id)
         else {
             continue;
         };
 
-        match (is_disabled, *checked, is_hovering) {
-            (true, true, _) => {
-                bg_color.0 = switch_style.disabled_on_background;
-                border_color.0 = switch_style.border_color;
-            }
-            (true, false, _) => {
-                bg_color.0 = switch_style.disabled_off_background;
-                border_color.0 = switch_style.border_color;
-            }
-            (_, true, true) => {
-                bg_color.0 = switch_style.hovered_background;
-                border_color.0 = switch_style.border_color;
+        track_node.width = Val::Px(switch_size_style.track_width);
+        track_node.height = Val::Px(switch_size_style.track_height);
+        track_node.border = UiRect::all(Val::Px(switch_size_style.track_border_width));
+        *track_radius = BorderRadius::all(Val::Px(match switch.variant {
+            SwitchVariant::Rounded => switch_size_style.track_corner_radius,
+            SwitchVariant::Rectangular => 0.0,
+        }));
+
+        let on_background = switch.on_color.unwrap_or(switch_style.on_background);
+        let off_background = switch.off_color.unwrap_or(switch_style.off_background);
+        let hovered_background = switch
+            .hover_color
+            .unwrap_or(switch_style.hovered_background);
+        border_color.0 = switch.border_color.unwrap_or(switch_style.border_color);
+
+        bg_color.0 = match (is_disabled, *checked, is_hovering) {
+            (true, true, _) => switch_style.disabled_on_background,
+            (true, false, _) => switch_style.disabled_off_background,
+            (_, _, true) => hovered_background,
+            (_, true, false) => on_background,
+            (_, false, false) => off_background,
+        };
+
+        let Some(knob_id) = track_children.first() else {
+            continue;
+        };
+        let mut icons: Vec<Entity> = track_children.iter().collect();
+        if let Ok((mut knob_bg, mut node, mut knob_radius, knob_children)) =
+            q_knob.get_mut(*knob_id)
+        {
+            icons.extend(
+                knob_children
+                    .into_iter()
+                    .flat_map(|children| children.iter()),
+            );
+            knob_bg.0 = if is_disabled {
+                switch_style.disabled_knob_color
+            } else {
+                switch.knob_color.unwrap_or(switch_style.knob_color)
+            };
+            node.width = Val::Px(switch_size_style.knob_width);
+            node.height = Val::Px(switch_size_style.knob_height);
+            node.left = Val::Px(
+                switch_size_style.knob_offset_x
+                    + (switch_size_style.knob_offset_x_on - switch_size_style.knob_offset_x)
+                        * knob_position,
+            );
+            *knob_radius = BorderRadius::all(Val::Px(match switch.variant {
+                SwitchVariant::Rounded => switch_size_style.knob_corner_radius,
+                SwitchVariant::Rectangular => 0.0,
+            }));
+        }
+
+        // Icons of both states cross-fade as the knob moves
+        for icon_id in icons {
+            let Ok((mut icon, mut icon_node, switch_icon)) = q_icon.get_mut(icon_id) else {
+                continue;
+            };
+            let (icon_name, color, size) = match (switch_icon.slot, switch_icon.state) {
+                (SwitchIconSlot::Knob, true) => (
+                    &switch.on_icon,
+                    on_background,
+                    switch_size_style.knob_icon_size,
+                ),
+                (SwitchIconSlot::Knob, false) => (
+                    &switch.off_icon,
+                    switch.off_text_color.unwrap_or(switch_style.off_text_color),
+                    switch_size_style.knob_icon_size,
+                ),
+                (SwitchIconSlot::Track, true) => (
+                    &switch.on_track_icon,
+                    switch.on_text_color.unwrap_or(switch_style.on_text_color),
+                    switch_size_style.track_icon_size,
+                ),
+                (SwitchIconSlot::Track, false) => (
+                    &switch.off_track_icon,
+                    switch.off_text_color.unwrap_or(switch_style.off_text_color),
+                    switch_size_style.track_icon_size,
+                ),
+            };
+            let opacity = if switch_icon.state {
+                *knob_position
+            } else {
+                1.0 - knob_position
+            };
+
+            icon_node.display = if icon_name.is_some() {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            icon_node.position_type = PositionType::Absolute;
+            match switch_icon.slot {
+                SwitchIconSlot::Knob => {
+                    icon_node.left = Val::Px((switch_size_style.knob_width - size) / 2.0);
+                    icon_node.top = Val::Px((switch_size_style.knob_height - size) / 2.0);
+                }
+                // Each track icon sits where the knob is not in its state
+                SwitchIconSlot::Track => {
+                    let knob_offset = if switch_icon.state {
+                        switch_size_style.knob_offset_x
+                    } else {
+                        switch_size_style.knob_offset_x_on
+                    };
+                    icon_node.left =
+                        Val::Px(knob_offset + (switch_size_style.knob_width - size) / 2.0);
+                    icon_node.top = Val::Px(
+                        (switch_size_style.track_height
+                            - 2.0 * switch_size_style.track_border_width
+                            - size)
+                            / 2.0,
+                    );
+                }
             }
-            (_, false, true) => {
-                bg_color.0 = switch_style.hovered_background;
-                border_color.0 = switch_style.border_color;
+
+            if let Some(name) = icon_name {
+                if icon.icon != *name {
+                    icon.icon = name.clone();
+                }
             }
-            (_, true, false) => {
-                bg_color.0 = switch_style.on_background;
-                border_color.0 = switch_style.border_color;
+            if icon.size != Some(size) {
+                icon.size = Some(size);
             }
-            (_, false, false) => {
-                bg_color.0 = switch_style.off_background;
-                border_color.0 = switch_style.border_color;
+            let color = color.with_alpha(color.alpha() * opacity);
+            if icon.color != Some(color) {
+                icon.color = Some(color);
             }
         }
 
-        let Some(knob_id) = track_children.first() else {
-            continue;
-        };
-        let Ok((mut knob_bg, mut node)) = q_knob.get_mut(*knob_id) else {
+        // Only rectangular switches show a label
+        let Some(label_id) = track_children.get(1) else {
             continue;
         };
+        if let Ok((mut text, mut node, mut text_color, mut text_font)) = q_text.get_mut(*label_id) {
+            let label = match (switch.variant, *checked) {
+                (SwitchVariant::Rounded, _) => String::new(),
+                (SwitchVariant::Rectangular, true) => {
+                    switch.on_label.clone().unwrap_or("ON".into())
+                }
+                (SwitchVariant::Rectangular, false) => {
+                    switch.off_label.clone().unwrap_or("OFF".into())
+                }
+            };
+            if text.0 != label {
+                text.0 = label;
+            }
 
-        if switch.disabled {
-            knob_bg.0 = switch_style.disabled_knob_color;
-            continue; // Skip updating visuals or responding to events
-        }
-
-        if *checked {
-            knob_bg.0 = switch_style.knob_color;
-            node.left = ui::Val::Px(switch_size_style.knob_offset_x_on)
-        } else {
-            knob_bg.0 = switch_style.knob_color;
-            node.left = ui::Val::Px(switch_size_style.knob_offset_x)
-        };
+            node.left = if *checked {
+                Val::Px(switch_size_style.label_offset_on)
+            } else {
+                Val::Px(switch_size_style.label_offset)
+            };
 
-        if switch.variant == SwitchVariant::Rectangular {
-            // label
-            let label = if *checked {
-                switch.on_label.clone().unwrap_or("ON".into())
+            text_color.0 = if *checked {
+                switch.on_text_color.unwrap_or(switch_style.on_text_color)
             } else {
-                switch.off_label.clone().unwrap_or("OFF".into())
+                switch.off_text_color.unwrap_or(switch_style.off_text_color)
             };
+            text_font.font_size = switch_size_style.label_font_size;
+        }
+    }
+}
 
-            let label_id = track_children[1];
-            if let Ok((mut text, mut node, mut text_color)) = q_text.get_mut(label_id) {
-                **text = if *checked {
-                    label.to_string()
-                } else {
-                    label.to_string()
-                };
+// Move the knob with a drag, else ease it to the switch state. Keeps the drag travel of
+// the core switch at the knob's travel for the switch size.
+#[allow(clippy::type_complexity)]
+pub fn animate_switch_knob(
+    time: Res<Time>,
+    theme_manager: Res<ThemeManager>,
+    mut query: Query<(
+        &StyledSwitch,
+        &Checked,
+        &mut CoreSwitchDrag,
+        &mut SwitchKnobPosition,
+    )>,
+    mut redraw: EventWriter<RequestRedraw>,
+) {
+    for (switch, Checked(checked), mut drag, mut knob_position) in query.iter_mut() {
+        let switch_style = match switch.variant {
+            SwitchVariant::Rounded => &theme_manager.styles.switches.rounded,
+            SwitchVariant::Rectangular => &theme_manager.styles.switches.rectangular,
+        };
+        let switch_size_styles = &theme_manager.styles.switch_sizes;
+        let switch_size_style = match switch.size.unwrap_or_default() {
+            SwitchSize::XSmall => &switch_size_styles.xsmall,
+            SwitchSize::Small => &switch_size_styles.small,
+            SwitchSize::Medium => &switch_size_styles.medium,
+            SwitchSize::Large => &switch_size_styles.large,
+            SwitchSize::XLarge => &switch_size_styles.xlarge,
+        };
 
-                node.left = if *checked {
-                    Val::Px(switch_size_style.label_offset_on)
-                } else {
-                    Val::Px(switch_size_style.label_offset)
-                };
+        let travel = switch_size_style.knob_offset_x_on - switch_size_style.knob_offset_x;
+        if drag.travel != travel {
+            drag.travel = travel;
+        }
 
-                text_color.0 = if *checked {
-                    switch_style.on_text_color
-                } else {
-                    switch_style.off_text_color
-                };
+        let position = if drag.dragging {
+            drag.position
+        } else {
+            let target = if *checked { 1.0 } else { 0.0 };
+            if switch_style.transition_duration <= 0.0 {
+                target
+            } else {
+                let step = time.delta_secs() / switch_style.transition_duration;
+                knob_position.0 + (target - knob_position.0).clamp(-step, step)
+            }
+        };
+        if knob_position.0 != position {
+            knob_position.0 = position;
+            // Keep updating while the knob moves, also in reactive update modes
+            redraw.write(RequestRedraw);
+        }
+    }
+}
+
+// Keep the core switch in sync with `StyledSwitch`, which is the source of truth.
+// `Checked` set directly, e.g. from an `on_change` callback, is copied back.
+#[allow(clippy::type_complexity)]
+pub fn sync_switch_state(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut StyledSwitch, Ref<Checked>)>,
+) {
+    for (entity, mut switch, checked) in query.iter_mut() {
+        if !switch.is_changed() {
+            if checked.is_changed() && checked.0 != switch.state {
+                switch.bypass_change_detection().state = checked.0;
             }
+            continue;
+        }
+
+        if checked.0 != switch.state {
+            commands.entity(entity).insert(Checked(switch.state));
         }
     }
 }
 
+// Emit `WidgetChanged` for every change. With an `on_change` callback the widget is
+// controlled and the callback decides the new state, otherwise it is applied here.
 pub fn on_switch_changed(
     mut trigger: Trigger<ValueChange<bool>>,
     query: Query<&StyledSwitch>,
@@ -151,15 +361,23 @@ pub fn on_switch_changed(
     let checked = trigger.event().0;
     let entity = trigger.target();
 
-    commands.entity(entity).insert(Checked(checked));
+    let Ok(styled_switch) = query.get(entity) else {
+        return;
+    };
 
-    if let Ok(styled_switch) = query.get(entity) {
-        if styled_switch.disabled {
-            commands.entity(entity).insert(InteractionDisabled);
-        }
-        if let Some(system_id) = styled_switch.on_change {
-            // Defer the callback system using commands
-            commands.run_system_with(system_id, (entity, checked));
-        }
+    commands.trigger_targets(
+        WidgetChanged {
+            entity,
+            old: styled_switch.state,
+            new: checked,
+        },
+        entity,
+    );
+
+    if let Some(system_id) = styled_switch.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, (entity, checked));
+    } else {
+        commands.entity(entity).insert(Checked(checked));
     }
 }
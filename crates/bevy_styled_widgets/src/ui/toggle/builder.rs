@@ -4,13 +4,16 @@ use bevy::{
 };
 use bevy_core_widgets::{Checked, hover::Hovering};
 
-use bevy_additional_core_widgets::CoreSwitch;
+use bevy_additional_core_widgets::CoreToggleButton;
 
 use super::{
     ToggleSize,
-    components::{AccessibleName, StyledToggle, ToggleVariant},
+    components::{AccessibleName, StyledToggle, StyledToggleIcon, ToggleVariant},
+};
+use crate::{
+    themes::ThemeManager,
+    ui::icon::{IconId, StyledIcon},
 };
-use crate::themes::ThemeManager;
 
 #[derive(Component, Default)]
 pub struct RootComponent;
@@ -20,10 +23,12 @@ pub struct ToggleBuilder {
     pub active: bool,
     pub label: Option<String>,
     pub on_change: Option<SystemId<In<(Entity, bool)>>>,
-    pub icon: Option<String>,
+    pub icon: Option<IconId>,
     pub variant: ToggleVariant,
     pub disabled: bool,
     pub size: Option<ToggleSize>,
+    pub name: Option<String>,
+    pub value: String,
 }
 
 impl ToggleBuilder {
@@ -37,8 +42,27 @@ impl ToggleBuilder {
         self
     }
 
-    pub fn icon<S: Into<String>>(mut self, icon: S) -> Self {
+    pub fn icon<I: Into<IconId>>(mut self, icon: I) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Builds an icon-only toggle. The name is required since there is no visible text.
+    pub fn icon_only<I: Into<IconId>, S: Into<String>>(mut self, icon: I, name: S) -> Self {
         self.icon = Some(icon.into());
+        self.label = None;
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the accessible name, which defaults to the label.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = value.into();
         self
     }
 
@@ -112,13 +136,17 @@ impl ToggleBuilder {
             }
         };
 
-        let text = self.label.clone().unwrap_or_else(|| "T".to_string());
-        let cursor_icon = if self.disabled {
-            SystemCursorIcon::NotAllowed
-        } else {
-            SystemCursorIcon::Pointer
+        let text = self.label.clone().unwrap_or_default();
+        let accessible_name = match (&self.name, &self.label) {
+            (Some(name), _) => name.clone(),
+            (None, Some(label)) => label.clone(),
+            (None, None) => {
+                if self.icon.is_some() {
+                    warn!("Icon-only toggles need an accessible name, use `icon_only` or `name`");
+                }
+                "Toggle".to_string()
+            }
         };
-
         let child_nodes = Children::spawn((Spawn((
             Node {
                 width: Val::Auto,
@@ -134,24 +162,41 @@ impl ToggleBuilder {
                 })),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
+                column_gap: Val::Px(4.0),
                 ..default()
             },
             BorderColor(style.border_color),
             BorderRadius::all(Val::Px(toggle_size_style.corner_radius)),
             BackgroundColor(background),
-            Children::spawn((Spawn((
-                Node {
-                    position_type: PositionType::Relative,
-                    left: Val::Px(0.0),
-                    ..default()
-                },
-                Text::new(text),
-                TextFont {
-                    font_size: toggle_size_style.label_font_size,
-                    ..default()
-                },
-                TextColor(text_color),
-            )),)),
+            Children::spawn((
+                // Icon, hidden while no icon is set
+                Spawn((
+                    StyledIcon::builder()
+                        .icon(self.icon.clone().unwrap_or_default())
+                        .size(toggle_size_style.icon_size)
+                        .color(text_color)
+                        .build(),
+                    StyledToggleIcon,
+                )),
+                Spawn((
+                    Node {
+                        display: if text.is_empty() {
+                            Display::None
+                        } else {
+                            Display::Flex
+                        },
+                        position_type: PositionType::Relative,
+                        left: Val::Px(0.0),
+                        ..default()
+                    },
+                    Text::new(text),
+                    TextFont {
+                        font_size: toggle_size_style.label_font_size,
+                        ..default()
+                    },
+                    TextColor(text_color),
+                )),
+            )),
         )),));
 
         (
@@ -163,9 +208,9 @@ impl ToggleBuilder {
                 border: UiRect::all(Val::Px(1.0)),
                 ..default()
             },
-            Name::new("Switch"),
+            Name::new("Toggle"),
             Hovering::default(),
-            CursorIcon::System(cursor_icon),
+            CursorIcon::System(SystemCursorIcon::Pointer),
             StyledToggle {
                 active: self.active,
                 on_change: self.on_change,
@@ -174,13 +219,13 @@ impl ToggleBuilder {
                 disabled: self.disabled,
                 size: self.size,
                 icon: self.icon.clone(),
+                value: self.value.clone(),
             },
-            CoreSwitch {
-                on_change: self.on_change,
-            },
+            // `on_change` is run by the styled observer, so the core widget triggers `ValueChange`
+            CoreToggleButton { on_change: None },
             Checked(self.active),
             RootComponent,
-            AccessibleName(self.label.clone().unwrap_or_else(|| "Switch".to_string())),
+            AccessibleName(accessible_name),
             TabIndex(0),
             child_nodes,
         )
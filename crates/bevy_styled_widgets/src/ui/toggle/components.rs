@@ -2,6 +2,8 @@ use bevy::a11y::AccessibilityNode;
 use bevy::ecs::{component::HookContext, system::SystemId, world::DeferredWorld};
 use bevy::prelude::*;
 
+use crate::ui::icon::IconId;
+
 #[derive(Component, Reflect)]
 #[reflect(from_reflect = false)]
 pub struct StyledToggle {
@@ -14,7 +16,9 @@ pub struct StyledToggle {
     #[reflect(ignore)]
     pub size: Option<ToggleSize>,
     pub disabled: bool,
-    pub icon: Option<String>,
+    pub icon: Option<IconId>,
+    /// Identifies the toggle in the selection of a toggle group.
+    pub value: String,
 }
 
 impl StyledToggle {
@@ -23,6 +27,9 @@ impl StyledToggle {
     }
 }
 
+#[derive(Component)]
+pub struct StyledToggleIcon;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
 pub enum ToggleVariant {
     Default,
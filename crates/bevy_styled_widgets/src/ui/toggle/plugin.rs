@@ -1,11 +1,22 @@
-use super::systems::{init, on_toggle_button_changed, update_toggle_button_visuals};
+use super::{
+    StyledToggle,
+    systems::{on_toggle_button_changed, sync_toggle_state, update_toggle_button_visuals},
+};
+use crate::ui::disabled::sync_disabled;
 use bevy::prelude::*;
 
 pub struct StyledTogglePlugin;
 impl Plugin for StyledTogglePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PostStartup, init);
         app.add_observer(on_toggle_button_changed);
-        app.add_systems(Update, update_toggle_button_visuals);
+        app.add_systems(
+            Update,
+            (
+                sync_toggle_state,
+                sync_disabled::<StyledToggle>,
+                update_toggle_button_visuals,
+            )
+                .chain(),
+        );
     }
 }
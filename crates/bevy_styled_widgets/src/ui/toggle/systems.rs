@@ -4,9 +4,12 @@ use bevy_core_widgets::{Checked, InteractionDisabled, ValueChange, hover::Hoveri
 use super::{
     ToggleSize,
     builder::RootComponent,
-    components::{StyledToggle, ToggleVariant},
+    components::{StyledToggle, StyledToggleIcon, ToggleVariant},
+};
+use crate::{
+    themes::ThemeManager,
+    ui::{events::WidgetChanged, icon::StyledIcon, toggle_group::ToggleGroupMember},
 };
-use crate::themes::ThemeManager;
 
 #[allow(clippy::type_complexity)]
 pub fn update_toggle_button_visuals(
@@ -22,8 +25,27 @@ pub fn update_toggle_button_visuals(
         ),
         (With<RootComponent>,),
     >,
-    mut q_background_color: Query<(&mut BackgroundColor, &mut Children), Without<RootComponent>>,
-    mut q_text: Query<(&mut Text, &mut TextColor)>,
+    mut q_background_color: Query<
+        (
+            &mut BackgroundColor,
+            &mut Children,
+            &mut Node,
+            &mut BorderRadius,
+        ),
+        (Without<RootComponent>, Without<StyledToggleIcon>),
+    >,
+    mut q_text: Query<
+        (&mut Text, &mut Node, &mut TextColor, &mut TextFont),
+        (
+            Without<RootComponent>,
+            Without<Children>,
+            Without<StyledToggleIcon>,
+        ),
+    >,
+    mut q_icon: Query<
+        (&mut StyledIcon, &mut Node),
+        (With<StyledToggleIcon>, Without<RootComponent>),
+    >,
 ) {
     for (mut toggle_node, toggle, Hovering(is_hovering), Checked(checked), is_disabled, children) in
         query.iter_mut()
@@ -38,69 +60,121 @@ pub fn update_toggle_button_visuals(
             ToggleVariant::WithText => toggle_styles.with_text,
         };
 
+        //Update size styles
+        let toggle_size_style = match toggle.size.unwrap_or_default() {
+            ToggleSize::XSmall => toggle_size_styles.xsmall,
+            ToggleSize::Small => toggle_size_styles.small,
+            ToggleSize::Medium => toggle_size_styles.medium,
+            ToggleSize::Large => toggle_size_styles.large,
+            ToggleSize::XLarge => toggle_size_styles.xlarge,
+        };
+
         let Some(toggle_id) = children.first() else {
             continue;
         };
 
-        let Ok((mut bg_color, main_children)) = q_background_color.get_mut(*toggle_id) else {
+        let Ok((mut bg_color, main_children, mut inner_node, mut inner_radius)) =
+            q_background_color.get_mut(*toggle_id)
+        else {
             continue;
         };
 
-        let label_id = main_children[0];
-        if let Ok((mut text, mut text_color)) = q_text.get_mut(label_id) {
-            **text = toggle.label.clone().unwrap_or("T".into());
-
-            let (new_bg, new_text_color) = match (*checked, is_disabled, is_hovering) {
-                (true, true, _) => (
-                    toggle_style.disabled_active_background,
-                    toggle_style.disabled_text_color,
-                ),
-                (false, true, _) => (
-                    toggle_style.disabled_inactive_background,
-                    toggle_style.disabled_text_color,
-                ),
-                (true, false, true) => (
-                    toggle_style.hovered_active_background,
-                    toggle_style.active_text_color,
-                ),
-                (false, false, true) => (
-                    toggle_style.hovered_active_background,
-                    toggle_style.hovered_text_color,
-                ),
-                (true, false, false) => (
-                    toggle_style.active_background,
-                    toggle_style.active_text_color,
-                ),
-                (false, false, false) => (
-                    toggle_style.inactive_background,
-                    toggle_style.active_text_color,
-                ),
-            };
-
-            //Update size styles
-            let toggle_size_style = match toggle.size.unwrap_or_default() {
-                ToggleSize::XSmall => toggle_size_styles.xsmall,
-                ToggleSize::Small => toggle_size_styles.small,
-                ToggleSize::Medium => toggle_size_styles.medium,
-                ToggleSize::Large => toggle_size_styles.large,
-                ToggleSize::XLarge => toggle_size_styles.xlarge,
-            };
-            toggle_node.padding = UiRect::axes(
-                Val::Px(toggle_size_style.padding_horizontal),
-                Val::Px(toggle_size_style.padding_vertical),
-            );
-            if toggle.variant == ToggleVariant::Outline {
-                toggle_node.border = UiRect::all(Val::Px(toggle_size_style.border_width));
+        inner_node.height = Val::Px(toggle_size_style.height);
+        inner_node.padding = UiRect::axes(
+            Val::Px(toggle_size_style.padding_horizontal),
+            Val::Px(toggle_size_style.padding_vertical),
+        );
+        inner_node.border = UiRect::all(Val::Px(if toggle.variant == ToggleVariant::Outline {
+            toggle_size_style.border_width
+        } else {
+            0.0
+        }));
+        *inner_radius = BorderRadius::all(Val::Px(toggle_size_style.corner_radius));
+
+        let (new_bg, new_text_color) = match (*checked, is_disabled, is_hovering) {
+            (true, true, _) => (
+                toggle_style.disabled_active_background,
+                toggle_style.disabled_text_color,
+            ),
+            (false, true, _) => (
+                toggle_style.disabled_inactive_background,
+                toggle_style.disabled_text_color,
+            ),
+            (true, false, true) => (
+                toggle_style.hovered_active_background,
+                toggle_style.active_text_color,
+            ),
+            (false, false, true) => (
+                toggle_style.hovered_active_background,
+                toggle_style.hovered_text_color,
+            ),
+            (true, false, false) => (
+                toggle_style.active_background,
+                toggle_style.active_text_color,
+            ),
+            (false, false, false) => (
+                toggle_style.inactive_background,
+                toggle_style.active_text_color,
+            ),
+        };
+
+        toggle_node.padding = UiRect::axes(
+            Val::Px(toggle_size_style.padding_horizontal),
+            Val::Px(toggle_size_style.padding_vertical),
+        );
+        if toggle.variant == ToggleVariant::Outline {
+            toggle_node.border = UiRect::all(Val::Px(toggle_size_style.border_width));
+        }
+        bg_color.0 = new_bg;
+
+        for child in main_children.iter() {
+            // Icon
+            if let Ok((mut icon, mut icon_node)) = q_icon.get_mut(child) {
+                icon_node.display = if toggle.icon.is_some() {
+                    Display::Flex
+                } else {
+                    Display::None
+                };
+                if let Some(icon_id) = &toggle.icon {
+                    if icon.icon != *icon_id {
+                        icon.icon = icon_id.clone();
+                    }
+                }
+                if icon.size != Some(toggle_size_style.icon_size) {
+                    icon.size = Some(toggle_size_style.icon_size);
+                }
+                if icon.color != Some(new_text_color) {
+                    icon.color = Some(new_text_color);
+                }
+                continue;
+            }
+
+            // Label, hidden while empty so icon-only toggles stay centered
+            if let Ok((mut text, mut text_node, mut text_color, mut text_font)) =
+                q_text.get_mut(child)
+            {
+                let label = toggle.label.clone().unwrap_or_default();
+                text_node.display = if label.is_empty() {
+                    Display::None
+                } else {
+                    Display::Flex
+                };
+                if text.0 != label {
+                    text.0 = label;
+                }
+                text_font.font_size = toggle_size_style.label_font_size;
+                text_color.0 = new_text_color;
             }
-            bg_color.0 = new_bg;
-            text_color.0 = new_text_color;
         }
     }
 }
 
+// Emit `WidgetChanged` for every change. With an `on_change` callback the widget is
+// controlled and the callback decides the new state, otherwise it is applied here.
+// Toggles in a group are left to the group, which may reject the change.
 pub fn on_toggle_button_changed(
     mut trigger: Trigger<ValueChange<bool>>,
-    query: Query<&StyledToggle>,
+    query: Query<(&StyledToggle, Has<ToggleGroupMember>)>,
     mut commands: Commands,
 ) {
     trigger.propagate(false);
@@ -108,39 +182,47 @@ pub fn on_toggle_button_changed(
     let checked = trigger.event().0;
     let entity = trigger.target();
 
-    commands.entity(entity).insert(Checked(checked));
+    let Ok((styled_toggle, is_group_member)) = query.get(entity) else {
+        return;
+    };
+    if is_group_member {
+        return;
+    }
 
-    if let Ok(styled_toggle) = query.get(entity) {
-        if styled_toggle.disabled {
-            commands.entity(entity).insert(InteractionDisabled);
-        }
-        if let Some(system_id) = styled_toggle.on_change {
-            // Defer the callback system using commands
-            commands.run_system_with(system_id, (entity, checked));
-        }
+    commands.trigger_targets(
+        WidgetChanged {
+            entity,
+            old: styled_toggle.active,
+            new: checked,
+        },
+        entity,
+    );
+
+    if let Some(system_id) = styled_toggle.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, (entity, checked));
+    } else {
+        commands.entity(entity).insert(Checked(checked));
     }
 }
 
-pub fn init(
+// Keep the core widget in sync with `StyledToggle`, which is the source of truth.
+// `Checked` set directly, e.g. from an `on_change` callback, is copied back.
+#[allow(clippy::type_complexity)]
+pub fn sync_toggle_state(
     mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut query: Query<(Entity, &StyledToggle)>,
+    mut query: Query<(Entity, &mut StyledToggle, Ref<Checked>)>,
 ) {
-    for (entity, toggle) in query.iter_mut() {
-        let mut command = commands.entity(entity);
-
-        // if icon is present, add it as a child
-        if let Some(icon) = toggle.icon.clone() {
-            command.with_children(|parent| {
-                parent.spawn((
-                    ImageNode::new(asset_server.load(&icon)),
-                    Node {
-                        width: Val::Px(30.0),
-                        height: Val::Px(30.0),
-                        ..default()
-                    },
-                ));
-            });
+    for (entity, mut toggle, checked) in query.iter_mut() {
+        if !toggle.is_changed() {
+            if checked.is_changed() && checked.0 != toggle.active {
+                toggle.bypass_change_detection().active = checked.0;
+            }
+            continue;
+        }
+
+        if checked.0 != toggle.active {
+            commands.entity(entity).insert(Checked(toggle.active));
         }
     }
 }
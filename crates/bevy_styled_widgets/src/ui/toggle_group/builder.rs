@@ -0,0 +1,169 @@
+use accesskit::Role;
+use bevy::{
+    a11y::AccessibilityNode,
+    ecs::{spawn::SpawnIter, system::SystemId},
+    prelude::*,
+};
+
+use super::components::{
+    StyledToggleGroup, ToggleGroupMode, ToggleGroupOrientation, ToggleGroupValue, ToggleOption,
+};
+use crate::ui::{
+    disabled::Fieldset,
+    toggle::{ToggleBuilder, ToggleSize, ToggleVariant},
+};
+
+pub struct ToggleGroupBuilder<T: ToggleGroupValue = String> {
+    options: Vec<(T, ToggleBuilder)>,
+    mode: ToggleGroupMode,
+    orientation: ToggleGroupOrientation,
+    variant: Option<ToggleVariant>,
+    size: Option<ToggleSize>,
+    required: bool,
+    disabled: bool,
+    label: Option<String>,
+    on_change: Option<SystemId<In<Vec<T>>>>,
+}
+
+impl<T: ToggleGroupValue> Default for ToggleGroupBuilder<T> {
+    fn default() -> Self {
+        Self {
+            options: Vec::new(),
+            mode: ToggleGroupMode::default(),
+            orientation: ToggleGroupOrientation::default(),
+            variant: None,
+            size: None,
+            required: false,
+            disabled: false,
+            label: None,
+            on_change: None,
+        }
+    }
+}
+
+impl ToggleGroupBuilder {
+    /// Adds a toggle standing for its `value`.
+    pub fn toggle(mut self, toggle: ToggleBuilder) -> Self {
+        self.options.push((toggle.value.clone(), toggle));
+        self
+    }
+
+    pub fn toggles(mut self, toggles: impl IntoIterator<Item = ToggleBuilder>) -> Self {
+        self.options.extend(
+            toggles
+                .into_iter()
+                .map(|toggle| (toggle.value.clone(), toggle)),
+        );
+        self
+    }
+}
+
+impl<T: ToggleGroupValue> ToggleGroupBuilder<T> {
+    /// Adds a toggle standing for `value`.
+    pub fn option(mut self, value: T, toggle: ToggleBuilder) -> Self {
+        self.options.push((value, toggle));
+        self
+    }
+
+    pub fn mode(mut self, mode: ToggleGroupMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn orientation(mut self, orientation: ToggleGroupOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the variant of every toggle in the group.
+    pub fn variant(mut self, variant: ToggleVariant) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    /// Sets the size of every toggle in the group.
+    pub fn size(mut self, size: ToggleSize) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Keeps at least one toggle pressed once one is.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn on_change(mut self, system_id: SystemId<In<Vec<T>>>) -> Self {
+        self.on_change = Some(system_id);
+        self
+    }
+
+    pub fn build(self) -> impl Bundle {
+        let flex_direction = match self.orientation {
+            ToggleGroupOrientation::Horizontal => FlexDirection::Row,
+            ToggleGroupOrientation::Vertical => FlexDirection::Column,
+        };
+
+        let mut accessible = accesskit::Node::new(Role::Group);
+        if let Some(label) = &self.label {
+            accessible.set_label(label.as_str());
+        }
+
+        let mut selected: Vec<T> = self
+            .options
+            .iter()
+            .filter(|(_, toggle)| toggle.active)
+            .map(|(value, _)| value.clone())
+            .collect();
+        if self.mode == ToggleGroupMode::Single {
+            selected.truncate(1);
+        }
+        let toggles: Vec<_> = self
+            .options
+            .into_iter()
+            .map(|(value, mut toggle)| {
+                toggle.active = selected.contains(&value);
+                if let Some(variant) = self.variant {
+                    toggle = toggle.variant(variant);
+                }
+                if let Some(size) = self.size {
+                    toggle = toggle.size(size);
+                }
+                (toggle.build(), ToggleOption(value))
+            })
+            .collect();
+
+        (
+            Node {
+                display: Display::Flex,
+                flex_direction,
+                align_items: AlignItems::Stretch,
+                ..default()
+            },
+            Name::new("ToggleGroup"),
+            AccessibilityNode(accessible),
+            StyledToggleGroup {
+                selected,
+                mode: self.mode,
+                orientation: self.orientation,
+                required: self.required,
+                disabled: self.disabled,
+                on_change: self.on_change,
+            },
+            Fieldset {
+                disabled: self.disabled,
+            },
+            Children::spawn(SpawnIter(toggles.into_iter())),
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use bevy::{ecs::system::SystemId, prelude::*};
+
+pub trait ToggleGroupValue: Clone + PartialEq + Send + Sync + 'static {}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> ToggleGroupValue for T {}
+
+/// A group of toggles selecting values of type `T`, registered with
+/// [`ToggleGroupPlugin`](super::ToggleGroupPlugin). Groups of `String` values are enabled by
+/// default and select the toggles' `value`.
+#[derive(Component)]
+pub struct StyledToggleGroup<T: ToggleGroupValue = String> {
+    /// Values of the pressed toggles, in child order.
+    pub selected: Vec<T>,
+    pub mode: ToggleGroupMode,
+    pub orientation: ToggleGroupOrientation,
+    /// Forbids releasing the last pressed toggle.
+    pub required: bool,
+    pub disabled: bool,
+    pub on_change: Option<SystemId<In<Vec<T>>>>,
+}
+
+impl StyledToggleGroup {
+    pub fn builder() -> super::builder::ToggleGroupBuilder {
+        super::builder::ToggleGroupBuilder::default()
+    }
+}
+
+impl<T: ToggleGroupValue> StyledToggleGroup<T> {
+    /// Builder for a group of `T` values, the toggles are added with
+    /// [`option`](super::ToggleGroupBuilder::option).
+    pub fn typed_builder() -> super::builder::ToggleGroupBuilder<T> {
+        super::builder::ToggleGroupBuilder::default()
+    }
+}
+
+/// The value a toggle stands for in a [`StyledToggleGroup`].
+#[derive(Component, Debug, Clone)]
+#[require(ToggleGroupMember)]
+pub struct ToggleOption<T: ToggleGroupValue>(pub T);
+
+/// Marks toggles whose changes are applied by their group, which may reject them.
+#[derive(Component, Default)]
+pub struct ToggleGroupMember;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub enum ToggleGroupMode {
+    /// At most one toggle is pressed, pressing another one releases it.
+    #[default]
+    Single,
+    /// Any number of toggles can be pressed.
+    Multiple,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Component)]
+pub enum ToggleGroupOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
@@ -0,0 +1,9 @@
+mod builder;
+mod components;
+mod plugin;
+mod systems;
+
+pub use builder::*;
+pub use components::*;
+pub use plugin::{StyledToggleGroupPlugin, ToggleGroupPlugin};
+pub use systems::*;
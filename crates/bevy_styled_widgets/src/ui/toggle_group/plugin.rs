@@ -0,0 +1,42 @@
+use std::marker::PhantomData;
+
+use super::{
+    components::ToggleGroupValue,
+    systems::{
+        on_toggle_group_key_input, on_toggle_group_member_changed, sync_toggle_group_state,
+        update_toggle_group_styles, update_toggle_group_tab_index,
+    },
+};
+use crate::ui::toggle::{sync_toggle_state, update_toggle_button_visuals};
+use bevy::prelude::*;
+
+pub struct StyledToggleGroupPlugin;
+impl Plugin for StyledToggleGroupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ToggleGroupPlugin::<String>::default());
+    }
+}
+
+/// Enables [`StyledToggleGroup`](super::StyledToggleGroup) selection with values of type `T`.
+pub struct ToggleGroupPlugin<T>(PhantomData<T>);
+
+impl<T> Default for ToggleGroupPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ToggleGroupValue> Plugin for ToggleGroupPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_toggle_group_member_changed::<T>)
+            .add_observer(on_toggle_group_key_input::<T>);
+        app.add_systems(
+            Update,
+            (
+                sync_toggle_group_state::<T>.before(sync_toggle_state),
+                update_toggle_group_styles::<T>.after(update_toggle_button_visuals),
+                update_toggle_group_tab_index::<T>,
+            ),
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use bevy::{
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::{FocusedInput, InputFocus, InputFocusVisible, tab_navigation::TabIndex},
+    prelude::*,
+};
+use bevy_core_widgets::{Checked, InteractionDisabled, ValueChange};
+
+use super::components::{
+    StyledToggleGroup, ToggleGroupMode, ToggleGroupOrientation, ToggleGroupValue, ToggleOption,
+};
+use crate::ui::{
+    disabled::Fieldset,
+    events::WidgetChanged,
+    focus::{move_roving_focus, update_roving_tab_index},
+    toggle::StyledToggle,
+};
+
+// Apply a user change of a member toggle. `on_toggle_button_changed` leaves members to this
+// observer, so releasing the last pressed toggle of a required group is rejected before the
+// toggle or its listeners see it. An accepted change triggers `WidgetChanged` on the toggle,
+// then on the group with the selected values. With an `on_change` callback the group is
+// controlled and the callback decides the selection, otherwise it is applied here.
+pub fn on_toggle_group_member_changed<T: ToggleGroupValue>(
+    trigger: Trigger<ValueChange<bool>>,
+    mut q_group: Query<(&mut StyledToggleGroup<T>, &Children)>,
+    q_toggle: Query<(&StyledToggle, &ChildOf)>,
+    q_option: Query<&ToggleOption<T>>,
+    mut commands: Commands,
+) {
+    let entity = trigger.target();
+    let (Ok((toggle, child_of)), Ok(option)) = (q_toggle.get(entity), q_option.get(entity)) else {
+        return;
+    };
+    let group_entity = child_of.parent();
+    let Ok((mut group, children)) = q_group.get_mut(group_entity) else {
+        return;
+    };
+    let pressed = trigger.event().0;
+
+    let new: Vec<T> = match (group.mode, pressed) {
+        (ToggleGroupMode::Single, true) => vec![option.0.clone()],
+        (ToggleGroupMode::Single, false) => Vec::new(),
+        (ToggleGroupMode::Multiple, pressed) => children
+            .iter()
+            .filter_map(|child| q_option.get(child).ok().map(|option| (child, option)))
+            .filter(|(child, option)| {
+                if *child == entity {
+                    pressed
+                } else {
+                    group.selected.contains(&option.0)
+                }
+            })
+            .map(|(_, option)| option.0.clone())
+            .collect(),
+    };
+
+    if group.required && new.is_empty() {
+        return;
+    }
+
+    commands.trigger_targets(
+        WidgetChanged {
+            entity,
+            old: toggle.active,
+            new: pressed,
+        },
+        entity,
+    );
+    if let Some(system_id) = toggle.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, (entity, pressed));
+    }
+
+    commands.trigger_targets(
+        WidgetChanged {
+            entity: group_entity,
+            old: group.selected.clone(),
+            new: new.clone(),
+        },
+        group_entity,
+    );
+
+    if let Some(system_id) = group.on_change {
+        // Defer the callback system using commands
+        commands.run_system_with(system_id, new);
+    } else if group.selected != new {
+        group.selected = new;
+    }
+}
+
+// Press the toggles listed in a changed `selected`, or copy the pressed toggles back into
+// `selected` when they changed. Mirrors `disabled` to the group's `Fieldset`.
+pub fn sync_toggle_group_state<T: ToggleGroupValue>(
+    mut q_group: Query<(&mut StyledToggleGroup<T>, &mut Fieldset, &Children)>,
+    mut q_toggle: Query<(&mut StyledToggle, &Checked, &ToggleOption<T>)>,
+) {
+    for (mut group, mut fieldset, children) in q_group.iter_mut() {
+        if fieldset.disabled != group.disabled {
+            fieldset.disabled = group.disabled;
+        }
+
+        if group.is_changed() {
+            for child in children.iter() {
+                if let Ok((mut toggle, checked, option)) = q_toggle.get_mut(child) {
+                    let is_selected = group.selected.contains(&option.0);
+                    // `Checked` may have been set directly, e.g. by a toggle's `on_change` callback
+                    if toggle.active != is_selected || checked.0 != is_selected {
+                        toggle.active = is_selected;
+                    }
+                }
+            }
+        } else if children.iter().any(|child| {
+            q_toggle
+                .get_mut(child)
+                .is_ok_and(|(toggle, _, _)| toggle.is_changed())
+        }) {
+            let selected: Vec<T> = children
+                .iter()
+                .filter_map(|child| q_toggle.get(child).ok())
+                .filter(|(toggle, _, _)| toggle.active)
+                .map(|(_, _, option)| option.0.clone())
+                .collect();
+            if group.selected != selected {
+                group.bypass_change_detection().selected = selected;
+            }
+        }
+    }
+}
+
+// Merge the borders of grouped toggles and keep the radius on the outer corners only.
+// Runs after `update_toggle_button_visuals`, which resets the padding, border and radius.
+pub fn update_toggle_group_styles<T: ToggleGroupValue>(
+    q_group: Query<(&StyledToggleGroup<T>, &Children)>,
+    mut q_toggle: Query<(&mut Node, &Children), With<StyledToggle>>,
+    mut q_inner: Query<(&mut Node, &mut BorderRadius), Without<StyledToggle>>,
+) {
+    for (group, children) in q_group.iter() {
+        let members: Vec<Entity> = children
+            .iter()
+            .filter(|child| q_toggle.contains(*child))
+            .collect();
+        let last = members.len().saturating_sub(1);
+
+        for (index, member) in members.iter().enumerate() {
+            let Ok((mut toggle_node, toggle_children)) = q_toggle.get_mut(*member) else {
+                continue;
+            };
+            // Segments touch each other, the inner node draws the toggle
+            toggle_node.padding = UiRect::ZERO;
+            toggle_node.border = UiRect::ZERO;
+
+            let Some(inner) = toggle_children.first() else {
+                continue;
+            };
+            let Ok((mut node, mut border_radius)) = q_inner.get_mut(*inner) else {
+                continue;
+            };
+            let is_first = index == 0;
+            let is_last = index == last;
+
+            match group.orientation {
+                ToggleGroupOrientation::Horizontal => {
+                    if !is_first {
+                        border_radius.top_left = Val::ZERO;
+                        border_radius.bottom_left = Val::ZERO;
+                        node.border.left = Val::ZERO;
+                    }
+                    if !is_last {
+                        border_radius.top_right = Val::ZERO;
+                        border_radius.bottom_right = Val::ZERO;
+                    }
+                }
+                ToggleGroupOrientation::Vertical => {
+                    if !is_first {
+                        border_radius.top_left = Val::ZERO;
+                        border_radius.top_right = Val::ZERO;
+                        node.border.top = Val::ZERO;
+                    }
+                    if !is_last {
+                        border_radius.bottom_left = Val::ZERO;
+                        border_radius.bottom_right = Val::ZERO;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Only one toggle of a group is reachable with Tab: the focused one, else the first pressed
+// one, else the first enabled one.
+#[allow(clippy::type_complexity)]
+pub fn update_toggle_group_tab_index<T: ToggleGroupValue>(
+    focus: Res<InputFocus>,
+    q_group: Query<&Children, With<StyledToggleGroup<T>>>,
+    q_checked: Query<&Checked, With<StyledToggle>>,
+    mut q_member: Query<(&mut TabIndex, Has<InteractionDisabled>), With<StyledToggle>>,
+) {
+    for children in q_group.iter() {
+        let members: Vec<Entity> = children
+            .iter()
+            .filter(|child| q_member.contains(*child))
+            .collect();
+        let pressed = members.iter().copied().find(|member| {
+            q_checked.get(*member).is_ok_and(|checked| checked.0)
+                && q_member.get(*member).is_ok_and(|(_, disabled)| !disabled)
+        });
+
+        update_roving_tab_index(&members, &focus, pressed, &mut q_member);
+    }
+}
+
+// Move focus between toggles with the arrow keys of the group's orientation, Home and End.
+// Space and Enter press the focused toggle as usual.
+pub fn on_toggle_group_key_input<T: ToggleGroupValue>(
+    mut trigger: Trigger<FocusedInput<KeyboardInput>>,
+    q_group: Query<(&StyledToggleGroup<T>, &Children)>,
+    q_member: Query<Has<InteractionDisabled>, With<StyledToggle>>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+) {
+    let Ok((group, children)) = q_group.get(trigger.target()) else {
+        return;
+    };
+    let event = &trigger.event().input;
+    if event.state != ButtonState::Pressed {
+        return;
+    }
+
+    let members: Vec<Entity> = children
+        .iter()
+        .filter(|child| q_member.contains(*child))
+        .collect();
+    if move_roving_focus(
+        event.key_code,
+        group.orientation == ToggleGroupOrientation::Vertical,
+        &members,
+        |member| q_member.get(member).is_ok_and(|disabled| !disabled),
+        &mut focus,
+        &mut focus_visible,
+    )
+    .is_some()
+    {
+        trigger.propagate(false);
+    }
+}
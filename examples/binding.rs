@@ -0,0 +1,172 @@
+use bevy::{input_focus::tab_navigation::TabGroup, prelude::*, winit::WinitSettings};
+use bevy_styled_widgets::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            StyledWidgetsPlugin,
+            RadioGroupPlugin::<AudioOutput>::default(),
+            BindResourcePlugin::<AudioSettings>::default().radio_group::<AudioOutput>(),
+        ))
+        .insert_resource(ThemeManager::default())
+        .insert_resource(WinitSettings::desktop_app())
+        .init_resource::<AudioSettings>()
+        .add_systems(Startup, setup_view_root)
+        .add_systems(Update, (update_settings_text, update_settings_fieldset))
+        .run();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AudioOutput {
+    Speakers,
+    Headphones,
+}
+
+#[derive(Resource)]
+struct AudioSettings {
+    volume: f32,
+    muted: bool,
+    output: Option<AudioOutput>,
+    locked: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            volume: 40.0,
+            muted: false,
+            output: Some(AudioOutput::Speakers),
+            locked: false,
+        }
+    }
+}
+
+#[derive(Component)]
+struct SettingsText;
+
+#[derive(Component)]
+struct SettingsFieldset;
+
+fn update_settings_text(
+    settings: Res<AudioSettings>,
+    mut query: Query<&mut Text, With<SettingsText>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut text in query.iter_mut() {
+        text.0 = format!(
+            "Volume: {:.0}, muted: {}, output: {:?}",
+            settings.volume, settings.muted, settings.output
+        );
+    }
+}
+
+// Locking the settings disables every widget inside the fieldset
+fn update_settings_fieldset(
+    settings: Res<AudioSettings>,
+    mut query: Query<&mut Fieldset, With<SettingsFieldset>>,
+) {
+    for mut fieldset in query.iter_mut() {
+        if fieldset.disabled != settings.locked {
+            fieldset.disabled = settings.locked;
+        }
+    }
+}
+
+// Widget events bubble, so one observer on the root sees every change in the form
+fn log_volume_changes(trigger: Trigger<WidgetChanged<f32>>) {
+    let event = trigger.event();
+    info!("{:?}: {} -> {}", event.entity, event.old, event.new);
+}
+
+fn log_mute_changes(trigger: Trigger<WidgetChanged<bool>>) {
+    let event = trigger.event();
+    info!("{:?}: {} -> {}", event.entity, event.old, event.new);
+}
+
+// Checkboxes report a `CheckboxState`, which keeps the mixed state
+fn log_checkbox_changes(trigger: Trigger<WidgetChanged<CheckboxState>>) {
+    let event = trigger.event();
+    info!("{:?}: {:?} -> {:?}", event.entity, event.old, event.new);
+}
+
+// Changing the resource directly updates every bound widget
+fn reset_settings(mut settings: ResMut<AudioSettings>) {
+    *settings = AudioSettings::default();
+}
+
+fn setup_view_root(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let on_reset = commands.register_system(reset_settings);
+
+    commands
+        .spawn((
+            TabGroup::default(),
+            StyledPanel::builder().fill().build_with((
+                Spawn(
+                    StyledText::builder()
+                        .content("Binding")
+                        .font_size(24.0)
+                        .build(),
+                ),
+                Spawn((
+                    StyledText::builder().content("").font_size(14.0).build(),
+                    SettingsText,
+                )),
+                Spawn((
+                    StyledCheckbox::builder().caption("Locked").build(),
+                    Bind::resource(|s: &mut AudioSettings| &mut s.locked),
+                )),
+                Spawn((
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        width: Val::Px(240.),
+                        row_gap: Val::Px(12.0),
+                        ..default()
+                    },
+                    Fieldset::default(),
+                    SettingsFieldset,
+                    Children::spawn((
+                        Spawn((
+                            StyledSlider::builder().min(0.0).max(100.0).build(),
+                            Bind::resource(|s: &mut AudioSettings| &mut s.volume),
+                        )),
+                        Spawn((
+                            StyledProgress::builder().build(),
+                            Bind::resource(|s: &mut AudioSettings| &mut s.volume),
+                        )),
+                        Spawn((
+                            StyledSwitch::builder().build(),
+                            Bind::resource(|s: &mut AudioSettings| &mut s.muted),
+                        )),
+                        Spawn((
+                            StyledCheckbox::builder().caption("Muted").build(),
+                            Bind::resource(|s: &mut AudioSettings| &mut s.muted),
+                        )),
+                        Spawn((
+                            RadioGroup::<AudioOutput>::builder()
+                                .direction(RadioButtonDirection::Vertical)
+                                .option(AudioOutput::Speakers, "Speakers")
+                                .option(AudioOutput::Headphones, "Headphones")
+                                .build(),
+                            Bind::resource(|s: &mut AudioSettings| &mut s.output),
+                        )),
+                        Spawn(
+                            StyledButton::builder()
+                                .text("Reset")
+                                .on_click(on_reset)
+                                .variant(ButtonVariant::Outline)
+                                .build(),
+                        ),
+                    )),
+                )),
+            )),
+        ))
+        .observe(log_volume_changes)
+        .observe(log_mute_changes)
+        .observe(log_checkbox_changes);
+}
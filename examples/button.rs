@@ -1,4 +1,9 @@
-use bevy::{input_focus::tab_navigation::TabGroup, prelude::*, winit::WinitSettings};
+use std::time::Duration;
+
+use bevy::{
+    input_focus::tab_navigation::TabGroup, prelude::*, tasks::AsyncComputeTaskPool,
+    winit::WinitSettings,
+};
 use bevy_asset_loader::prelude::*;
 use bevy_asset_loader::{
     loading_state::{LoadingState, LoadingStateAppExt, config::ConfigureLoadingState},
@@ -33,7 +38,6 @@ fn main() {
                 .load_collection::<FontAssets>(),
         )
         .add_systems(OnEnter(AssetsLoadingState::Loaded), setup_view_root)
-        .add_systems(Update, update_root_background)
         .run();
 }
 
@@ -41,18 +45,7 @@ fn main() {
 struct ThemeToggleButton;
 
 #[derive(Component)]
-struct RootWindow;
-
-fn update_root_background(
-    theme_manager: Res<ThemeManager>,
-    mut query: Query<&mut BackgroundColor, With<RootWindow>>,
-) {
-    for mut bg_color in query.iter_mut() {
-        let theme_styles = theme_manager.styles.clone();
-        let color = theme_styles.panel.background_color;
-        bg_color.0 = color;
-    }
-}
+struct SaveButton;
 
 fn toggle_mode(mut theme_manager: ResMut<ThemeManager>) {
     let current_mode = theme_manager.current_mode;
@@ -63,17 +56,40 @@ fn toggle_mode(mut theme_manager: ResMut<ThemeManager>) {
     theme_manager.set_theme_mode(new_mode);
 }
 
+fn navigate(In(href): In<String>) {
+    info!("Navigate to {}", href);
+}
+
+// Simulates a slow save, the button shows a spinner until the task completes
+fn save(mut commands: Commands, query: Query<Entity, With<SaveButton>>) {
+    for entity in query.iter() {
+        let task = AsyncComputeTaskPool::get().spawn(async {
+            std::thread::sleep(Duration::from_secs(2));
+        });
+        commands.entity(entity).insert(ButtonLoadingTask(task));
+    }
+}
+
 fn set_theme(id: ThemeId) -> impl FnMut(ResMut<ThemeManager>) + Clone {
     move |mut theme_manager: ResMut<ThemeManager>| {
         theme_manager.set_theme(id.clone());
     }
 }
 
-fn setup_view_root(mut commands: Commands, font_assets: Res<FontAssets>) {
+fn setup_view_root(
+    mut commands: Commands,
+    font_assets: Res<FontAssets>,
+    mut icon_registry: ResMut<IconRegistry>,
+) {
     let FontAssets { font_icons, .. } = font_assets.into_inner();
     commands.spawn(Camera2d);
 
+    // Theme icons are glyphs in the icon font
+    icon_registry.set_default_font(font_icons.clone());
+
     let on_toogle_theme_mode = commands.register_system(toggle_mode);
+    let on_navigate = commands.register_system(navigate);
+    let on_save = commands.register_system(save);
 
     // Example theme change handlers (register your real handlers)
     let on_default_theme = commands.register_system(set_theme(ThemeId("default".into())));
@@ -86,21 +102,8 @@ fn setup_view_root(mut commands: Commands, font_assets: Res<FontAssets>) {
     let on_violet_theme = commands.register_system(set_theme(ThemeId("violet".into())));
 
     commands.spawn((
-        Node {
-            display: Display::Flex,
-            flex_direction: FlexDirection::Column,
-            position_type: PositionType::Absolute,
-            left: Val::Px(0.),
-            top: Val::Px(0.),
-            right: Val::Px(0.),
-            bottom: Val::Px(0.),
-            padding: UiRect::all(Val::Px(3.)),
-            row_gap: Val::Px(18.),
-            ..Default::default()
-        },
-        RootWindow,
         TabGroup::default(),
-        Children::spawn((
+        StyledPanel::builder().fill().build_with((
             // Theme selection row
             Spawn((
                 Node {
@@ -184,8 +187,7 @@ fn setup_view_root(mut commands: Commands, font_assets: Res<FontAssets>) {
                 },
                 Children::spawn((Spawn((
                     StyledButton::builder()
-                        .icon("theme_mode_toggle")
-                        .font(font_icons.clone())
+                        .icon_only("theme_mode_toggle", "Toggle theme mode")
                         .on_click(on_toogle_theme_mode)
                         .variant(ButtonVariant::Secondary)
                         .build(),
@@ -247,6 +249,14 @@ fn setup_view_root(mut commands: Commands, font_assets: Res<FontAssets>) {
                             .variant(ButtonVariant::Ghost)
                             .build(),
                     ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Link")
+                            .variant(ButtonVariant::Link)
+                            .href("/docs")
+                            .on_navigate(on_navigate)
+                            .build(),
+                    ),
                 )),
             )),
             Spawn(
@@ -304,6 +314,89 @@ fn setup_view_root(mut commands: Commands, font_assets: Res<FontAssets>) {
                     ),
                 )),
             )),
+            Spawn(
+                StyledText::builder()
+                    .content("Icons")
+                    .font_size(18.0)
+                    .build(),
+            ),
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::Start,
+                    align_items: AlignItems::Center,
+                    align_content: AlignContent::Center,
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    column_gap: Val::Px(6.0),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Leading")
+                            .leading_icon("theme_mode_toggle")
+                            .variant(ButtonVariant::Outline)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Trailing")
+                            .trailing_icon("theme_mode_toggle")
+                            .variant(ButtonVariant::Outline)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .icon_only("theme_mode_toggle", "Theme mode")
+                            .variant(ButtonVariant::Outline)
+                            .build(),
+                    ),
+                )),
+            )),
+            Spawn(
+                StyledText::builder()
+                    .content("Loading")
+                    .font_size(18.0)
+                    .build(),
+            ),
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::Start,
+                    align_items: AlignItems::Center,
+                    align_content: AlignContent::Center,
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    column_gap: Val::Px(6.0),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Loading")
+                            .loading(true)
+                            .variant(ButtonVariant::Primary)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Please wait")
+                            .loading(true)
+                            .loading_placement(ButtonLoadingPlacement::Leading)
+                            .variant(ButtonVariant::Outline)
+                            .build(),
+                    ),
+                    Spawn((
+                        StyledButton::builder()
+                            .text("Save")
+                            .on_click(on_save)
+                            .variant(ButtonVariant::Secondary)
+                            .build(),
+                        SaveButton,
+                    )),
+                )),
+            )),
         )),
     ));
 }
@@ -0,0 +1,129 @@
+use bevy::{input_focus::tab_navigation::TabGroup, prelude::*, winit::WinitSettings};
+use bevy_styled_widgets::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, StyledWidgetsPlugin))
+        .insert_resource(ThemeManager::default())
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup_view_root)
+        .run();
+}
+
+fn toggle_mode(mut theme_manager: ResMut<ThemeManager>) {
+    let current_mode = theme_manager.current_mode;
+    let new_mode = match current_mode {
+        ThemeMode::Light => ThemeMode::Dark,
+        ThemeMode::Dark => ThemeMode::Light,
+    };
+    theme_manager.set_theme_mode(new_mode);
+}
+
+fn log_action(name: &'static str) -> impl FnMut() + Clone {
+    move || info!("{} clicked", name)
+}
+
+fn setup_view_root(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let on_toogle_theme_mode = commands.register_system(toggle_mode);
+    let on_bold = commands.register_system(log_action("Bold"));
+    let on_italic = commands.register_system(log_action("Italic"));
+    let on_underline = commands.register_system(log_action("Underline"));
+    let on_save = commands.register_system(log_action("Save"));
+    let on_save_as = commands.register_system(log_action("Save as"));
+    let on_save_all = commands.register_system(log_action("Save all"));
+
+    commands.spawn((
+        Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.),
+            top: Val::Px(0.),
+            right: Val::Px(0.),
+            bottom: Val::Px(0.),
+            ..Default::default()
+        },
+        TabGroup::default(),
+        Children::spawn((Spawn(
+            StyledPanel::builder()
+                .variant(PanelVariant::Background)
+                .build_with((
+                    // Light / Dark toggle row
+                    Spawn((
+                        Node {
+                            display: Display::Flex,
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::End,
+                            ..default()
+                        },
+                        Children::spawn((Spawn(
+                            StyledButton::builder()
+                                .text("Toggle mode")
+                                .on_click(on_toogle_theme_mode)
+                                .variant(ButtonVariant::Secondary)
+                                .build(),
+                        ),)),
+                    )),
+                    Spawn(
+                        StyledText::builder()
+                            .content("Button group")
+                            .font_size(18.0)
+                            .build(),
+                    ),
+                    Spawn((
+                        Node {
+                            display: Display::Flex,
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(12.0),
+                            align_items: AlignItems::Start,
+                            ..default()
+                        },
+                        Children::spawn((
+                            Spawn(
+                                StyledButtonGroup::builder()
+                                    .label("Text style")
+                                    .variant(ButtonVariant::Outline)
+                                    .button(StyledButton::builder().text("Bold").on_click(on_bold))
+                                    .button(
+                                        StyledButton::builder().text("Italic").on_click(on_italic),
+                                    )
+                                    .button(
+                                        StyledButton::builder()
+                                            .text("Underline")
+                                            .on_click(on_underline),
+                                    )
+                                    .build(),
+                            ),
+                            Spawn(
+                                StyledButtonGroup::builder()
+                                    .orientation(ButtonGroupOrientation::Vertical)
+                                    .variant(ButtonVariant::Secondary)
+                                    .size(ButtonSize::Small)
+                                    .button(StyledButton::builder().text("Top"))
+                                    .button(StyledButton::builder().text("Middle"))
+                                    .button(StyledButton::builder().text("Bottom"))
+                                    .build(),
+                            ),
+                        )),
+                    )),
+                    Spawn(
+                        StyledText::builder()
+                            .content("Split button")
+                            .font_size(18.0)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledSplitButton::builder()
+                            .text("Save")
+                            .on_click(on_save)
+                            .trigger_label("More save options")
+                            .item("Save as...", on_save_as)
+                            .item("Save all", on_save_all)
+                            .build(),
+                    ),
+                )),
+        ),)),
+    ));
+}
@@ -8,7 +8,8 @@ fn main() {
         .insert_resource(ThemeManager::default())
         .insert_resource(WinitSettings::desktop_app())
         .add_systems(Startup, setup_view_root)
-        .add_systems(Update, update_root_background)
+        .add_observer(on_select_all_changed)
+        .add_systems(Update, update_select_all)
         .add_systems(
             Update,
             update_theme_toggle_button.run_if(resource_exists_and_changed::<ThemeManager>),
@@ -23,16 +24,46 @@ pub struct ThemeToggleButton;
 pub struct ThemeToggleCheckbox;
 
 #[derive(Component)]
-struct RootWindow;
+struct SelectAll;
 
-fn update_root_background(
-    theme_manager: Res<ThemeManager>,
-    mut query: Query<&mut BackgroundColor, With<RootWindow>>,
+#[derive(Component)]
+struct SelectItem;
+
+// The header is checked, unchecked or indeterminate depending on how many items are selected
+fn update_select_all(
+    q_items: Query<Ref<StyledCheckbox>, (With<SelectItem>, Without<SelectAll>)>,
+    mut q_header: Query<&mut StyledCheckbox, With<SelectAll>>,
+) {
+    if !q_items.iter().any(|item| item.is_changed()) {
+        return;
+    }
+    let selected = q_items.iter().filter(|item| item.checked).count();
+    let state = match selected {
+        0 => CheckboxState::Unchecked,
+        n if n == q_items.iter().len() => CheckboxState::Checked,
+        _ => CheckboxState::Indeterminate,
+    };
+    for mut header in q_header.iter_mut() {
+        if header.state() != state {
+            header.set_state(state);
+        }
+    }
+}
+
+// Clicking the header selects or clears every item
+fn on_select_all_changed(
+    trigger: Trigger<WidgetChanged<CheckboxState>>,
+    q_header: Query<(), With<SelectAll>>,
+    mut q_items: Query<&mut StyledCheckbox, With<SelectItem>>,
 ) {
-    for mut bg_color in query.iter_mut() {
-        let theme_styles = theme_manager.styles.clone();
-        let color = theme_styles.panel.background_color;
-        bg_color.0 = color;
+    if !q_header.contains(trigger.target()) {
+        return;
+    }
+    let checked = trigger.event().new == CheckboxState::Checked;
+    for mut item in q_items.iter_mut() {
+        if item.checked != checked {
+            item.checked = checked;
+        }
     }
 }
 
@@ -48,10 +79,10 @@ fn update_theme_toggle_button(
 ) {
     for mut toggle in query.iter_mut() {
         let icon = match theme_manager.current_mode {
-            ThemeMode::Light => "dark.png",
-            ThemeMode::Dark => "light.png",
+            ThemeMode::Light => "theme_mode_dark",
+            ThemeMode::Dark => "theme_mode_light",
         };
-        toggle.icon = Some(icon.to_string());
+        toggle.icon = Some(icon.into());
     }
 }
 
@@ -76,9 +107,16 @@ fn update_light_dark_theme(
     }
 }
 
-fn setup_view_root(mut commands: Commands) {
+fn setup_view_root(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut icon_registry: ResMut<IconRegistry>,
+) {
     commands.spawn(Camera2d);
 
+    // Theme icons are glyphs in the icon font
+    icon_registry.set_default_font(asset_server.load("fonts/font-icons.ttf"));
+
     let on_toogle_theme_mode = commands.register_system(update_light_dark_theme);
 
     // Example theme change handlers (register your real handlers)
@@ -92,21 +130,8 @@ fn setup_view_root(mut commands: Commands) {
     let on_violet_theme = commands.register_system(set_theme(ThemeId("violet".into())));
 
     commands.spawn((
-        Node {
-            display: Display::Flex,
-            flex_direction: FlexDirection::Column,
-            position_type: PositionType::Absolute,
-            left: Val::Px(0.),
-            top: Val::Px(0.),
-            right: Val::Px(0.),
-            bottom: Val::Px(0.),
-            padding: UiRect::all(Val::Px(3.)),
-            row_gap: Val::Px(6.),
-            ..Default::default()
-        },
-        RootWindow,
         TabGroup::default(),
-        Children::spawn((
+        StyledPanel::builder().fill().build_with((
             // Theme selection row
             Spawn((
                 Node {
@@ -344,14 +369,53 @@ fn setup_view_root(mut commands: Commands) {
                     column_gap: Val::Px(6.0),
                     ..default()
                 },
-                Children::spawn((Spawn((
+                Children::spawn((Spawn(
                     StyledCheckbox::builder()
                         .variant(CheckboxVariant::Default)
                         .checked(true)
                         .disabled()
                         .build(),
-                    InteractionDisabled,
-                )),)),
+                ),)),
+            )),
+            Spawn(
+                StyledText::builder()
+                    .content("Select all")
+                    .font_size(24.0)
+                    .build(),
+            ),
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Start,
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    row_gap: Val::Px(3.0),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn((
+                        StyledCheckbox::builder()
+                            .caption("All fruits")
+                            .indeterminate()
+                            .build(),
+                        SelectAll,
+                    )),
+                    Spawn((
+                        StyledCheckbox::builder()
+                            .caption("Apples")
+                            .checked(true)
+                            .build(),
+                        SelectItem,
+                    )),
+                    Spawn((
+                        StyledCheckbox::builder().caption("Pears").build(),
+                        SelectItem,
+                    )),
+                    Spawn((
+                        StyledCheckbox::builder().caption("Plums").build(),
+                        SelectItem,
+                    )),
+                )),
             )),
         )),
     ));
@@ -0,0 +1,106 @@
+use bevy::{input_focus::tab_navigation::TabGroup, prelude::*, winit::WinitSettings};
+use bevy_styled_widgets::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, StyledWidgetsPlugin))
+        .insert_resource(ThemeManager::default())
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup_view_root)
+        .add_systems(Update, update_error_text)
+        .run();
+}
+
+#[derive(Component)]
+struct ToppingsGroup;
+
+#[derive(Component)]
+struct ErrorText;
+
+// Show why the last change of the toppings was rejected
+fn update_error_text(
+    q_group: Query<&StyledCheckboxGroup, (With<ToppingsGroup>, Changed<StyledCheckboxGroup>)>,
+    mut q_text: Query<&mut Text, With<ErrorText>>,
+) {
+    for group in q_group.iter() {
+        let error = group
+            .error
+            .map(|error| error.to_string())
+            .unwrap_or_default();
+        for mut text in q_text.iter_mut() {
+            if text.0 != error {
+                text.0 = error.clone();
+            }
+        }
+    }
+}
+
+// The group reports its selection as one event, which bubbles like the checkbox events
+fn log_selection(trigger: Trigger<WidgetChanged<Vec<String>>>) {
+    let event = trigger.event();
+    info!("{:?}: {:?} -> {:?}", event.entity, event.old, event.new);
+}
+
+fn setup_view_root(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            TabGroup::default(),
+            StyledPanel::builder().fill().build_with((
+                Spawn(
+                    StyledText::builder()
+                        .content("Permissions")
+                        .font_size(24.0)
+                        .build(),
+                ),
+                Spawn(
+                    StyledCheckboxGroup::builder()
+                        .label("Permissions")
+                        .select_all("All permissions")
+                        .checkboxes([
+                            StyledCheckbox::builder()
+                                .caption("Read")
+                                .value("read")
+                                .checked(true),
+                            StyledCheckbox::builder().caption("Write").value("write"),
+                            StyledCheckbox::builder().caption("Delete").value("delete"),
+                            StyledCheckbox::builder()
+                                .caption("Administer")
+                                .value("admin")
+                                .disabled(),
+                        ])
+                        .build(),
+                ),
+                Spawn(
+                    StyledText::builder()
+                        .content("Toppings (choose 1 to 2)")
+                        .font_size(24.0)
+                        .build(),
+                ),
+                Spawn((
+                    StyledCheckboxGroup::builder()
+                        .label("Toppings")
+                        .min(1)
+                        .max(2)
+                        .checkboxes([
+                            StyledCheckbox::builder()
+                                .caption("Cheese")
+                                .value("cheese")
+                                .checked(true),
+                            StyledCheckbox::builder()
+                                .caption("Mushrooms")
+                                .value("mushrooms"),
+                            StyledCheckbox::builder().caption("Olives").value("olives"),
+                        ])
+                        .build(),
+                    ToppingsGroup,
+                )),
+                Spawn((
+                    StyledText::builder().content("").font_size(14.0).build(),
+                    ErrorText,
+                )),
+            )),
+        ))
+        .observe(log_selection);
+}
@@ -19,27 +19,12 @@ fn main() {
         .insert_resource(ThemeManager::default())
         .insert_resource(WinitSettings::desktop_app())
         .add_systems(Startup, setup_view_root)
-        .add_systems(Update, update_root_background)
         .run();
 }
 
 #[derive(Component)]
 struct ThemeToggleButton;
 
-#[derive(Component)]
-struct RootWindow;
-
-fn update_root_background(
-    theme_manager: Res<ThemeManager>,
-    mut query: Query<&mut BackgroundColor, With<RootWindow>>,
-) {
-    for mut bg_color in query.iter_mut() {
-        let theme_styles = theme_manager.styles.clone();
-        let color = theme_styles.panel.background_color;
-        bg_color.0 = color;
-    }
-}
-
 fn toggle_mode(mut theme_manager: ResMut<ThemeManager>) {
     let current_mode = theme_manager.current_mode;
     let new_mode = match current_mode {
@@ -71,21 +56,8 @@ fn setup_view_root(mut commands: Commands, mut font_system: ResMut<CosmicFontSys
     let on_violet_theme = commands.register_system(set_theme(ThemeId("violet".into())));
 
     commands.spawn((
-        Node {
-            display: Display::Flex,
-            flex_direction: FlexDirection::Column,
-            position_type: PositionType::Absolute,
-            left: Val::Px(0.),
-            top: Val::Px(0.),
-            right: Val::Px(0.),
-            bottom: Val::Px(0.),
-            padding: UiRect::all(Val::Px(3.)),
-            row_gap: Val::Px(18.),
-            ..Default::default()
-        },
-        RootWindow,
         TabGroup::default(),
-        Children::spawn((
+        StyledPanel::builder().fill().build_with((
             // Theme selection row
             Spawn((
                 Node {
@@ -169,7 +141,7 @@ fn setup_view_root(mut commands: Commands, mut font_system: ResMut<CosmicFontSys
                 },
                 Children::spawn((Spawn((
                     StyledButton::builder()
-                        .icon("theme_mode_toggle")
+                        .icon_only("theme_mode_toggle", "Toggle theme mode")
                         .on_click(on_toogle_theme_mode)
                         .variant(ButtonVariant::Secondary)
                         .build(),
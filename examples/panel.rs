@@ -0,0 +1,95 @@
+use bevy::{input_focus::tab_navigation::TabGroup, prelude::*, winit::WinitSettings};
+use bevy_styled_widgets::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, StyledWidgetsPlugin))
+        .insert_resource(ThemeManager::default())
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup_view_root)
+        .run();
+}
+
+fn toggle_mode(mut theme_manager: ResMut<ThemeManager>) {
+    let current_mode = theme_manager.current_mode;
+    let new_mode = match current_mode {
+        ThemeMode::Light => ThemeMode::Dark,
+        ThemeMode::Dark => ThemeMode::Light,
+    };
+    theme_manager.set_theme_mode(new_mode);
+}
+
+fn setup_view_root(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let on_toogle_theme_mode = commands.register_system(toggle_mode);
+
+    commands.spawn((
+        TabGroup::default(),
+        // The root panel replaces the hand written background system
+        StyledPanel::builder()
+            .variant(PanelVariant::Background)
+            .fill()
+            .build_with((
+                // Light / Dark toggle row
+                Spawn((
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::End,
+                        ..default()
+                    },
+                    Children::spawn((Spawn(
+                        StyledButton::builder()
+                            .text("Toggle mode")
+                            .on_click(on_toogle_theme_mode)
+                            .variant(ButtonVariant::Secondary)
+                            .build(),
+                    ),)),
+                )),
+                Spawn(
+                    StyledText::builder()
+                        .content("Panels")
+                        .font_size(24.0)
+                        .build(),
+                ),
+                Spawn((
+                    Node {
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(12.0),
+                        ..default()
+                    },
+                    Children::spawn((
+                        Spawn(
+                            StyledCard::builder()
+                                .header("Card")
+                                .footer("Uses the card colors")
+                                .build_with((Spawn(StyledText::body("Card body")),)),
+                        ),
+                        Spawn(
+                            StyledPanel::builder()
+                                .variant(PanelVariant::Popover)
+                                .bordered(true)
+                                .header("Popover")
+                                .build_with((Spawn(StyledText::body("Popover body")),)),
+                        ),
+                        Spawn(
+                            StyledPanel::builder()
+                                .variant(PanelVariant::Sidebar)
+                                .size(PanelSize::Small)
+                                .header("Sidebar")
+                                .build_with((Spawn(StyledText::body("Sidebar body")),)),
+                        ),
+                        Spawn(
+                            StyledPanel::builder()
+                                .variant(PanelVariant::Muted)
+                                .size(PanelSize::Large)
+                                .footer("Muted footer")
+                                .build_with((Spawn(StyledText::body("Muted body")),)),
+                        ),
+                    )),
+                )),
+            )),
+    ));
+}
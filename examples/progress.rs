@@ -7,27 +7,12 @@ fn main() {
         .insert_resource(ThemeManager::default())
         .insert_resource(WinitSettings::desktop_app())
         .add_systems(Startup, setup_view_root)
-        .add_systems(Update, update_root_background)
         .run();
 }
 
 #[derive(Component)]
 struct ThemeToggleButton;
 
-#[derive(Component)]
-struct RootWindow;
-
-fn update_root_background(
-    theme_manager: Res<ThemeManager>,
-    mut query: Query<&mut BackgroundColor, With<RootWindow>>,
-) {
-    for mut bg_color in query.iter_mut() {
-        let theme_styles = theme_manager.styles.clone();
-        let color = theme_styles.panel.background_color;
-        bg_color.0 = color;
-    }
-}
-
 fn toggle_mode(mut theme_manager: ResMut<ThemeManager>) {
     let current_mode = theme_manager.current_mode;
     let new_mode = match current_mode {
@@ -37,6 +22,17 @@ fn toggle_mode(mut theme_manager: ResMut<ThemeManager>) {
     theme_manager.set_theme_mode(new_mode);
 }
 
+// Progress widgets follow their `StyledProgress` value
+fn advance_progress(mut query: Query<&mut StyledProgress>) {
+    for mut progress in query.iter_mut() {
+        progress.value = if progress.value >= progress.max {
+            0.0
+        } else {
+            progress.value + 10.0
+        };
+    }
+}
+
 fn set_theme(id: ThemeId) -> impl FnMut(ResMut<ThemeManager>) + Clone {
     move |mut theme_manager: ResMut<ThemeManager>| {
         theme_manager.set_theme(id.clone());
@@ -47,6 +43,7 @@ fn setup_view_root(mut commands: Commands) {
     commands.spawn(Camera2d);
 
     let on_toogle_theme_mode = commands.register_system(toggle_mode);
+    let on_advance_progress = commands.register_system(advance_progress);
 
     // Example theme change handlers (register your real handlers)
     let on_default_theme = commands.register_system(set_theme(ThemeId("default".into())));
@@ -59,21 +56,8 @@ fn setup_view_root(mut commands: Commands) {
     let on_violet_theme = commands.register_system(set_theme(ThemeId("violet".into())));
 
     commands.spawn((
-        Node {
-            display: Display::Flex,
-            flex_direction: FlexDirection::Column,
-            position_type: PositionType::Absolute,
-            left: Val::Px(0.),
-            top: Val::Px(0.),
-            right: Val::Px(0.),
-            bottom: Val::Px(0.),
-            padding: UiRect::all(Val::Px(3.)),
-            row_gap: Val::Px(18.),
-            ..Default::default()
-        },
-        RootWindow,
         TabGroup::default(),
-        Children::spawn((
+        StyledPanel::builder().fill().build_with((
             // Theme selection row
             Spawn((
                 Node {
@@ -157,7 +141,7 @@ fn setup_view_root(mut commands: Commands) {
                 },
                 Children::spawn((Spawn((
                     StyledButton::builder()
-                        .icon("theme_mode_toggle")
+                        .icon_only("theme_mode_toggle", "Toggle theme mode")
                         .on_click(on_toogle_theme_mode)
                         .variant(ButtonVariant::Secondary)
                         .build(),
@@ -180,9 +164,19 @@ fn setup_view_root(mut commands: Commands) {
                     align_content: AlignContent::Center,
                     width: Val::Px(200.), // change this as required
                     padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    row_gap: Val::Px(12.0),
                     ..default()
                 },
-                Children::spawn((Spawn(StyledProgress::builder().value(80.).build()),)),
+                Children::spawn((
+                    Spawn(StyledProgress::builder().value(80.).max(100.).build()),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Advance")
+                            .on_click(on_advance_progress)
+                            .variant(ButtonVariant::Outline)
+                            .build(),
+                    ),
+                )),
             )),
         )),
     ));
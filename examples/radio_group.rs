@@ -1,57 +1,35 @@
 use bevy::{input_focus::tab_navigation::TabGroup, prelude::*, winit::WinitSettings};
-use bevy_core_widgets::{Checked, InteractionDisabled};
 use bevy_styled_widgets::prelude::*;
 
 fn main() {
     App::new()
-        .add_plugins((DefaultPlugins, StyledWidgetsPlugin))
+        .add_plugins((
+            DefaultPlugins,
+            StyledWidgetsPlugin,
+            RadioGroupPlugin::<ThemeMode>::default(),
+        ))
         .insert_resource(ThemeManager::default())
         .insert_resource(WinitSettings::desktop_app())
         .add_systems(Startup, setup_view_root)
-        .add_systems(Update, update_root_background)
+        .add_systems(Update, update_theme_mode)
         .run();
 }
 
-#[derive(Component)]
-struct RootWindow;
-
-fn update_root_background(
-    theme_manager: Res<ThemeManager>,
-    mut query: Query<&mut BackgroundColor, With<RootWindow>>,
-) {
-    for mut bg_color in query.iter_mut() {
-        let theme_styles = theme_manager.styles.clone();
-        let color = theme_styles.panel.background_color;
-        bg_color.0 = color;
-    }
-}
-
 fn set_theme(id: ThemeId) -> impl FnMut(ResMut<ThemeManager>) + Clone {
     move |mut theme_manager: ResMut<ThemeManager>| {
         theme_manager.set_theme(id.clone());
     }
 }
 
-fn run_on_radio_selected(
-    In(selected_entity): In<Entity>,
-    q_radio_group: Query<&Children>,
-    query: Query<(&ChildOf, &RadioValue)>,
+// The group's value follows the checked radio
+fn update_theme_mode(
+    q_group: Query<&RadioGroup<ThemeMode>, Changed<RadioGroup<ThemeMode>>>,
     mut theme_manager: ResMut<ThemeManager>,
-    mut commands: Commands,
 ) {
-    if let Ok((child_of, radio_value)) = query.get(selected_entity) {
-        match radio_value.0.as_str() {
-            "dark" => theme_manager.set_theme_mode(ThemeMode::Dark),
-            "light" => theme_manager.set_theme_mode(ThemeMode::Light),
-            &_ => warn!("Unhandled radio value: {}", radio_value.0.to_string()),
-        }
-
-        let group_children = q_radio_group.get(child_of.parent()).unwrap();
-        for radio_child in group_children.iter() {
-            if let Ok((_, value)) = query.get(radio_child) {
-                commands
-                    .entity(radio_child)
-                    .insert(Checked(value.0 == radio_value.0));
+    for group in q_group.iter() {
+        if let Some(mode) = group.value {
+            if theme_manager.current_mode != mode {
+                theme_manager.set_theme_mode(mode);
             }
         }
     }
@@ -60,8 +38,6 @@ fn run_on_radio_selected(
 fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
     commands.spawn(Camera2d);
 
-    let radio_on_change_system_id = commands.register_system(run_on_radio_selected);
-
     // Example theme change handlers (register your real handlers)
     let on_default_theme = commands.register_system(set_theme(ThemeId("default".into())));
     let on_red_theme = commands.register_system(set_theme(ThemeId("red".into())));
@@ -72,38 +48,43 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
     let on_yellow_theme = commands.register_system(set_theme(ThemeId("yellow".into())));
     let on_violet_theme = commands.register_system(set_theme(ThemeId("violet".into())));
 
-    let (group_bundle, children) = StyledRadioGroup::builder()
-        .on_change(radio_on_change_system_id)
+    let (card_group_bundle, card_bundles) = StyledRadioGroup::builder()
+        .direction(RadioButtonDirection::Vertical)
+        .variant(RadioButtonVariant::Card)
         .children([
             StyledRadioButton::builder()
-                .caption("Light mode")
-                .value("light")
-                .checked(theme.current_mode == ThemeMode::Light),
+                .caption("Starter")
+                .description("One project, community support")
+                .value("starter")
+                .checked(true),
             StyledRadioButton::builder()
-                .caption("Dark mode")
-                .value("dark")
-                .checked(theme.current_mode == ThemeMode::Dark),
+                .caption("Pro")
+                .description("Unlimited projects, email support")
+                .icon("theme_mode_toggle")
+                .value("pro"),
+            StyledRadioButton::builder()
+                .caption("Enterprise")
+                .description("Contact sales")
+                .value("enterprise")
+                .disabled(),
+        ])
+        .build();
+
+    let (segmented_group_bundle, segment_bundles) = StyledRadioGroup::builder()
+        .direction(RadioButtonDirection::Horizontal)
+        .variant(RadioButtonVariant::Segmented)
+        .required()
+        .children([
+            StyledRadioButton::builder().caption("Left").value("left"),
+            StyledRadioButton::builder()
+                .caption("Center")
+                .value("center"),
+            StyledRadioButton::builder().caption("Right").value("right"),
         ])
-        .direction(RadioButtonDirection::Vertical)
         .build();
 
     commands
-        .spawn((
-            RootWindow,
-            Node {
-                display: Display::Flex,
-                flex_direction: FlexDirection::Column,
-                position_type: PositionType::Absolute,
-                left: Val::Px(0.),
-                top: Val::Px(0.),
-                right: Val::Px(0.),
-                bottom: Val::Px(0.),
-                padding: UiRect::all(Val::Px(3.)),
-                row_gap: Val::Px(6.),
-                ..Default::default()
-            },
-            TabGroup::default(),
-        ))
+        .spawn((StyledPanel::builder().fill().build(), TabGroup::default()))
         .with_children(|parent| {
             parent.spawn((
                 Node {
@@ -182,21 +163,63 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                     .build(),
             );
 
+            parent.spawn(
+                RadioGroup::<ThemeMode>::builder()
+                    .direction(RadioButtonDirection::Vertical)
+                    .required()
+                    .value(theme.current_mode)
+                    .option(ThemeMode::Light, "Light mode")
+                    .option(ThemeMode::Dark, "Dark mode")
+                    .build(),
+            );
+
+            // Card section
+            parent.spawn(
+                StyledText::builder()
+                    .content("Cards")
+                    .font_size(24.0)
+                    .build(),
+            );
+
             parent
-                .spawn((Node {
+                .spawn(Node {
                     display: Display::Flex,
-                    flex_direction: FlexDirection::Row,
-                    align_items: AlignItems::Start,
-                    align_content: AlignContent::Start,
-                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
-                    row_gap: Val::Px(6.0),
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Px(320.0),
+                    margin: UiRect::horizontal(Val::Px(12.0)),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn(card_group_bundle)
+                        .with_children(|group_parent| {
+                            for card in card_bundles {
+                                group_parent.spawn(card);
+                            }
+                        });
+                });
+
+            // Segmented section
+            parent.spawn(
+                StyledText::builder()
+                    .content("Segmented")
+                    .font_size(24.0)
+                    .build(),
+            );
+
+            parent
+                .spawn(Node {
+                    margin: UiRect::horizontal(Val::Px(12.0)),
                     ..default()
-                },))
-                .insert(group_bundle)
-                .with_children(|group_parent| {
-                    for child in children {
-                        group_parent.spawn(child);
-                    }
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn(segmented_group_bundle)
+                        .with_children(|group_parent| {
+                            for segment in segment_bundles {
+                                group_parent.spawn(segment);
+                            }
+                        });
                 });
 
             // Sizes section
@@ -306,14 +329,13 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                     column_gap: Val::Px(6.0),
                     ..default()
                 },
-                Children::spawn((Spawn((
+                Children::spawn((Spawn(
                     StyledRadioButton::builder()
                         .variant(RadioButtonVariant::Default)
                         .checked(true)
                         .disabled()
                         .build(),
-                    InteractionDisabled,
-                )),)),
+                ),)),
             ));
         });
 }
@@ -0,0 +1,226 @@
+use bevy::{input_focus::tab_navigation::TabGroup, prelude::*, winit::WinitSettings};
+use bevy_styled_widgets::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, StyledWidgetsPlugin))
+        .insert_resource(ThemeManager::default())
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup_view_root)
+        .add_observer(log_range_changes)
+        .run();
+}
+
+#[derive(Component)]
+struct ThemeToggleButton;
+
+fn toggle_mode(mut theme_manager: ResMut<ThemeManager>) {
+    let current_mode = theme_manager.current_mode;
+    let new_mode = match current_mode {
+        ThemeMode::Light => ThemeMode::Dark,
+        ThemeMode::Dark => ThemeMode::Light,
+    };
+    theme_manager.set_theme_mode(new_mode);
+}
+
+fn set_theme(id: ThemeId) -> impl FnMut(ResMut<ThemeManager>) + Clone {
+    move |mut theme_manager: ResMut<ThemeManager>| {
+        theme_manager.set_theme(id.clone());
+    }
+}
+
+fn setup_view_root(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let on_toogle_theme_mode = commands.register_system(toggle_mode);
+
+    // Example theme change handlers (register your real handlers)
+    let on_default_theme = commands.register_system(set_theme(ThemeId("default".into())));
+    let on_red_theme = commands.register_system(set_theme(ThemeId("red".into())));
+    let on_rose_theme = commands.register_system(set_theme(ThemeId("rose".into())));
+    let on_orange_theme = commands.register_system(set_theme(ThemeId("orange".into())));
+    let on_green_theme = commands.register_system(set_theme(ThemeId("green".into())));
+    let on_blue_theme = commands.register_system(set_theme(ThemeId("blue".into())));
+    let on_yellow_theme = commands.register_system(set_theme(ThemeId("yellow".into())));
+    let on_violet_theme = commands.register_system(set_theme(ThemeId("violet".into())));
+
+    commands.spawn((
+        TabGroup::default(),
+        StyledPanel::builder().fill().build_with((
+            // Theme selection row
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::End,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(6.0),
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Default")
+                            .variant(ButtonVariant::Ghost)
+                            .on_click(on_default_theme)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Red")
+                            .variant(ButtonVariant::Ghost)
+                            .on_click(on_red_theme)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Rose")
+                            .variant(ButtonVariant::Ghost)
+                            .on_click(on_rose_theme)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Orange")
+                            .variant(ButtonVariant::Ghost)
+                            .on_click(on_orange_theme)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Green")
+                            .variant(ButtonVariant::Ghost)
+                            .on_click(on_green_theme)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Blue")
+                            .variant(ButtonVariant::Ghost)
+                            .on_click(on_blue_theme)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Yellow")
+                            .variant(ButtonVariant::Ghost)
+                            .on_click(on_yellow_theme)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledButton::builder()
+                            .text("Violet")
+                            .variant(ButtonVariant::Ghost)
+                            .on_click(on_violet_theme)
+                            .build(),
+                    ),
+                )),
+            )),
+            // Light / Dark toggle row
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::End,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(6.0),
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    ..default()
+                },
+                Children::spawn((Spawn((
+                    StyledButton::builder()
+                        .icon_only("theme_mode_toggle", "Toggle theme mode")
+                        .on_click(on_toogle_theme_mode)
+                        .variant(ButtonVariant::Secondary)
+                        .build(),
+                    ThemeToggleButton,
+                )),)),
+            )),
+            // Range slider
+            Spawn(
+                StyledText::builder()
+                    .content("Range Slider")
+                    .font_size(24.0)
+                    .build(),
+            ),
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Start,
+                    align_items: AlignItems::Center,
+                    align_content: AlignContent::Center,
+                    row_gap: Val::Px(12.0),
+                    width: Val::Px(200.),
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn(
+                        StyledRangeSlider::builder()
+                            .max(100.)
+                            .value(20., 80.)
+                            .build(),
+                    ),
+                    // Price filter, the thumbs stay 10 apart
+                    Spawn(
+                        StyledRangeSlider::builder()
+                            .max(500.)
+                            .value(100., 300.)
+                            .step(10.)
+                            .min_gap(10.)
+                            .size(SliderSize::Large)
+                            .build(),
+                    ),
+                    // The thumbs push each other along
+                    Spawn(
+                        StyledRangeSlider::builder()
+                            .max(100.)
+                            .value(40., 60.)
+                            .min_gap(5.)
+                            .push()
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledRangeSlider::builder()
+                            .max(100.)
+                            .value(30., 70.)
+                            .disabled()
+                            .build(),
+                    ),
+                )),
+            )),
+            // Vertical
+            Spawn(
+                StyledText::builder()
+                    .content("Vertical")
+                    .font_size(24.0)
+                    .build(),
+            ),
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::Start,
+                    column_gap: Val::Px(24.0),
+                    height: Val::Px(160.),
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    ..default()
+                },
+                Children::spawn((Spawn(
+                    StyledRangeSlider::builder()
+                        .max(100.)
+                        .value(25., 75.)
+                        .vertical()
+                        .build(),
+                ),)),
+            )),
+        )),
+    ));
+}
+
+fn log_range_changes(trigger: Trigger<WidgetChanged<(f32, f32)>>) {
+    let event = trigger.event();
+    info!("Range changed from {:?} to {:?}", event.old, event.new);
+}
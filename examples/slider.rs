@@ -7,27 +7,12 @@ fn main() {
         .insert_resource(ThemeManager::default())
         .insert_resource(WinitSettings::desktop_app())
         .add_systems(Startup, setup_view_root)
-        .add_systems(Update, update_root_background)
         .run();
 }
 
 #[derive(Component)]
 struct ThemeToggleButton;
 
-#[derive(Component)]
-struct RootWindow;
-
-fn update_root_background(
-    theme_manager: Res<ThemeManager>,
-    mut query: Query<&mut BackgroundColor, With<RootWindow>>,
-) {
-    for mut bg_color in query.iter_mut() {
-        let theme_styles = theme_manager.styles.clone();
-        let color = theme_styles.panel.background_color;
-        bg_color.0 = color;
-    }
-}
-
 fn toggle_mode(mut theme_manager: ResMut<ThemeManager>) {
     let current_mode = theme_manager.current_mode;
     let new_mode = match current_mode {
@@ -59,21 +44,8 @@ fn setup_view_root(mut commands: Commands) {
     let on_violet_theme = commands.register_system(set_theme(ThemeId("violet".into())));
 
     commands.spawn((
-        Node {
-            display: Display::Flex,
-            flex_direction: FlexDirection::Column,
-            position_type: PositionType::Absolute,
-            left: Val::Px(0.),
-            top: Val::Px(0.),
-            right: Val::Px(0.),
-            bottom: Val::Px(0.),
-            padding: UiRect::all(Val::Px(3.)),
-            row_gap: Val::Px(18.),
-            ..Default::default()
-        },
-        RootWindow,
         TabGroup::default(),
-        Children::spawn((
+        StyledPanel::builder().fill().build_with((
             // Theme selection row
             Spawn((
                 Node {
@@ -157,7 +129,7 @@ fn setup_view_root(mut commands: Commands) {
                 },
                 Children::spawn((Spawn((
                     StyledButton::builder()
-                        .icon("theme_mode_toggle")
+                        .icon_only("theme_mode_toggle", "Toggle theme mode")
                         .on_click(on_toogle_theme_mode)
                         .variant(ButtonVariant::Secondary)
                         .build(),
@@ -186,6 +158,183 @@ fn setup_view_root(mut commands: Commands) {
                     StyledSlider::builder().max(100.).min(0.).value(50.).build(),
                 ),)),
             )), // types
+            // Stepped slider, arrows move by 5 and PageUp/PageDown by 25
+            Spawn(
+                StyledText::builder()
+                    .content("Steps")
+                    .font_size(24.0)
+                    .build(),
+            ),
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Start,
+                    align_items: AlignItems::Center,
+                    align_content: AlignContent::Center,
+                    row_gap: Val::Px(12.0),
+                    width: Val::Px(200.),
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(100.)
+                            .value(50.)
+                            .step(5.)
+                            .page_step(25.)
+                            .build(),
+                    ),
+                    // Continuous slider rounded to two decimals
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(1.)
+                            .value(0.5)
+                            .step(0.05)
+                            .precision(2)
+                            .build(),
+                    ),
+                )),
+            )),
+            // Sizes
+            Spawn(
+                StyledText::builder()
+                    .content("Sizes")
+                    .font_size(24.0)
+                    .build(),
+            ),
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Start,
+                    align_items: AlignItems::Center,
+                    align_content: AlignContent::Center,
+                    row_gap: Val::Px(12.0),
+                    width: Val::Px(200.),
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(100.)
+                            .value(20.)
+                            .size(SliderSize::XSmall)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(100.)
+                            .value(40.)
+                            .size(SliderSize::Small)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(100.)
+                            .value(60.)
+                            .size(SliderSize::Large)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(100.)
+                            .value(80.)
+                            .size(SliderSize::XLarge)
+                            .build(),
+                    ),
+                )),
+            )),
+            // Ticks, labels and the value while hovered
+            Spawn(
+                StyledText::builder()
+                    .content("Ticks")
+                    .font_size(24.0)
+                    .build(),
+            ),
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Start,
+                    align_items: AlignItems::Center,
+                    align_content: AlignContent::Center,
+                    row_gap: Val::Px(12.0),
+                    width: Val::Px(200.),
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(100.)
+                            .value(40.)
+                            .step(20.)
+                            .ticks(SliderTicks::Steps)
+                            .tick_labels()
+                            .show_value()
+                            .build(),
+                    ),
+                    // Volume with marks at the quarters, shown as percentages
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(1.)
+                            .value(0.75)
+                            .ticks(SliderTicks::Values(vec![0.0, 0.25, 0.5, 0.75, 1.0]))
+                            .tick_labels()
+                            .show_value()
+                            .format(|value| format!("{:.0}%", value * 100.0))
+                            .build(),
+                    ),
+                )),
+            )),
+            // Vertical mixer channels
+            Spawn(
+                StyledText::builder()
+                    .content("Vertical")
+                    .font_size(24.0)
+                    .build(),
+            ),
+            Spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::Start,
+                    column_gap: Val::Px(24.0),
+                    height: Val::Px(160.),
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(0.0)),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(100.)
+                            .value(75.)
+                            .vertical()
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(100.)
+                            .value(50.)
+                            .step(10.)
+                            .ticks(SliderTicks::Steps)
+                            .show_value()
+                            .vertical()
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledSlider::builder()
+                            .max(100.)
+                            .value(25.)
+                            .size(SliderSize::Large)
+                            .vertical()
+                            .build(),
+                    ),
+                )),
+            )),
         )),
     ));
 }
@@ -8,7 +8,6 @@ fn main() {
         .insert_resource(ThemeManager::default())
         .insert_resource(WinitSettings::desktop_app())
         .add_systems(Startup, setup_view_root)
-        .add_systems(Update, update_root_background)
         .add_systems(
             Update,
             update_theme_toggle_button.run_if(resource_exists_and_changed::<ThemeManager>),
@@ -19,20 +18,6 @@ fn main() {
 #[derive(Component)]
 pub struct ThemeToggleButton;
 
-#[derive(Component)]
-struct RootWindow;
-
-fn update_root_background(
-    theme_manager: Res<ThemeManager>,
-    mut query: Query<&mut BackgroundColor, With<RootWindow>>,
-) {
-    for mut bg_color in query.iter_mut() {
-        let theme_styles = theme_manager.styles.clone();
-        let color = theme_styles.panel.background_color;
-        bg_color.0 = color;
-    }
-}
-
 fn set_theme(id: ThemeId) -> impl FnMut(ResMut<ThemeManager>) + Clone {
     move |mut theme_manager: ResMut<ThemeManager>| {
         theme_manager.set_theme(id.clone());
@@ -46,10 +31,10 @@ fn update_theme_toggle_button(
     println!("update_theme_toggle_button()");
     for mut toggle in query.iter_mut() {
         let icon = match theme_manager.current_mode {
-            ThemeMode::Light => "dark.png",
-            ThemeMode::Dark => "light.png",
+            ThemeMode::Light => "theme_mode_dark",
+            ThemeMode::Dark => "theme_mode_light",
         };
-        toggle.icon = Some(icon.to_string());
+        toggle.icon = Some(icon.into());
     }
 }
 
@@ -72,17 +57,25 @@ fn update_light_dark_theme(
             theme_manager.set_theme_mode(new_mode);
             commands.entity(entity).insert(Checked(checked));
             let icon = match theme_manager.current_mode {
-                ThemeMode::Light => "dark.png",
-                ThemeMode::Dark => "light.png",
+                ThemeMode::Light => "theme_mode_dark",
+                ThemeMode::Dark => "theme_mode_light",
             };
-            styled_toggle.icon = Some(icon.to_string());
+            styled_toggle.icon = Some(icon.into());
         }
     }
 }
 
-fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
+fn setup_view_root(
+    mut commands: Commands,
+    theme: Res<ThemeManager>,
+    asset_server: Res<AssetServer>,
+    mut icon_registry: ResMut<IconRegistry>,
+) {
     commands.spawn(Camera2d);
 
+    // Theme icons are glyphs in the icon font
+    icon_registry.set_default_font(asset_server.load("fonts/font-icons.ttf"));
+
     let on_toogle_theme_mode = commands.register_system(update_light_dark_theme);
 
     // Example theme change handlers (register your real handlers)
@@ -96,21 +89,8 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
     let on_violet_theme = commands.register_system(set_theme(ThemeId("violet".into())));
 
     commands.spawn((
-        Node {
-            display: Display::Flex,
-            flex_direction: FlexDirection::Column,
-            position_type: PositionType::Absolute,
-            left: Val::Px(0.),
-            top: Val::Px(0.),
-            right: Val::Px(0.),
-            bottom: Val::Px(0.),
-            padding: UiRect::all(Val::Px(3.)),
-            row_gap: Val::Px(6.),
-            ..Default::default()
-        },
-        RootWindow,
         TabGroup::default(),
-        Children::spawn((
+        StyledPanel::builder().fill().build_with((
             // Theme selection row
             Spawn((
                 Node {
@@ -234,6 +214,7 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                         StyledToggle::builder()
                             .variant(ToggleVariant::Default)
                             .active(true)
+                            .label("Bold")
                             .build(),
                     ),
                     Spawn(
@@ -245,6 +226,7 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                     Spawn(
                         StyledToggle::builder()
                             .variant(ToggleVariant::Outline)
+                            .label("Italic")
                             .build(),
                     ),
                     Spawn(
@@ -256,6 +238,19 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                     Spawn(
                         StyledToggle::builder()
                             .variant(ToggleVariant::WithText)
+                            .label("Underline")
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledText::builder()
+                            .content("Icon only")
+                            .font_size(14.0)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledToggle::builder()
+                            .variant(ToggleVariant::Outline)
+                            .icon_only("theme_mode_light", "Light mode")
                             .build(),
                     ),
                 )),
@@ -289,6 +284,7 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                             .size(ToggleSize::XSmall)
                             .variant(ToggleVariant::Default)
                             .active(true)
+                            .label("Bold")
                             .build(),
                     ),
                     Spawn(
@@ -302,6 +298,7 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                             .size(ToggleSize::Small)
                             .variant(ToggleVariant::Default)
                             .active(true)
+                            .label("Bold")
                             .build(),
                     ),
                     Spawn(
@@ -315,6 +312,7 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                             .size(ToggleSize::Medium)
                             .variant(ToggleVariant::Default)
                             .active(true)
+                            .label("Bold")
                             .build(),
                     ),
                     Spawn(
@@ -328,6 +326,7 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                             .size(ToggleSize::Large)
                             .variant(ToggleVariant::Default)
                             .active(true)
+                            .label("Bold")
                             .build(),
                     ),
                     Spawn(
@@ -341,6 +340,7 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                             .size(ToggleSize::XLarge)
                             .variant(ToggleVariant::Default)
                             .active(true)
+                            .label("Bold")
                             .build(),
                     ),
                 )),
@@ -362,15 +362,14 @@ fn setup_view_root(mut commands: Commands, theme: Res<ThemeManager>) {
                     column_gap: Val::Px(6.0),
                     ..default()
                 },
-                Children::spawn((Spawn((
+                Children::spawn((Spawn(
                     StyledToggle::builder()
                         .variant(ToggleVariant::Default)
                         .active(true)
                         .disabled()
                         .label("Disabled")
                         .build(),
-                    InteractionDisabled,
-                )),)),
+                ),)),
             )),
         )),
     ));
@@ -0,0 +1,115 @@
+use bevy::{input_focus::tab_navigation::TabGroup, prelude::*, winit::WinitSettings};
+use bevy_styled_widgets::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            StyledWidgetsPlugin,
+            ToggleGroupPlugin::<Alignment>::default(),
+        ))
+        .insert_resource(ThemeManager::default())
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup_view_root)
+        .run();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+// Groups report their selection as one event, which bubbles like the toggle events
+fn log_selection(trigger: Trigger<WidgetChanged<Vec<String>>>) {
+    let event = trigger.event();
+    info!("{:?}: {:?} -> {:?}", event.entity, event.old, event.new);
+}
+
+fn log_alignment(trigger: Trigger<WidgetChanged<Vec<Alignment>>>) {
+    let event = trigger.event();
+    info!("{:?}: {:?} -> {:?}", event.entity, event.old, event.new);
+}
+
+fn setup_view_root(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn((
+            TabGroup::default(),
+            StyledPanel::builder().fill().build_with((Spawn((
+                // Groups keep their own width instead of stretching across the panel
+                Node {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Start,
+                    row_gap: Val::Px(12.),
+                    ..default()
+                },
+                Children::spawn((
+                    Spawn(
+                        StyledText::builder()
+                            .content("Formatting (multiple)")
+                            .font_size(24.0)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledToggleGroup::builder()
+                            .label("Formatting")
+                            .mode(ToggleGroupMode::Multiple)
+                            .variant(ToggleVariant::Outline)
+                            .toggles([
+                                StyledToggle::builder().label("Bold").value("bold"),
+                                StyledToggle::builder()
+                                    .label("Italic")
+                                    .value("italic")
+                                    .active(true),
+                                StyledToggle::builder()
+                                    .label("Underline")
+                                    .value("underline"),
+                            ])
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledText::builder()
+                            .content("View mode (single, required)")
+                            .font_size(24.0)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledToggleGroup::builder()
+                            .label("View mode")
+                            .required()
+                            .variant(ToggleVariant::Outline)
+                            .toggles([
+                                StyledToggle::builder()
+                                    .label("List")
+                                    .value("list")
+                                    .active(true),
+                                StyledToggle::builder().label("Grid").value("grid"),
+                                StyledToggle::builder().label("Board").value("board"),
+                            ])
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledText::builder()
+                            .content("Alignment (vertical)")
+                            .font_size(24.0)
+                            .build(),
+                    ),
+                    Spawn(
+                        StyledToggleGroup::<Alignment>::typed_builder()
+                            .label("Alignment")
+                            .orientation(ToggleGroupOrientation::Vertical)
+                            .option(Alignment::Left, StyledToggle::builder().label("Left"))
+                            .option(Alignment::Center, StyledToggle::builder().label("Center"))
+                            .option(Alignment::Right, StyledToggle::builder().label("Right"))
+                            .build(),
+                    ),
+                )),
+            )),)),
+        ))
+        .observe(log_selection)
+        .observe(log_alignment);
+}